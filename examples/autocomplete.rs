@@ -3,7 +3,7 @@ use rustyline::Context;
 use rustyline::Editor;
 use rustyline_derive::{Helper, Highlighter, Hinter, Validator};
 
-use rusteval::{Function, Interactive, InteractiveRoot, Methods};
+use rusteval::{Function, Interactive, InteractiveRoot, Methods, OutputOptions, Radix};
 
 #[derive(Interactive, Debug, Default)]
 struct ChildStruct {
@@ -41,11 +41,27 @@ fn main() -> rustyline::Result<()> {
     let mut rl = Editor::new().unwrap();
     rl.set_helper(Some(h));
 
+    let mut options = OutputOptions::default();
+
     loop {
         let input = rl.readline(">>> ")?;
         rl.add_history_entry(&input);
-        let root = &mut rl.helper_mut().unwrap().root;
-        println!("{}", root.eval_to_string(&input));
+
+        // `:pretty`, `:display` and `:hex` toggle the output options
+        match input.trim() {
+            ":pretty" => options.pretty = !options.pretty,
+            ":display" => options.prefer_display = !options.prefer_display,
+            ":hex" => {
+                options.radix = match options.radix {
+                    Some(Radix::Hex) => None,
+                    _ => Some(Radix::Hex),
+                }
+            }
+            _ => {
+                let root = &mut rl.helper_mut().unwrap().root;
+                println!("{}", root.eval_to_string_with(&input, &options));
+            }
+        }
     }
 }
 
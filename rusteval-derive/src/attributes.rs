@@ -0,0 +1,390 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::spanned::Spanned;
+use syn::*;
+
+/// The options of an `#[interactive(...)]` attribute on a field.
+#[derive(Default)]
+pub struct FieldAttributes {
+    /// Not accessible at all.
+    pub skip: bool,
+    /// Accessible under a different name.
+    pub rename: Option<String>,
+    /// Never accessible mutably.
+    pub readonly: bool,
+    /// Accessible but not listed.
+    pub hidden: bool,
+    /// Its fields and methods are accessible as if they belonged to the parent.
+    pub flatten: bool,
+    /// How its value is shown.
+    pub format: Format,
+}
+
+/// The options of an `#[interactive(...)]` attribute on a method.
+#[derive(Default)]
+pub struct MethodAttributes {
+    /// Not interactive at all.
+    pub skip: bool,
+    /// Accessible under a different name.
+    pub rename: Option<String>,
+    /// Accessible but not listed.
+    pub hidden: bool,
+    /// Accessible like a read-only field.
+    pub property: bool,
+    /// The name of the property this method is the setter of.
+    pub setter: Option<String>,
+    /// How its return value is shown.
+    pub format: Format,
+}
+
+/// The options of an `#[interactive(...)]` attribute on a struct deriving `InteractiveRoot`.
+/// The options of an `#[interactive(...)]` attribute on a struct.
+#[derive(Default)]
+pub struct StructAttributes {
+    /// Implements `StaticType`, so results of this type can be downcast.
+    pub any: bool,
+    /// Doesn't implement `Methods`, so it can be implemented by hand.
+    pub manual_methods: bool,
+}
+
+/// The options of an `#[interactive(...)]` attribute on a root.
+#[derive(Default)]
+pub struct RootAttributes {
+    /// The `PanicHandler` queries are evaluated with.
+    pub panic_handler: Option<TokenStream2>,
+}
+
+/// The `format`, `display` and `unit` options of an `#[interactive(...)]` attribute.
+#[derive(Default)]
+pub struct Format {
+    pub style: Option<FormatStyle>,
+    /// Appended to the value, separated by a space.
+    pub unit: Option<String>,
+}
+
+pub enum FormatStyle {
+    /// `format = "hex"`
+    Hex,
+    /// `format = "bin"`
+    Bin,
+    /// A format string like `format = "{:.3}"`
+    Custom(LitStr),
+    /// `display`
+    Display,
+}
+
+impl FieldAttributes {
+    pub fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let mut result = Self::default();
+
+        for meta in interactive_metas(attrs)? {
+            match &meta {
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("skip") => result.skip = true,
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("readonly") => {
+                    result.readonly = true
+                }
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("hidden") => {
+                    result.hidden = true
+                }
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("flatten") => {
+                    result.flatten = true
+                }
+                NestedMeta::Meta(Meta::NameValue(name_value))
+                    if name_value.path.is_ident("rename") =>
+                {
+                    result.rename = Some(parse_str_value(name_value)?)
+                }
+                meta if result.format.parse_option(meta)? => {}
+                _ => {
+                    return Err(Error::new(
+                        meta.span(),
+                        "unknown `interactive` field option",
+                    ))
+                }
+            }
+        }
+
+        if result.flatten
+            && (result.rename.is_some() || result.hidden || !result.format.is_default())
+        {
+            let attr = attrs
+                .iter()
+                .find(|attr| attr.path.is_ident("interactive"))
+                .unwrap();
+            return Err(Error::new(
+                attr.span(),
+                "`flatten` can't be combined with `rename`, `hidden`, `format` or `unit`",
+            ));
+        }
+
+        Ok(result)
+    }
+}
+
+impl MethodAttributes {
+    pub fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let mut result = Self::default();
+
+        for meta in interactive_metas(attrs)? {
+            match &meta {
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("skip") => result.skip = true,
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("hidden") => {
+                    result.hidden = true
+                }
+                NestedMeta::Meta(Meta::NameValue(name_value))
+                    if name_value.path.is_ident("rename") =>
+                {
+                    result.rename = Some(parse_str_value(name_value)?)
+                }
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("property") => {
+                    result.property = true
+                }
+                NestedMeta::Meta(Meta::NameValue(name_value))
+                    if name_value.path.is_ident("setter") =>
+                {
+                    result.setter = Some(parse_str_value(name_value)?)
+                }
+                meta if result.format.parse_option(meta)? => {}
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("readonly") => {
+                    return Err(Error::new(
+                        meta.span(),
+                        "`readonly` can only be used on fields",
+                    ))
+                }
+                _ => {
+                    return Err(Error::new(
+                        meta.span(),
+                        "unknown `interactive` method option",
+                    ))
+                }
+            }
+        }
+
+        if result.property && result.setter.is_some() {
+            let attr = attrs
+                .iter()
+                .find(|attr| attr.path.is_ident("interactive"))
+                .unwrap();
+            return Err(Error::new(
+                attr.span(),
+                "a method can't be both a `property` and a `setter`",
+            ));
+        }
+
+        Ok(result)
+    }
+}
+
+impl StructAttributes {
+    pub fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let mut result = Self::default();
+
+        for meta in interactive_metas(attrs)? {
+            match &meta {
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("any") => result.any = true,
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("manual_methods") => {
+                    result.manual_methods = true
+                }
+                // parsed by `RootAttributes`
+                _ if is_root_option(&meta) => {}
+                _ => return Err(Error::new(meta.span(), "unknown `interactive` option")),
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+fn is_root_option(meta: &NestedMeta) -> bool {
+    match meta {
+        NestedMeta::Meta(Meta::Path(path)) => path.is_ident("catch_panics"),
+        NestedMeta::Meta(Meta::NameValue(name_value)) => name_value.path.is_ident("panic_handler"),
+        _ => false,
+    }
+}
+
+impl RootAttributes {
+    pub fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let mut result = Self::default();
+
+        for meta in interactive_metas(attrs)? {
+            let panic_handler = match &meta {
+                // parsed by `StructAttributes`
+                NestedMeta::Meta(Meta::Path(path))
+                    if path.is_ident("any") || path.is_ident("manual_methods") =>
+                {
+                    continue
+                }
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("catch_panics") => {
+                    if !cfg!(feature = "std") {
+                        return Err(Error::new(
+                            meta.span(),
+                            "`catch_panics` requires the `std` feature, use `panic_handler = \"...\"` instead",
+                        ));
+                    }
+                    quote! {::rusteval::panic::catch_unwind}
+                }
+                NestedMeta::Meta(Meta::NameValue(name_value))
+                    if name_value.path.is_ident("panic_handler") =>
+                {
+                    let path: Path = match &name_value.lit {
+                        Lit::Str(lit) => lit.parse()?,
+                        lit => return Err(Error::new(lit.span(), "expected a string literal")),
+                    };
+                    quote! {#path}
+                }
+                _ => return Err(Error::new(meta.span(), "unknown `interactive` root option")),
+            };
+
+            if result.panic_handler.is_some() {
+                return Err(Error::new(
+                    meta.span(),
+                    "only one of `catch_panics` and `panic_handler` can be used",
+                ));
+            }
+            result.panic_handler = Some(panic_handler);
+        }
+
+        Ok(result)
+    }
+}
+
+impl Format {
+    /// Parses `format = "..."`, `display` and `unit = "..."`, returns `false` for other options.
+    fn parse_option(&mut self, meta: &NestedMeta) -> Result<bool> {
+        let is_style = match meta {
+            NestedMeta::Meta(Meta::Path(path)) => path.is_ident("display"),
+            NestedMeta::Meta(Meta::NameValue(name_value)) => name_value.path.is_ident("format"),
+            _ => false,
+        };
+        if is_style && self.style.is_some() {
+            return Err(Error::new(
+                meta.span(),
+                "only one of `format` and `display` can be used",
+            ));
+        }
+
+        let name_value = match meta {
+            NestedMeta::Meta(Meta::Path(path)) if path.is_ident("display") => {
+                self.style = Some(FormatStyle::Display);
+                return Ok(true);
+            }
+            NestedMeta::Meta(Meta::NameValue(name_value)) => name_value,
+            _ => return Ok(false),
+        };
+
+        if name_value.path.is_ident("format") {
+            let lit = match &name_value.lit {
+                Lit::Str(lit) => lit,
+                lit => return Err(Error::new(lit.span(), "expected a string literal")),
+            };
+            self.style = Some(match lit.value().as_str() {
+                "hex" => FormatStyle::Hex,
+                "bin" => FormatStyle::Bin,
+                format if format.contains('{') => FormatStyle::Custom(lit.clone()),
+                _ => {
+                    return Err(Error::new(
+                        lit.span(),
+                        "expected `hex`, `bin` or a format string like `{:.3}`",
+                    ))
+                }
+            });
+            Ok(true)
+        } else if name_value.path.is_ident("unit") {
+            self.unit = Some(parse_str_value(name_value)?);
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    pub fn is_default(&self) -> bool {
+        self.style.is_none() && self.unit.is_none()
+    }
+
+    /// Generates an expression that implements `Debug` by showing the given value in this format.
+    ///
+    /// Returns `None` for the default format, use `AsOutput` in that case.
+    pub fn debug_expr(&self, value: &TokenStream2) -> Option<TokenStream2> {
+        if self.is_default() {
+            return None;
+        }
+
+        let value_fmt = match &self.style {
+            Some(FormatStyle::Hex) => quote! { ::rusteval::format::fmt_hex(&#value, formatter) },
+            Some(FormatStyle::Bin) => quote! { ::rusteval::format::fmt_bin(&#value, formatter) },
+            Some(FormatStyle::Custom(format)) => {
+                quote! { ::core::write!(formatter, #format, #value) }
+            }
+            Some(FormatStyle::Display) => quote! { ::core::fmt::Display::fmt(&#value, formatter) },
+            None => quote! { ::core::fmt::Debug::fmt(&#value, formatter) },
+        };
+
+        let body = match &self.unit {
+            Some(unit) => {
+                let unit = format!(" {}", unit);
+                quote! {
+                    #value_fmt?;
+                    ::core::fmt::Formatter::write_str(formatter, #unit)
+                }
+            }
+            None => value_fmt,
+        };
+
+        Some(quote! {
+            ::rusteval::format::formatted(&#value, |formatter: &mut ::core::fmt::Formatter<'_>| {
+                #body
+            })
+        })
+    }
+}
+
+/// Removes all `#[interactive(...)]` attributes.
+///
+/// Needed for attribute macros since `interactive` isn't a real attribute.
+pub fn strip_interactive_attributes(attrs: &mut Vec<Attribute>) {
+    attrs.retain(|attr| !attr.path.is_ident("interactive"));
+}
+
+/// Joins the lines of all doc comments, without the space that follows `///`.
+pub fn doc_comment(attrs: &[Attribute]) -> String {
+    let lines: Vec<_> = attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("doc"))
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(Meta::NameValue(MetaNameValue {
+                lit: Lit::Str(lit), ..
+            })) => Some(lit.value()),
+            _ => None,
+        })
+        .map(|line| match line.strip_prefix(' ') {
+            Some(line) => line.to_owned(),
+            None => line,
+        })
+        .collect();
+    lines.join("\n")
+}
+
+/// Collects the options of all `#[interactive(...)]` attributes.
+fn interactive_metas(attrs: &[Attribute]) -> Result<Vec<NestedMeta>> {
+    let mut metas = Vec::new();
+
+    for attr in attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("interactive"))
+    {
+        match attr.parse_meta()? {
+            Meta::List(list) => metas.extend(list.nested),
+            meta => return Err(Error::new(meta.span(), "expected `#[interactive(...)]`")),
+        }
+    }
+
+    Ok(metas)
+}
+
+fn parse_str_value(name_value: &MetaNameValue) -> Result<String> {
+    match &name_value.lit {
+        Lit::Str(lit) => Ok(lit.value()),
+        lit => Err(Error::new(lit.span(), "expected a string literal")),
+    }
+}
@@ -5,9 +5,29 @@ use quote::{quote, quote_spanned};
 use syn::spanned::Spanned;
 use syn::*;
 
+use crate::attributes::{doc_comment, FieldAttributes, RootAttributes, StructAttributes};
+use crate::functions;
+
 pub fn derive_interactive(input: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(input as ItemStruct);
-    interactive_impl(&ast).into()
+
+    let interactive_impl = interactive_impl(&ast);
+
+    // generic types implement `Methods` in their single `#[Methods]` impl block instead
+    let methods_impl = if functions::uses_registry(&ast.generics) && !has_manual_methods(&ast) {
+        let struct_name = &ast.ident;
+        functions::dispatching_methods_impl(&quote! {#struct_name}, &ast.generics, true, false)
+    } else {
+        TokenStream2::new()
+    };
+
+    let expanded = quote! {
+        #interactive_impl
+
+        #methods_impl
+    };
+
+    expanded.into()
 }
 
 pub fn derive_root(input: TokenStream) -> TokenStream {
@@ -19,59 +39,100 @@ pub fn derive_root(input: TokenStream) -> TokenStream {
 
     let interactive_impl = interactive_impl(&ast);
 
-    let expanded = quote! {
-        #interactive_impl
+    // gives access to free functions
+    let methods_impl = if cfg!(feature = "std") && !has_manual_methods(&ast) {
+        functions::dispatching_methods_impl(
+            &quote! {#struct_name #ty_generics},
+            &ast.generics,
+            functions::uses_registry(&ast.generics),
+            true,
+        )
+    } else {
+        TokenStream2::new()
+    };
 
-        impl #impl_generics InteractiveRoot for #struct_name #ty_generics #where_clause{}
+    let attributes = match RootAttributes::parse(&ast.attrs) {
+        Ok(attributes) => attributes,
+        Err(e) => return e.to_compile_error().into(),
+    };
 
-        #[cfg(feature = "std")]
-        impl #impl_generics ::rusteval::Methods for #struct_name #ty_generics #where_clause{
-            fn eval_method(
-                &self,
-                function_name: &str,
-                args: &str,
-                f: &mut dyn ::core::ops::FnMut(::rusteval::Result<'_, &dyn ::core::fmt::Debug>),
-            ) {
-                if let ::core::option::Option::Some(function) = ::core::iter::Iterator::find(
-                    &mut ::core::iter::IntoIterator::into_iter(
-                        ::rusteval::inventory::iter::<&dyn ::rusteval::Function>,
-                    ),
-                    |function| function.function_name() == function_name,
-                ) {
-                    function.eval(args, f)
-                } else {
-                    f(::core::result::Result::Err(::rusteval::InteractiveError::FunctionNotFound {
-                        function_name,
-                    }))
-                }
+    let panic_handler = attributes.panic_handler.map(|panic_handler| {
+        quote! {
+            fn panic_handler(&self) -> ::core::option::Option<::rusteval::panic::PanicHandler> {
+                ::core::option::Option::Some(#panic_handler)
             }
+        }
+    });
 
-            fn eval_method_mut(
-                &mut self,
-                function_name: &str,
-                args: &str,
-                f: &mut dyn ::core::ops::FnMut(::rusteval::Result<'_, &dyn ::core::fmt::Debug>),
-            ) {
-                (&*self).eval_method(function_name, args, f)
-            }
+    let expanded = quote! {
+        #interactive_impl
 
-            fn get_all_method_names(&self) -> &'static [&'static str]{
-                ::lazy_static::lazy_static! {
-                    static ref NAMES: ::std::vec::Vec<&'static str> = ::core::iter::Iterator::collect(::core::iter::Iterator::map(
-                        ::core::iter::IntoIterator::into_iter(
-                            ::rusteval::inventory::iter::<&dyn ::rusteval::Function>,
-                        ),
-                        |function| function.function_name(),
-                    ));
-                }
-                &*NAMES
-            }
+        impl #impl_generics ::rusteval::InteractiveRoot for #struct_name #ty_generics #where_clause{
+            #panic_handler
         }
+
+        #methods_impl
     };
 
     expanded.into()
 }
 
+/// A field together with its `#[interactive(...)]` options.
+struct InteractiveField<'a> {
+    field: &'a Field,
+    /// Used to access the field as `self.#member`
+    member: TokenStream2,
+    /// Used to access the field in a query
+    name: String,
+    attributes: FieldAttributes,
+}
+
+/// Collects all fields that weren't skipped.
+fn interactive_fields(ast: &ItemStruct) -> Result<Vec<InteractiveField<'_>>> {
+    let mut fields = Vec::new();
+
+    for (i, field) in ast.fields.iter().enumerate() {
+        let attributes = FieldAttributes::parse(&field.attrs)?;
+        if attributes.skip {
+            continue;
+        }
+
+        let member = get_name(field, i);
+        let name = match (&attributes.rename, &field.ident) {
+            (Some(rename), _) => rename.clone(),
+            (None, Some(ident)) => ident.to_string(),
+            (None, None) => i.to_string(),
+        };
+
+        if !attributes.flatten
+            && fields
+                .iter()
+                .any(|other: &InteractiveField<'_>| !other.attributes.flatten && other.name == name)
+        {
+            return Err(Error::new(
+                field.span(),
+                format!("duplicate interactive field name `{}`", name),
+            ));
+        }
+
+        fields.push(InteractiveField {
+            field,
+            member,
+            name,
+            attributes,
+        });
+    }
+
+    Ok(fields)
+}
+
+/// Whether the struct implements `Methods` by hand, see `StructAttributes::manual_methods`.
+///
+/// Invalid options are reported by `interactive_impl`.
+fn has_manual_methods(ast: &ItemStruct) -> bool {
+    StructAttributes::parse(&ast.attrs).map_or(false, |attributes| attributes.manual_methods)
+}
+
 fn interactive_impl(ast: &ItemStruct) -> TokenStream2 {
     let struct_name = &ast.ident;
 
@@ -79,43 +140,198 @@ fn interactive_impl(ast: &ItemStruct) -> TokenStream2 {
 
     let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
 
-    let interactive_fields: Vec<_> = ast.fields.iter().collect();
+    let uses_registry = functions::uses_registry(&ast.generics);
+
+    let attributes = match StructAttributes::parse(&ast.attrs) {
+        Ok(attributes) => attributes,
+        Err(e) => return e.to_compile_error(),
+    };
 
-    let eval_field_matches = interactive_fields.iter().enumerate().map(|(i, field)| {
-        let name = get_name(field, i);
+    let interactive_fields = match interactive_fields(ast) {
+        Ok(fields) => fields,
+        Err(e) => return e.to_compile_error(),
+    };
 
-        quote! {
-            stringify!(#name) => f(::rusteval::specialization::AsDebug::try_as_debug(&self.#name)),
+    let (flattened_fields, own_fields): (Vec<_>, Vec<_>) = interactive_fields
+        .iter()
+        .partition(|field| field.attributes.flatten);
+
+    let eval_field_matches = own_fields.iter().map(|field| {
+        let InteractiveField { member, name, .. } = field;
+
+        match field.attributes.format.debug_expr(&quote! {self.#member}) {
+            Some(debug_expr) => quote! {
+                #name => f(::core::result::Result::Ok(&#debug_expr)),
+            },
+            None => quote! {
+                #name => f(::rusteval::specialization::AsOutput::try_as_output(&self.#member)),
+            },
         }
     });
 
-    let get_field_matches = interactive_fields.iter().enumerate().map(|(i, field)| {
-        let name = get_name(field, i);
+    let get_field_matches = own_fields.iter().map(|field| {
+        let InteractiveField { member, name, .. } = field;
 
         quote! {
-            stringify!(#name) => ::rusteval::specialization::AsInteractive::try_as_interactive(&self.#name),
+            #name => ::rusteval::specialization::AsInteractive::try_as_interactive(&self.#member),
         }
     });
 
-    let get_field_mut_matches = interactive_fields
+    let get_field_mut_matches = own_fields
         .iter()
-        .filter(is_owned_or_mut_reference)
-        .enumerate()
-        .map(|(i, field)| {
-            let name = get_name(field, i);
+        .filter(|field| is_mutably_accessible(field))
+        .map(|field| {
+            let InteractiveField { member, name, .. } = field;
 
             quote! {
-                stringify!(#name) => ::rusteval::specialization::AsInteractiveMut::try_as_interactive_mut(&mut self.#name),
+                #name => ::rusteval::specialization::AsInteractiveMut::try_as_interactive_mut(&mut self.#member),
             }
         });
 
-    let all_field_names = interactive_fields.iter().enumerate().map(|(i, field)| {
-        let name = get_name(field, i);
+    let field_info_matches = own_fields.iter().map(|field| {
+        let InteractiveField { member, name, .. } = field;
+        let ty = &field.field.ty;
+        let type_name = functions::type_string(&quote! {#ty});
+        let mutable = is_mutably_accessible(field);
+        let docs = doc_comment(&field.field.attrs);
+
+        quote! {
+            #name => ::core::option::Option::Some(::rusteval::FieldInfo {
+                name: #name,
+                type_name: #type_name,
+                mutable: #mutable,
+                interactive: ::rusteval::specialization::AsInteractive::try_as_interactive(&self.#member).is_ok(),
+                docs: #docs,
+            }),
+        }
+    });
+
+    // fields of flattened fields can't be mutated if the flattened field can't be
+    let flattened_field_infos = flattened_fields.iter().map(|field| {
+        let member = &field.member;
+        let mutable = is_mutably_accessible(field);
+
         quote! {
-            stringify!(#name),
+            if let ::core::option::Option::Some(info) = ::core::option::Option::and_then(
+                ::core::result::Result::ok(::rusteval::specialization::AsInteractive::try_as_interactive(&self.#member)),
+                |flattened| flattened.get_field_info(field_name),
+            ) {
+                return ::core::option::Option::Some(::rusteval::FieldInfo {
+                    mutable: info.mutable && #mutable,
+                    ..info
+                });
+            }
         }
     });
 
+    let all_field_names = own_fields
+        .iter()
+        .filter(|field| !field.attributes.hidden)
+        .map(|field| {
+            let name = &field.name;
+            quote! {
+                #name,
+            }
+        });
+
+    let (get_field_fallback, get_field_mut_fallback, eval_field_fallback, get_all_field_names) =
+        if flattened_fields.is_empty() {
+            (
+                quote! {
+                    ::core::result::Result::Err(::rusteval::InteractiveError::FieldNotFound{type_name: stringify!(#struct_name), field_name, suggestion: ::rusteval::did_you_mean(field_name, ::rusteval::Interactive::get_all_field_names(self))})
+                },
+                quote! {
+                    ::core::result::Result::Err(::rusteval::InteractiveError::FieldNotFound{type_name: stringify!(#struct_name), field_name, suggestion: ::rusteval::did_you_mean(field_name, ::rusteval::Interactive::get_all_field_names(self))})
+                },
+                quote! {
+                    f(::core::result::Result::Err(::rusteval::InteractiveError::FieldNotFound{type_name: stringify!(#struct_name), field_name, suggestion: ::rusteval::did_you_mean(field_name, ::rusteval::Interactive::get_all_field_names(self))}))
+                },
+                if uses_registry {
+                    functions::cached(
+                        quote! { &'static str },
+                        quote! { ::rusteval::property::get_all_field_names(self, &[#(#all_field_names)*]) },
+                    )
+                } else {
+                    quote! { &[#(#all_field_names)*] }
+                },
+            )
+        } else {
+            (
+                quote! {
+                    ::rusteval::flatten::get_field(self, field_name, stringify!(#struct_name))
+                },
+                quote! {
+                    ::rusteval::flatten::get_field_mut(self, field_name, stringify!(#struct_name))
+                },
+                quote! {
+                    ::rusteval::flatten::eval_field(self, field_name, stringify!(#struct_name), f)
+                },
+                if uses_registry {
+                    functions::cached(
+                        quote! { &'static str },
+                        quote! {
+                            ::rusteval::flatten::get_all_field_names(
+                                self,
+                                &::rusteval::property::get_all_field_names(self, &[#(#all_field_names)*]),
+                            )
+                        },
+                    )
+                } else {
+                    quote! { &[#(#all_field_names)*] }
+                },
+            )
+        };
+
+    let get_flattened_field = if flattened_fields.is_empty() {
+        TokenStream2::new()
+    } else {
+        let flattened_field_matches = flattened_fields.iter().enumerate().map(|(i, field)| {
+            let member = &field.member;
+            quote! {
+                #i => ::core::option::Option::Some(&self.#member),
+            }
+        });
+
+        let flattened_field_mut_matches = flattened_fields.iter().enumerate().map(|(i, field)| {
+            let member = &field.member;
+            if is_mutably_accessible(field) {
+                quote! {
+                    #i => ::core::option::Option::Some(&mut self.#member),
+                }
+            } else {
+                quote! {
+                    #i => ::core::option::Option::None,
+                }
+            }
+        });
+
+        quote! {
+            fn get_flattened_field(&self, index: usize) -> ::core::option::Option<&dyn ::rusteval::Interactive> {
+                match index {
+                    #(#flattened_field_matches)*
+                    _ => ::core::option::Option::None,
+                }
+            }
+
+            fn get_flattened_field_mut(&mut self, index: usize) -> ::core::option::Option<&mut dyn ::rusteval::Interactive> {
+                match index {
+                    #(#flattened_field_mut_matches)*
+                    _ => ::core::option::Option::None,
+                }
+            }
+        }
+    };
+
+    // hidden fields are accessible and can therefore be ambiguous as well
+    let accessible_field_names = own_fields.iter().map(|field| &field.name);
+
+    let ambiguity_checks = ambiguity_checks(ast, &own_fields, &flattened_fields);
+    let static_type_impl = if attributes.any {
+        static_type_impl(ast)
+    } else {
+        TokenStream2::new()
+    };
+
     // TODO shorten impl when default impl would work as_well
 
     quote! {
@@ -123,49 +339,212 @@ fn interactive_impl(ast: &ItemStruct) -> TokenStream2 {
             fn get_field<#tick_a>(&#tick_a self, field_name: &#tick_a str) -> ::rusteval::Result<'_, &dyn ::rusteval::Interactive>{
                 match field_name {
                     #(#get_field_matches)*
-                    _ => ::core::result::Result::Err(::rusteval::InteractiveError::FieldNotFound{type_name: stringify!(#struct_name), field_name}),
+                    _ => #get_field_fallback,
                 }
             }
             fn get_field_mut<#tick_a>(&#tick_a mut self, field_name: &#tick_a str) -> ::rusteval::Result<'_, &mut dyn ::rusteval::Interactive>{
                 match field_name {
                     #(#get_field_mut_matches)*
-                    _ => ::core::result::Result::Err(::rusteval::InteractiveError::FieldNotFound{type_name: stringify!(#struct_name), field_name}),
+                    _ => #get_field_mut_fallback,
                 }
             }
 
             fn eval_field(&self, field_name: &str, f: &mut dyn ::core::ops::FnMut(::rusteval::Result<'_, &dyn ::core::fmt::Debug>))
+            {
+                ::rusteval::Interactive::eval_field_output(self, field_name, &mut |result| {
+                    match result {
+                        ::core::result::Result::Ok(value) => f(::core::result::Result::Ok(value)),
+                        ::core::result::Result::Err(e) => f(::core::result::Result::Err(e)),
+                    }
+                })
+            }
+
+            fn eval_field_output(&self, field_name: &str, f: &mut dyn ::core::ops::FnMut(::rusteval::Result<'_, &dyn ::rusteval::Output>))
             {
                 match field_name {
                     #(#eval_field_matches)*
-                    _ => f(::core::result::Result::Err(::rusteval::InteractiveError::FieldNotFound{type_name: stringify!(#struct_name), field_name})),
+                    _ => {
+                        if !::rusteval::property::eval_property(self, field_name, f) {
+                            #eval_field_fallback
+                        }
+                    }
                 }
             }
 
             fn get_all_field_names(&self) -> &'static [&'static str]{
-                &[#(#all_field_names)*]
+                #get_all_field_names
+            }
+
+            fn get_field_info(&self, field_name: &str) -> ::core::option::Option<::rusteval::FieldInfo> {
+                match field_name {
+                    #(#field_info_matches)*
+                    _ => {
+                        #(#flattened_field_infos)*
+                        ::core::option::Option::None
+                    }
+                }
+            }
+
+            fn get_type_name(&self) -> &'static str {
+                stringify!(#struct_name)
             }
+
+            #get_flattened_field
+        }
+
+        impl #impl_generics ::rusteval::flatten::FieldNames for #struct_name #ty_generics #where_clause {
+            const FIELD_NAMES: &'static [&'static str] = &[#(#accessible_field_names),*];
         }
+
+        #static_type_impl
+
+        #ambiguity_checks
     }
 }
 
+/// Allows downcasting the struct with `eval_as`, opted into with `#[interactive(any)]`.
+///
+/// Upholds the safety contract of `rusteval::StaticType`:
+/// structs with lifetime parameters are rejected and type parameters have to be `StaticType` themselves.
+fn static_type_impl(ast: &ItemStruct) -> TokenStream2 {
+    if let Some(lifetime) = ast.generics.lifetimes().next() {
+        return Error::new(
+            lifetime.span(),
+            "`#[interactive(any)]` can't be used on structs with lifetime parameters",
+        )
+        .to_compile_error();
+    }
+
+    let struct_name = &ast.ident;
+    let mut generics = ast.generics.clone();
+    for param in generics.type_params_mut() {
+        param.bounds.push(parse_quote!(::rusteval::StaticType));
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    quote! {
+        unsafe impl #impl_generics ::rusteval::StaticType for #struct_name #ty_generics #where_clause {}
+    }
+}
+
+/// Checks at compile time that no field name of a flattened field is also used by its parent or another flattened field.
+///
+/// Only possible if the involved types can be named outside of the impl,
+/// so generic structs and flattened fields with lifetimes aren't checked.
+fn ambiguity_checks(
+    ast: &ItemStruct,
+    own_fields: &[&InteractiveField<'_>],
+    flattened_fields: &[&InteractiveField<'_>],
+) -> TokenStream2 {
+    let struct_name = &ast.ident;
+
+    if ast.generics.type_params().next().is_some() || ast.generics.const_params().next().is_some() {
+        return TokenStream2::new();
+    }
+
+    let checked_fields: Vec<_> = flattened_fields
+        .iter()
+        .filter_map(|field| {
+            let mut ty = &field.field.ty;
+            while let Type::Reference(reference) = ty {
+                ty = &reference.elem;
+            }
+            if contains_lifetime(quote! {#ty}) {
+                None
+            } else {
+                Some((field, ty))
+            }
+        })
+        .collect();
+
+    let mut checks = TokenStream2::new();
+
+    for (field, ty) in &checked_fields {
+        for own_field in own_fields {
+            let message = format!(
+                "field `{}` of `{}` is ambiguous, the flattened field `{}` has a field with the same name",
+                own_field.name, struct_name, field.name
+            );
+            let own_name = &own_field.name;
+            checks.extend(quote_spanned! {field.field.span()=>
+                ::core::assert!(
+                    !::rusteval::flatten::contains(<#ty as ::rusteval::flatten::AsFieldNames>::FIELD_NAMES, #own_name),
+                    #message,
+                );
+            });
+        }
+    }
+
+    for (i, (field, ty)) in checked_fields.iter().enumerate() {
+        for (other_field, other_ty) in &checked_fields[i + 1..] {
+            let message = format!(
+                "the flattened fields `{}` and `{}` of `{}` have a field name in common",
+                field.name, other_field.name, struct_name
+            );
+            checks.extend(quote_spanned! {other_field.field.span()=>
+                ::core::assert!(
+                    !::rusteval::flatten::intersects(
+                        <#ty as ::rusteval::flatten::AsFieldNames>::FIELD_NAMES,
+                        <#other_ty as ::rusteval::flatten::AsFieldNames>::FIELD_NAMES,
+                    ),
+                    #message,
+                );
+            });
+        }
+    }
+
+    if checks.is_empty() {
+        return checks;
+    }
+
+    quote! {
+        const _: () = {
+            #checks
+        };
+    }
+}
+
+fn contains_lifetime(tokens: TokenStream2) -> bool {
+    tokens.into_iter().any(|token| match token {
+        proc_macro2::TokenTree::Punct(punct) => punct.as_char() == '\'',
+        proc_macro2::TokenTree::Group(group) => contains_lifetime(group.stream()),
+        _ => false,
+    })
+}
+
 pub fn derive_partial_debug(input: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(input as ItemStruct);
 
     let struct_name = &ast.ident;
     let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
 
-    let interactive_fields = ast.fields.iter();
+    let interactive_fields = match interactive_fields(&ast) {
+        Ok(fields) => fields,
+        Err(e) => return e.to_compile_error().into(),
+    };
 
-    let as_debug_all_fields = interactive_fields.enumerate().map(|(i, field)| {
-        let name = get_name(field, i);
-        quote! {
-            .field(
-                stringify!(#name),
-                match ::rusteval::specialization::AsDebug::try_as_debug(&self.#name){
-                    ::core::result::Result::Ok(field) => field,
-                    ::core::result::Result::Err(_) => &::rusteval::specialization::Unknown,
-                },
-            )
+    // show that skipped fields exist without revealing them
+    let finish = if interactive_fields.len() < ast.fields.len() {
+        quote! {finish_non_exhaustive}
+    } else {
+        quote! {finish}
+    };
+
+    let as_debug_all_fields = interactive_fields.iter().map(|field| {
+        let InteractiveField { member, .. } = field;
+        match field.attributes.format.debug_expr(&quote! {self.#member}) {
+            Some(debug_expr) => quote! {
+                .field(stringify!(#member), &#debug_expr)
+            },
+            None => quote! {
+                .field(
+                    stringify!(#member),
+                    match ::rusteval::specialization::AsDebug::try_as_debug(&self.#member){
+                        ::core::result::Result::Ok(field) => field,
+                        ::core::result::Result::Err(_) => &::rusteval::specialization::Unknown,
+                    },
+                )
+            },
         }
     });
 
@@ -174,7 +553,7 @@ pub fn derive_partial_debug(input: TokenStream) -> TokenStream {
             fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
                 f.debug_struct(stringify!(#struct_name))
                     #(#as_debug_all_fields)*
-                    .finish()
+                    .#finish()
     }
         }
     };
@@ -210,7 +589,11 @@ fn get_name(field: &Field, field_index: usize) -> TokenStream2 {
     }
 }
 
-fn is_owned_or_mut_reference(field: &&&Field) -> bool {
+fn is_mutably_accessible(field: &InteractiveField<'_>) -> bool {
+    !field.attributes.readonly && is_owned_or_mut_reference(field.field)
+}
+
+fn is_owned_or_mut_reference(field: &Field) -> bool {
     !matches!(field.ty, Type::Reference(_))
         || matches!(
             field.ty,
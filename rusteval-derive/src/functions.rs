@@ -1,10 +1,12 @@
 use proc_macro::TokenStream;
 
-use proc_macro2::TokenStream as TokenStream2;
-use quote::quote;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote, quote_spanned};
 use syn::spanned::Spanned;
 use syn::*;
 
+use crate::attributes::{doc_comment, strip_interactive_attributes, Format, MethodAttributes};
+
 #[cfg(feature = "std")]
 static SUPPORTED_FUNC_ARGS: &[&str] = &[
     "bool", "char", "f32", "f64", "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32",
@@ -17,63 +19,920 @@ static SUPPORTED_FUNC_ARGS: &[&str] = &[
     "u64", "u128", "usize",
 ];
 
-pub fn methods(input: TokenStream) -> TokenStream {
-    let original_impl = TokenStream2::from(input.clone());
-    let ast = parse_macro_input!(input as ItemImpl);
+pub fn methods(attr: TokenStream, input: TokenStream) -> TokenStream {
+    let options = parse_macro_input!(attr as MethodsOptions);
+    let item = parse_macro_input!(input as Item);
 
-    let struct_name = &ast.self_ty;
+    let expanded = match &item {
+        Item::Impl(ast) => {
+            let methods_impl = methods_impl_block(ast, &options);
 
-    let (impl_generics, _, where_clause) = ast.generics.split_for_impl();
+            let mut stripped_impl = ast.clone();
+            for item in &mut stripped_impl.items {
+                if let ImplItem::Method(method) = item {
+                    strip_interactive_attributes(&mut method.attrs);
+                }
+            }
 
-    let methods = ast.items.iter().filter_map(|item| match item {
-        ImplItem::Method(method) => Some(method),
-        _ => None,
+            quote! {
+                #stripped_impl
+                #methods_impl
+            }
+        }
+        _ => Error::new(item.span(), "`#[Methods]` can only be used on impl blocks")
+            .to_compile_error(),
+    };
+
+    expanded.into()
+}
+
+/// The arguments of the `#[Methods]` attribute.
+#[derive(Default)]
+struct MethodsOptions {
+    /// Turn warnings about skipped methods into errors.
+    strict: bool,
+    /// Implement `Methods` for a type that doesn't derive `Interactive`, like an enum.
+    primary: bool,
+}
+
+impl parse::Parse for MethodsOptions {
+    fn parse(input: parse::ParseStream<'_>) -> Result<Self> {
+        let mut options = MethodsOptions::default();
+
+        for option in input.parse_terminated::<Ident, Token![,]>(Ident::parse)? {
+            if option == "strict" {
+                options.strict = true;
+            } else if option == "primary" {
+                options.primary = true;
+            } else {
+                return Err(Error::new(
+                    option.span(),
+                    format!("unknown `#[Methods]` option `{}`", option),
+                ));
+            }
+        }
+
+        Ok(options)
+    }
+}
+
+/// Computes a list only once per type and keeps it in a static.
+///
+/// Only possible for non-generic types with the `std` feature.
+pub fn cached(ty: TokenStream2, init: TokenStream2) -> TokenStream2 {
+    quote! {
+        {
+            static CACHE: ::std::sync::OnceLock<::std::vec::Vec<#ty>> = ::std::sync::OnceLock::new();
+            CACHE.get_or_init(|| #init)
+        }
+    }
+}
+
+/// Emits a warning without unstable compiler features by using a deprecated constant.
+///
+/// Like any lint it can be silenced with `#![allow(deprecated)]` in the surrounding module.
+fn warning(span: Span, method_ident: &Ident, message: &str) -> TokenStream2 {
+    let note = format!(
+        "{}\nsupported argument types are: {}",
+        message,
+        SUPPORTED_FUNC_ARGS.join(", ")
+    );
+    let ident = format_ident!("{}_is_not_interactive", method_ident);
+    let usage = quote_spanned! {span=> #ident};
+    quote! {
+        const _: () = {
+            #[deprecated(note = #note)]
+            #[allow(non_upper_case_globals)]
+            const #ident: () = ();
+            #usage
+        };
+    }
+}
+
+/// Non-generic types register their `#[Methods]` impl blocks, so they can have more than one.
+pub fn uses_registry(generics: &Generics) -> bool {
+    cfg!(feature = "std") && generics.params.is_empty()
+}
+
+/// Whether the impl block is added to the registry instead of implementing `Methods` itself.
+///
+/// Impl blocks of generic types, even with concrete arguments like `impl Wrapper<u8>`, are never registered,
+/// because the `Methods` implementation of a generic type can't look them up.
+fn is_registered(ast: &ItemImpl) -> bool {
+    let is_plain_path = match &*ast.self_ty {
+        Type::Path(TypePath { qself: None, path }) => path
+            .segments
+            .iter()
+            .all(|segment| segment.arguments.is_empty()),
+        _ => false,
+    };
+    uses_registry(&ast.generics) && is_plain_path
+}
+
+fn methods_impl_block(ast: &ItemImpl, options: &MethodsOptions) -> TokenStream2 {
+    let self_ty = &ast.self_ty;
+
+    let callee_prefix = match &ast.trait_ {
+        Some((_, trait_path, _)) => quote! { <#self_ty as #trait_path> },
+        None => quote! { <#self_ty> },
+    };
+
+    let (interactive_methods, skipped_methods) = match collect_methods(ast) {
+        Ok(methods) => methods,
+        Err(e) => return e.to_compile_error(),
+    };
+
+    // tell the user about methods that can't be made interactive
+    let mut errors = TokenStream2::new();
+    for SkippedMethod {
+        method,
+        reason,
+        arg,
+    } in &skipped_methods
+    {
+        let message = format!(
+            "method `{}` can't be made interactive: {}",
+            method.sig.ident, reason
+        );
+        if options.strict {
+            errors.extend(Error::new(arg.span(), message).to_compile_error());
+        } else {
+            errors.extend(warning(arg.span(), &method.sig.ident, &message));
+        }
+    }
+
+    let all_skipped_methods =
+        skipped_methods
+            .iter()
+            .map(|SkippedMethod { method, reason, .. }| {
+                let name = &method.sig.ident;
+                quote! {
+                    ::rusteval::SkippedMethod {
+                        method_name: stringify!(#name),
+                        reason: #reason,
+                    },
+                }
+            });
+
+    let registered = is_registered(ast);
+
+    // fragments report back whether they found the method
+    let arm_result = if registered {
+        quote! {true}
+    } else {
+        TokenStream2::new()
+    };
+
+    let (shared_methods, mut_methods): (Vec<_>, Vec<_>) =
+        interactive_methods
+            .iter()
+            .partition(|InteractiveMethod { method, .. }| {
+                matches!(
+                    method.sig.receiver(),
+                    Some(FnArg::Receiver(Receiver {
+                        mutability: None,
+                        ..
+                    }))
+                )
+            });
+
+    let gen_matches = |methods: &[&InteractiveMethod<'_>], source| -> Vec<_> {
+        methods
+            .iter()
+            .map(|method| gen_method_match_expr(method, &callee_prefix, &arm_result, source))
+            .collect()
+    };
+
+    let method_matches = gen_matches(&shared_methods, ArgsSource::Parsed);
+    let method_mut_matches = gen_matches(&mut_methods, ArgsSource::Parsed);
+    let typed_method_matches = gen_matches(&shared_methods, ArgsSource::Typed);
+    let typed_method_mut_matches = gen_matches(&mut_methods, ArgsSource::Typed);
+
+    // with mutable access the methods that take `&self` are forwarded to the shared versions
+    let shared_names: Vec<_> = shared_methods.iter().map(|method| &method.name).collect();
+    let forward_shared = |call: TokenStream2| {
+        if shared_names.is_empty() {
+            TokenStream2::new()
+        } else {
+            quote! {
+                #(#shared_names)|* => #call,
+            }
+        }
+    };
+
+    // properties are listed as fields instead
+    let listed_methods: Vec<_> = interactive_methods
+        .iter()
+        .filter(|method| !method.attributes.hidden && !method.attributes.property)
+        .collect();
+    let all_method_names = listed_methods.iter().map(|InteractiveMethod { name, .. }| {
+        quote! {
+            #name,
+        }
     });
+    let all_method_signatures: Vec<_> = listed_methods
+        .iter()
+        .map(|InteractiveMethod { method, name, .. }| {
+            let signature = gen_signature(method, name);
+            quote! {
+                #signature,
+            }
+        })
+        .collect();
 
-    let interactive_methods: Vec<_> = methods.filter(is_interactive_method).collect();
+    let properties: Vec<_> = interactive_methods
+        .iter()
+        .filter(|method| method.attributes.property)
+        .collect();
 
-    let method_matches = interactive_methods
+    let property_matches: Vec<_> = properties
         .iter()
-        .filter(|method| {
-            matches!(
-                method.sig.receiver(),
-                Some(FnArg::Receiver(Receiver {
-                    mutability: None,
-                    ..
-                }))
-            )
+        .map(
+            |InteractiveMethod {
+                 method,
+                 name,
+                 attributes,
+             }| {
+                let method_ident = &method.sig.ident;
+                let eval_result = eval_result(method, &attributes.format, &quote! {property_name});
+                quote! {
+                    #name => {
+                        let result = #callee_prefix::#method_ident(this);
+                        #eval_result
+                        #arm_result
+                    }
+                }
+            },
+        )
+        .collect();
+
+    let setter_matches: Vec<_> = properties
+        .iter()
+        .map(|property| {
+            let name = &property.name;
+            match find_setter(&interactive_methods, name) {
+                Some(setter) => {
+                    let setter_ident = &setter.method.sig.ident;
+                    let setter_name = &setter.name;
+                    let setter_call = gen_method_call(
+                        setter.method,
+                        &quote! {#callee_prefix::#setter_ident},
+                        &Some(quote! {this}),
+                        &setter.attributes.format,
+                        ArgsSource::Parsed,
+                    );
+                    quote! {
+                        #name => {
+                            let method_name = #setter_name;
+                            let args = value;
+                            #setter_call
+                            #arm_result
+                        }
+                    }
+                }
+                None => quote! {
+                    #name => {
+                        f(::core::result::Result::Err(::rusteval::InteractiveError::PropertyNotWritable {
+                            property_name,
+                        }));
+                        #arm_result
+                    }
+                },
+            }
+        })
+        .collect();
+
+    let property_signatures: Vec<_> = properties
+        .iter()
+        .map(|InteractiveMethod { method, name, .. }| {
+            let signature = gen_signature(method, name);
+            quote! {
+                #name => ::core::option::Option::Some(&#signature),
+            }
         })
-        .map(gen_method_match_expr);
+        .collect();
 
-    let method_mut_matches = interactive_methods.iter().map(gen_method_match_expr);
-    // TODO don't duplicate &self methods
+    let all_property_names = properties
+        .iter()
+        .filter(|property| !property.attributes.hidden)
+        .map(|InteractiveMethod { name, .. }| {
+            quote! {
+                #name,
+            }
+        });
+
+    let all_writable_property_names = properties
+        .iter()
+        .filter(|property| !property.attributes.hidden)
+        .filter(|property| find_setter(&interactive_methods, &property.name).is_some())
+        .map(|InteractiveMethod { name, .. }| {
+            quote! {
+                #name,
+            }
+        });
+
+    if registered {
+        let fragment_name = &Ident::new(
+            &format!("MethodsFragment{}", hash(&quote! {#ast})),
+            ast.span(),
+        );
+
+        let forward_eval_method = forward_shared(quote! {
+            ::rusteval::registry::MethodsFragment::eval_method(self, &*this, method_name, args, f)
+        });
+        let forward_call_typed_method = forward_shared(quote! {
+            ::rusteval::registry::MethodsFragment::call_typed_method(self, &*this, method_name, args, f)
+        });
+
+        // the type has to implement `Methods` by dispatching to the registry, otherwise this impl block is unreachable
+        let primary_impl = if options.primary {
+            dispatching_methods_impl(&quote! {#self_ty}, &ast.generics, true, false)
+        } else {
+            quote_spanned! {self_ty.span()=>
+                const _: fn() = ::rusteval::registry::assert_registered::<#self_ty>;
+            }
+        };
 
-    let all_method_names = interactive_methods.iter().map(|method| {
-        let name = &method.sig.ident;
         quote! {
-            stringify!(#name),
+            #errors
+
+            #primary_impl
+
+            struct #fragment_name;
+
+            impl ::rusteval::registry::MethodsFragment for #fragment_name {
+                fn self_type_id(&self) -> ::core::any::TypeId {
+                    ::core::any::TypeId::of::<#self_ty>()
+                }
+
+                fn eval_method(
+                    &self,
+                    object: &dyn ::core::any::Any,
+                    method_name: &str,
+                    args: &str,
+                    f: &mut dyn ::core::ops::FnMut(::rusteval::Result<'_, &dyn ::rusteval::Output>),
+                ) -> bool {
+                    let this = match <dyn ::core::any::Any>::downcast_ref::<#self_ty>(object) {
+                        ::core::option::Option::Some(this) => this,
+                        ::core::option::Option::None => return false,
+                    };
+                    match method_name {
+                        #(#method_matches)*
+
+                        _ => false,
+                    }
+                }
+
+                fn eval_method_mut(
+                    &self,
+                    object: &mut dyn ::core::any::Any,
+                    method_name: &str,
+                    args: &str,
+                    f: &mut dyn ::core::ops::FnMut(::rusteval::Result<'_, &dyn ::rusteval::Output>),
+                ) -> bool {
+                    let this = match <dyn ::core::any::Any>::downcast_mut::<#self_ty>(object) {
+                        ::core::option::Option::Some(this) => this,
+                        ::core::option::Option::None => return false,
+                    };
+                    match method_name {
+                        #(#method_mut_matches)*
+                        #forward_eval_method
+
+                        _ => false,
+                    }
+                }
+
+                fn call_typed_method(
+                    &self,
+                    object: &dyn ::core::any::Any,
+                    method_name: &str,
+                    args: &mut ::rusteval::TypedArgs<'_>,
+                    f: &mut dyn ::core::ops::FnMut(::rusteval::Result<'_, &dyn ::rusteval::Output>),
+                ) -> bool {
+                    let this = match <dyn ::core::any::Any>::downcast_ref::<#self_ty>(object) {
+                        ::core::option::Option::Some(this) => this,
+                        ::core::option::Option::None => return false,
+                    };
+                    match method_name {
+                        #(#typed_method_matches)*
+
+                        _ => false,
+                    }
+                }
+
+                fn call_typed_method_mut(
+                    &self,
+                    object: &mut dyn ::core::any::Any,
+                    method_name: &str,
+                    args: &mut ::rusteval::TypedArgs<'_>,
+                    f: &mut dyn ::core::ops::FnMut(::rusteval::Result<'_, &dyn ::rusteval::Output>),
+                ) -> bool {
+                    let this = match <dyn ::core::any::Any>::downcast_mut::<#self_ty>(object) {
+                        ::core::option::Option::Some(this) => this,
+                        ::core::option::Option::None => return false,
+                    };
+                    match method_name {
+                        #(#typed_method_mut_matches)*
+                        #forward_call_typed_method
+
+                        _ => false,
+                    }
+                }
+
+                fn eval_property(
+                    &self,
+                    object: &dyn ::core::any::Any,
+                    property_name: &str,
+                    f: &mut dyn ::core::ops::FnMut(::rusteval::Result<'_, &dyn ::rusteval::Output>),
+                ) -> bool {
+                    let this = match <dyn ::core::any::Any>::downcast_ref::<#self_ty>(object) {
+                        ::core::option::Option::Some(this) => this,
+                        ::core::option::Option::None => return false,
+                    };
+                    match property_name {
+                        #(#property_matches)*
+
+                        _ => false,
+                    }
+                }
+
+                fn set_property(
+                    &self,
+                    object: &mut dyn ::core::any::Any,
+                    property_name: &str,
+                    value: &str,
+                    f: &mut dyn ::core::ops::FnMut(::rusteval::Result<'_, &dyn ::rusteval::Output>),
+                ) -> bool {
+                    let this = match <dyn ::core::any::Any>::downcast_mut::<#self_ty>(object) {
+                        ::core::option::Option::Some(this) => this,
+                        ::core::option::Option::None => return false,
+                    };
+                    match property_name {
+                        #(#setter_matches)*
+
+                        _ => false,
+                    }
+                }
+
+                fn get_all_method_names(&self) -> &'static [&'static str] {
+                    &[#(#all_method_names)*]
+                }
+
+                fn get_all_method_signatures(&self) -> &'static [::rusteval::Signature] {
+                    &[#(#all_method_signatures)*]
+                }
+
+                fn get_all_property_names(&self) -> &'static [&'static str] {
+                    &[#(#all_property_names)*]
+                }
+
+                fn get_all_writable_property_names(&self) -> &'static [&'static str] {
+                    &[#(#all_writable_property_names)*]
+                }
+
+                fn get_property_signature(&self, property_name: &str) -> ::core::option::Option<&'static ::rusteval::Signature> {
+                    match property_name {
+                        #(#property_signatures)*
+
+                        _ => ::core::option::Option::None,
+                    }
+                }
+
+                fn get_all_skipped_methods(&self) -> &'static [::rusteval::SkippedMethod] {
+                    &[#(#all_skipped_methods)*]
+                }
+            }
+
+            ::rusteval::inventory::submit! {
+                &#fragment_name as &dyn ::rusteval::registry::MethodsFragment
+            }
         }
-    });
+    } else {
+        let (impl_generics, _, where_clause) = ast.generics.split_for_impl();
 
-    let expanded = quote! {
-        #original_impl
+        let forward_eval_method = forward_shared(quote! {
+            ::rusteval::Methods::eval_method_output(&*this, method_name, args, f)
+        });
+        let forward_call_typed_method = forward_shared(quote! {
+            ::rusteval::Methods::call_typed_method(&*this, method_name, args, f)
+        });
+
+        quote! {
+            #errors
+
+            impl #impl_generics ::rusteval::Methods for #self_ty #where_clause {
+                fn eval_method(
+                    &self,
+                    method_name: &str,
+                    args: &str,
+                    f: &mut dyn ::core::ops::FnMut(::rusteval::Result<'_, &dyn ::core::fmt::Debug>),
+                )
+                {
+                    ::rusteval::Methods::eval_method_output(self, method_name, args, &mut |result| {
+                        match result {
+                        ::core::result::Result::Ok(value) => f(::core::result::Result::Ok(value)),
+                        ::core::result::Result::Err(e) => f(::core::result::Result::Err(e)),
+                    }
+                    })
+                }
+
+                fn eval_method_mut(
+                    &mut self,
+                    method_name: &str,
+                    args: &str,
+                    f: &mut dyn ::core::ops::FnMut(::rusteval::Result<'_, &dyn ::core::fmt::Debug>),
+                )
+                {
+                    ::rusteval::Methods::eval_method_mut_output(self, method_name, args, &mut |result| {
+                        match result {
+                        ::core::result::Result::Ok(value) => f(::core::result::Result::Ok(value)),
+                        ::core::result::Result::Err(e) => f(::core::result::Result::Err(e)),
+                    }
+                    })
+                }
 
-        impl #impl_generics ::rusteval::Methods for #struct_name #where_clause{
+                fn eval_method_output(
+                    &self,
+                    method_name: &str,
+                    args: &str,
+                    f: &mut dyn ::core::ops::FnMut(::rusteval::Result<'_, &dyn ::rusteval::Output>),
+                )
+                {
+                    let this = self;
+                    match method_name {
+                        #(#method_matches)*
+
+                        _ => {
+                            if !::rusteval::flatten::eval_method(this, method_name, args, f) {
+                                f(::core::result::Result::Err(::rusteval::InteractiveError::MethodNotFound {
+                                    type_name: stringify!(#self_ty),
+                                    method_name,
+                                    suggestion: ::rusteval::did_you_mean(method_name, ::rusteval::Methods::get_all_method_names(this)),
+                                }))
+                            }
+                        }
+                    }
+                }
+
+                fn eval_method_mut_output(
+                    &mut self,
+                    method_name: &str,
+                    args: &str,
+                    f: &mut dyn ::core::ops::FnMut(::rusteval::Result<'_, &dyn ::rusteval::Output>),
+                )
+                {
+                    let this = self;
+                    match method_name {
+                        #(#method_mut_matches)*
+                        #forward_eval_method
+
+                        _ => {
+                            if !::rusteval::flatten::eval_method_mut(this, method_name, args, f) {
+                                f(::core::result::Result::Err(::rusteval::InteractiveError::MethodNotFound {
+                                    type_name: stringify!(#self_ty),
+                                    method_name,
+                                    suggestion: ::rusteval::did_you_mean(method_name, ::rusteval::Methods::get_all_method_names(this)),
+                                }))
+                            }
+                        }
+                    }
+                }
+
+                fn call_typed_method(
+                    &self,
+                    method_name: &str,
+                    args: &mut ::rusteval::TypedArgs<'_>,
+                    f: &mut dyn ::core::ops::FnMut(::rusteval::Result<'_, &dyn ::rusteval::Output>),
+                )
+                {
+                    let this = self;
+                    match method_name {
+                        #(#typed_method_matches)*
+
+                        _ => {
+                            if !::rusteval::flatten::call_typed_method(this, method_name, args, f) {
+                                f(::core::result::Result::Err(::rusteval::InteractiveError::MethodNotFound {
+                                    type_name: stringify!(#self_ty),
+                                    method_name,
+                                    suggestion: ::rusteval::did_you_mean(method_name, ::rusteval::Methods::get_all_method_names(this)),
+                                }))
+                            }
+                        }
+                    }
+                }
+
+                fn call_typed_method_mut(
+                    &mut self,
+                    method_name: &str,
+                    args: &mut ::rusteval::TypedArgs<'_>,
+                    f: &mut dyn ::core::ops::FnMut(::rusteval::Result<'_, &dyn ::rusteval::Output>),
+                )
+                {
+                    let this = self;
+                    match method_name {
+                        #(#typed_method_mut_matches)*
+                        #forward_call_typed_method
+
+                        _ => {
+                            if !::rusteval::flatten::call_typed_method_mut(this, method_name, args, f) {
+                                f(::core::result::Result::Err(::rusteval::InteractiveError::MethodNotFound {
+                                    type_name: stringify!(#self_ty),
+                                    method_name,
+                                    suggestion: ::rusteval::did_you_mean(method_name, ::rusteval::Methods::get_all_method_names(this)),
+                                }))
+                            }
+                        }
+                    }
+                }
+
+                fn eval_property(
+                    &self,
+                    property_name: &str,
+                    f: &mut dyn ::core::ops::FnMut(::rusteval::Result<'_, &dyn ::rusteval::Output>),
+                ) {
+                    let this = self;
+                    match property_name {
+                        #(#property_matches)*
+
+                        _ => f(::core::result::Result::Err(::rusteval::InteractiveError::FieldNotFound {
+                            type_name: stringify!(#self_ty),
+                            field_name: property_name,
+                            suggestion: ::rusteval::did_you_mean(property_name, ::rusteval::Methods::get_all_property_names(this)),
+                        })),
+                    }
+                }
+
+                fn set_property(
+                    &mut self,
+                    property_name: &str,
+                    value: &str,
+                    f: &mut dyn ::core::ops::FnMut(::rusteval::Result<'_, &dyn ::rusteval::Output>),
+                ) {
+                    let this = self;
+                    match property_name {
+                        #(#setter_matches)*
+
+                        _ => {
+                            if !::rusteval::flatten::set_property(this, property_name, value, f) {
+                                f(::core::result::Result::Err(::rusteval::InteractiveError::FieldNotFound {
+                                    type_name: stringify!(#self_ty),
+                                    field_name: property_name,
+                                    suggestion: ::rusteval::did_you_mean(property_name, ::rusteval::Methods::get_all_property_names(this)),
+                                }))
+                            }
+                        }
+                    }
+                }
+
+                fn get_all_method_names(&self) -> &'static [&'static str] {
+                    &[#(#all_method_names)*]
+                }
+
+                fn get_all_method_signatures(&self) -> &'static [::rusteval::Signature] {
+                    &[#(#all_method_signatures)*]
+                }
+
+                fn get_all_property_names(&self) -> &'static [&'static str] {
+                    &[#(#all_property_names)*]
+                }
+
+                fn get_all_writable_property_names(&self) -> &'static [&'static str] {
+                    &[#(#all_writable_property_names)*]
+                }
+
+                fn get_property_signature(&self, property_name: &str) -> ::core::option::Option<&'static ::rusteval::Signature> {
+                    match property_name {
+                        #(#property_signatures)*
+
+                        _ => ::core::option::Option::None,
+                    }
+                }
+
+                fn get_all_skipped_methods(&self) -> &'static [::rusteval::SkippedMethod] {
+                    &[#(#all_skipped_methods)*]
+                }
+            }
+        }
+    }
+}
+
+/// Implements `Methods` for `self_ty` by dispatching to its registered `#[Methods]` impl blocks,
+/// then to its flattened fields and, for a root, to the free functions.
+///
+/// Generic types can't look up their impl blocks, so without `registry` they are skipped.
+pub fn dispatching_methods_impl(
+    self_ty: &TokenStream2,
+    generics: &Generics,
+    registry: bool,
+    root: bool,
+) -> TokenStream2 {
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let type_id = quote! { ::core::any::TypeId::of::<#self_ty>() };
+
+    let dispatch = |call: TokenStream2| {
+        let registered = if registry {
+            quote! {
+                if ::rusteval::registry::#call {
+                    return;
+                }
+            }
+        } else {
+            TokenStream2::new()
+        };
+        quote! {
+            #registered
+
+            if ::rusteval::flatten::#call {
+                return;
+            }
+        }
+    };
+    let eval_method = dispatch(quote! { eval_method(self, method_name, args, f) });
+    let eval_method_mut = dispatch(quote! { eval_method_mut(self, method_name, args, f) });
+    let call_typed_method = dispatch(quote! { call_typed_method(self, method_name, args, f) });
+    let call_typed_method_mut =
+        dispatch(quote! { call_typed_method_mut(self, method_name, args, f) });
+
+    let not_found = quote! {
+        f(::core::result::Result::Err(::rusteval::InteractiveError::MethodNotFound {
+            type_name: stringify!(#self_ty),
+            method_name,
+            suggestion: ::rusteval::did_you_mean(method_name, ::rusteval::Methods::get_all_method_names(self)),
+        }))
+    };
+    let call_function = |call: TokenStream2| {
+        quote! {
+            if let ::core::option::Option::Some(function) = ::core::iter::Iterator::find(
+                &mut ::core::iter::IntoIterator::into_iter(
+                    ::rusteval::inventory::iter::<&dyn ::rusteval::Function>,
+                ),
+                |function| function.function_name() == method_name,
+            ) {
+                function.#call
+            } else {
+                f(::core::result::Result::Err(::rusteval::InteractiveError::FunctionNotFound {
+                    function_name: method_name,
+                    suggestion: ::rusteval::did_you_mean(method_name, ::rusteval::Methods::get_all_method_names(self)),
+                }))
+            }
+        }
+    };
+    let (eval_method_fallback, eval_method_mut_fallback) = if root {
+        (
+            call_function(quote! { eval_output(args, f) }),
+            quote! { ::rusteval::Methods::eval_method_output(&*self, method_name, args, f) },
+        )
+    } else {
+        (not_found.clone(), not_found.clone())
+    };
+    let (call_typed_method_fallback, call_typed_method_mut_fallback) = if root {
+        (
+            call_function(quote! { call(args, f) }),
+            quote! { ::rusteval::Methods::call_typed_method(&*self, method_name, args, f) },
+        )
+    } else {
+        (not_found.clone(), not_found)
+    };
+
+    let collect_functions = |ty: TokenStream2, map: TokenStream2| {
+        quote! {
+            ::core::iter::Iterator::collect::<::std::vec::Vec<#ty>>(::core::iter::Iterator::map(
+                ::core::iter::IntoIterator::into_iter(
+                    ::rusteval::inventory::iter::<&dyn ::rusteval::Function>,
+                ),
+                |function| function.#map(),
+            ))
+        }
+    };
+    // the registered items, followed by the free functions and those of the flattened fields
+    let list = |ty: TokenStream2, get_all: TokenStream2, function_map: TokenStream2| {
+        let functions = collect_functions(ty.clone(), function_map);
+        if !registry {
+            // generic roots can't have a static per type, so they only list their functions
+            return quote! {
+                ::rusteval::lazy_static::lazy_static! {
+                    static ref LIST: ::std::vec::Vec<#ty> = #functions;
+                }
+                &*LIST
+            };
+        }
+        let functions = if root {
+            quote! { &#functions[..], }
+        } else {
+            TokenStream2::new()
+        };
+        cached(
+            ty,
+            quote! {
+                ::rusteval::flatten::#get_all(self, &[
+                    &::rusteval::registry::#get_all(#type_id)[..],
+                    #functions
+                ].concat())
+            },
+        )
+    };
+    let all_method_names = list(
+        quote! { &'static str },
+        quote! { get_all_method_names },
+        quote! { function_name },
+    );
+    let all_method_signatures = list(
+        quote! { ::rusteval::Signature },
+        quote! { get_all_method_signatures },
+        quote! { signature },
+    );
+
+    let registered = if registry {
+        let registered_list = |ty: TokenStream2, get_all: TokenStream2| {
+            cached(ty, quote! { ::rusteval::registry::#get_all(#type_id) })
+        };
+        let all_property_names =
+            registered_list(quote! { &'static str }, quote! { get_all_property_names });
+        let all_writable_property_names = registered_list(
+            quote! { &'static str },
+            quote! { get_all_writable_property_names },
+        );
+        let all_skipped_methods = registered_list(
+            quote! { ::rusteval::SkippedMethod },
+            quote! { get_all_skipped_methods },
+        );
+
+        quote! {
+            fn eval_property(
+                &self,
+                property_name: &str,
+                f: &mut dyn ::core::ops::FnMut(::rusteval::Result<'_, &dyn ::rusteval::Output>),
+            ) {
+                if !::rusteval::registry::eval_property(self, property_name, f) {
+                    f(::core::result::Result::Err(::rusteval::InteractiveError::FieldNotFound {
+                        type_name: stringify!(#self_ty),
+                        field_name: property_name,
+                        suggestion: ::rusteval::did_you_mean(property_name, ::rusteval::Methods::get_all_property_names(self)),
+                    }))
+                }
+            }
+
+            fn set_property(
+                &mut self,
+                property_name: &str,
+                value: &str,
+                f: &mut dyn ::core::ops::FnMut(::rusteval::Result<'_, &dyn ::rusteval::Output>),
+            ) {
+                if !::rusteval::registry::set_property(self, property_name, value, f)
+                    && !::rusteval::flatten::set_property(self, property_name, value, f)
+                {
+                    f(::core::result::Result::Err(::rusteval::InteractiveError::FieldNotFound {
+                        type_name: stringify!(#self_ty),
+                        field_name: property_name,
+                        suggestion: ::rusteval::did_you_mean(property_name, ::rusteval::Methods::get_all_property_names(self)),
+                    }))
+                }
+            }
+
+            fn get_all_property_names(&self) -> &'static [&'static str] {
+                #all_property_names
+            }
+
+            fn get_all_writable_property_names(&self) -> &'static [&'static str] {
+                #all_writable_property_names
+            }
+
+            fn get_property_signature(&self, property_name: &str) -> ::core::option::Option<&'static ::rusteval::Signature> {
+                ::rusteval::registry::get_property_signature(#type_id, property_name)
+            }
+
+            fn get_all_skipped_methods(&self) -> &'static [::rusteval::SkippedMethod] {
+                #all_skipped_methods
+            }
+        }
+    } else {
+        TokenStream2::new()
+    };
+
+    let registered_methods_impl = if registry {
+        quote! {
+            impl ::rusteval::registry::RegisteredMethods for #self_ty {}
+        }
+    } else {
+        TokenStream2::new()
+    };
+
+    quote! {
+        #registered_methods_impl
+
+        impl #impl_generics ::rusteval::Methods for #self_ty #where_clause {
             fn eval_method(
                 &self,
                 method_name: &str,
                 args: &str,
                 f: &mut dyn ::core::ops::FnMut(::rusteval::Result<'_, &dyn ::core::fmt::Debug>),
-            )
-            {
-                match method_name {
-                    #(#method_matches)*
-
-                    _ => f(::core::result::Result::Err(::rusteval::InteractiveError::MethodNotFound {
-                        type_name: stringify!(#struct_name),
-                        method_name,
-                    })),
-                }
+            ) {
+                ::rusteval::Methods::eval_method_output(self, method_name, args, &mut |result| {
+                    match result {
+                        ::core::result::Result::Ok(value) => f(::core::result::Result::Ok(value)),
+                        ::core::result::Result::Err(e) => f(::core::result::Result::Err(e)),
+                    }
+                })
             }
 
             fn eval_method_mut(
@@ -81,37 +940,99 @@ pub fn methods(input: TokenStream) -> TokenStream {
                 method_name: &str,
                 args: &str,
                 f: &mut dyn ::core::ops::FnMut(::rusteval::Result<'_, &dyn ::core::fmt::Debug>),
-            )
-            {
-                match method_name {
-                    #(#method_mut_matches)*
+            ) {
+                ::rusteval::Methods::eval_method_mut_output(self, method_name, args, &mut |result| {
+                    match result {
+                        ::core::result::Result::Ok(value) => f(::core::result::Result::Ok(value)),
+                        ::core::result::Result::Err(e) => f(::core::result::Result::Err(e)),
+                    }
+                })
+            }
 
-                    _ => f(::core::result::Result::Err(::rusteval::InteractiveError::MethodNotFound {
-                        type_name: stringify!(#struct_name),
-                        method_name,
-                    })),
-                }
+            fn eval_method_output(
+                &self,
+                method_name: &str,
+                args: &str,
+                f: &mut dyn ::core::ops::FnMut(::rusteval::Result<'_, &dyn ::rusteval::Output>),
+            ) {
+                #eval_method
+
+                #eval_method_fallback
             }
 
-            fn get_all_method_names(&self) -> &'static [&'static str]{
-                &[#(#all_method_names)*]
+            fn eval_method_mut_output(
+                &mut self,
+                method_name: &str,
+                args: &str,
+                f: &mut dyn ::core::ops::FnMut(::rusteval::Result<'_, &dyn ::rusteval::Output>),
+            ) {
+                #eval_method_mut
+
+                #eval_method_mut_fallback
             }
-        }
-    };
 
-    expanded.into()
+            fn call_typed_method(
+                &self,
+                method_name: &str,
+                args: &mut ::rusteval::TypedArgs<'_>,
+                f: &mut dyn ::core::ops::FnMut(::rusteval::Result<'_, &dyn ::rusteval::Output>),
+            ) {
+                #call_typed_method
+
+                #call_typed_method_fallback
+            }
+
+            fn call_typed_method_mut(
+                &mut self,
+                method_name: &str,
+                args: &mut ::rusteval::TypedArgs<'_>,
+                f: &mut dyn ::core::ops::FnMut(::rusteval::Result<'_, &dyn ::rusteval::Output>),
+            ) {
+                #call_typed_method_mut
+
+                #call_typed_method_mut_fallback
+            }
+
+            fn get_all_method_names(&self) -> &'static [&'static str] {
+                #all_method_names
+            }
+
+            fn get_all_method_signatures(&self) -> &'static [::rusteval::Signature] {
+                #all_method_signatures
+            }
+
+            #registered
+        }
+    }
 }
 
 pub fn function(input: TokenStream) -> TokenStream {
     let original_func = TokenStream2::from(input.clone());
 
-    let struct_name = &Ident::new(&format!("Function{}", hash(&input)), original_func.span());
+    let struct_name = &Ident::new(
+        &format!("Function{}", hash(&original_func)),
+        original_func.span(),
+    );
 
     let ast = parse_macro_input!(input as ImplItemMethod);
 
     let function_name = &ast.sig.ident;
 
-    let method_call = gen_method_call(&ast, &None);
+    let method_call = gen_method_call(
+        &ast,
+        &quote! {#function_name},
+        &None,
+        &Format::default(),
+        ArgsSource::Parsed,
+    );
+    let typed_call = gen_method_call(
+        &ast,
+        &quote! {#function_name},
+        &None,
+        &Format::default(),
+        ArgsSource::Typed,
+    );
+    let signature = gen_signature(&ast, &function_name.to_string());
 
     let expanded = quote! {
         #original_func
@@ -120,12 +1041,30 @@ pub fn function(input: TokenStream) -> TokenStream {
 
         impl ::rusteval::Function for #struct_name{
             fn eval(&self, args: &str, f: &mut dyn ::core::ops::FnMut(::rusteval::Result<'_, &dyn ::core::fmt::Debug>)) {
+                ::rusteval::Function::eval_output(self, args, &mut |result| {
+                    match result {
+                        ::core::result::Result::Ok(value) => f(::core::result::Result::Ok(value)),
+                        ::core::result::Result::Err(e) => f(::core::result::Result::Err(e)),
+                    }
+                })
+            }
+            fn eval_output(&self, args: &str, f: &mut dyn ::core::ops::FnMut(::rusteval::Result<'_, &dyn ::rusteval::Output>)) {
                 let method_name = self.function_name();
                 #method_call
             }
+            fn call(&self, args: &mut ::rusteval::TypedArgs<'_>, f: &mut dyn ::core::ops::FnMut(::rusteval::Result<'_, &dyn ::rusteval::Output>)) {
+                let method_name = self.function_name();
+                #typed_call
+            }
             fn function_name(&self) -> &'static str{
                 stringify!(#function_name)
             }
+            fn module_path(&self) -> &'static str {
+                ::core::module_path!()
+            }
+            fn signature(&self) -> ::rusteval::Signature {
+                #signature
+            }
         }
 
         ::rusteval::inventory::submit! {
@@ -136,23 +1075,218 @@ pub fn function(input: TokenStream) -> TokenStream {
     expanded.into()
 }
 
-fn is_interactive_method(method: &&ImplItemMethod) -> bool {
-    // skip associated functions
-    // skip methods with argument types that are not supported
+/// A method together with its `#[interactive(...)]` options.
+struct InteractiveMethod<'a> {
+    method: &'a ImplItemMethod,
+    /// Used to call the method in a query
+    name: String,
+    attributes: MethodAttributes,
+}
+
+/// A method that can't be made interactive.
+struct SkippedMethod<'a> {
+    method: &'a ImplItemMethod,
+    reason: String,
+    /// The argument that is to blame
+    arg: &'a FnArg,
+}
+
+/// Sorts the methods of an impl block into interactive and skipped methods.
+///
+/// Associated functions and methods marked with `#[interactive(skip)]` are in neither.
+fn collect_methods(ast: &ItemImpl) -> Result<(Vec<InteractiveMethod<'_>>, Vec<SkippedMethod<'_>>)> {
+    let mut interactive_methods: Vec<InteractiveMethod<'_>> = Vec::new();
+    let mut skipped_methods = Vec::new();
+
+    let methods = ast.items.iter().filter_map(|item| match item {
+        ImplItem::Method(method) => Some(method),
+        _ => None,
+    });
+
+    for method in methods.filter(has_receiver) {
+        let attributes = MethodAttributes::parse(&method.attrs)?;
+        if attributes.skip {
+            continue;
+        }
+
+        if let Some(arg) = find_unsupported_arg(method) {
+            skipped_methods.push(SkippedMethod {
+                method,
+                reason: skip_reason(arg),
+                arg,
+            });
+            continue;
+        }
+
+        let name = match &attributes.rename {
+            Some(rename) => rename.clone(),
+            None => method.sig.ident.to_string(),
+        };
+
+        if interactive_methods.iter().any(|other| other.name == name) {
+            return Err(Error::new(
+                method.sig.ident.span(),
+                format!("duplicate interactive method name `{}`", name),
+            ));
+        }
+
+        if attributes.property && !(is_ref_self(method, false) && method.sig.inputs.len() == 1) {
+            return Err(Error::new(
+                method.sig.span(),
+                "a `property` has to take `&self` and no other arguments",
+            ));
+        }
+
+        if attributes.setter.is_some()
+            && !(is_ref_self(method, true) && method.sig.inputs.len() == 2)
+        {
+            return Err(Error::new(
+                method.sig.span(),
+                "a `setter` has to take `&mut self` and exactly one other argument",
+            ));
+        }
+
+        interactive_methods.push(InteractiveMethod {
+            method,
+            name,
+            attributes,
+        });
+    }
+
+    // every setter needs exactly one property
+    for (i, method) in interactive_methods.iter().enumerate() {
+        if let Some(property_name) = &method.attributes.setter {
+            if find_property(&interactive_methods, property_name).is_none() {
+                return Err(Error::new(
+                    method.method.sig.ident.span(),
+                    format!("no property `{}` found in this impl block", property_name),
+                ));
+            }
+            if find_setter(&interactive_methods[..i], property_name).is_some() {
+                return Err(Error::new(
+                    method.method.sig.ident.span(),
+                    format!("duplicate setter for property `{}`", property_name),
+                ));
+            }
+        }
+    }
+
+    Ok((interactive_methods, skipped_methods))
+}
+
+fn find_property<'a, 'b>(
+    methods: &'a [InteractiveMethod<'b>],
+    property_name: &str,
+) -> Option<&'a InteractiveMethod<'b>> {
+    methods
+        .iter()
+        .find(|method| method.attributes.property && method.name == property_name)
+}
+
+fn find_setter<'a, 'b>(
+    methods: &'a [InteractiveMethod<'b>],
+    property_name: &str,
+) -> Option<&'a InteractiveMethod<'b>> {
+    methods
+        .iter()
+        .find(|method| method.attributes.setter.as_deref() == Some(property_name))
+}
 
+/// true for `&self` or `&mut self` depending on `mutable`
+fn is_ref_self(method: &ImplItemMethod, mutable: bool) -> bool {
+    matches!(
+        method.sig.receiver(),
+        Some(FnArg::Receiver(Receiver {
+            reference: Some(_),
+            mutability,
+            ..
+        })) if mutability.is_some() == mutable
+    )
+}
+
+/// Associated functions are never interactive
+fn has_receiver(method: &&ImplItemMethod) -> bool {
     matches!(method.sig.inputs.first(), Some(FnArg::Receiver(_)))
-        && method.sig.inputs.iter().skip(1).all(is_supported_fn_arg)
 }
 
-fn gen_method_match_expr(method: &&ImplItemMethod) -> TokenStream2 {
+/// Methods with argument types that are not supported are skipped
+fn find_unsupported_arg(method: &ImplItemMethod) -> Option<&FnArg> {
+    method
+        .sig
+        .inputs
+        .iter()
+        .skip(1)
+        .find(|arg| !is_supported_fn_arg(arg))
+}
+
+/// parameter `data` has unsupported type `&[u8]`
+fn skip_reason(arg: &FnArg) -> String {
+    match arg {
+        FnArg::Typed(PatType { pat, ty, .. }) => format!(
+            "parameter `{}` has unsupported type `{}`",
+            type_string(&quote! {#pat}),
+            type_string(&quote! {#ty})
+        ),
+        FnArg::Receiver(_) => "unsupported receiver".to_owned(),
+    }
+}
+
+/// Renders tokens like `& mut Vec < u8 >` as `&mut Vec<u8>`.
+pub fn type_string(tokens: &TokenStream2) -> String {
+    let string = tokens.to_string();
+    let mut result = String::with_capacity(string.len());
+    let mut chars = string.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c == ' ' {
+            let prev = result.chars().last();
+            let next = chars.peek().copied();
+            let keep = match (prev, next) {
+                (Some(prev), Some(next)) => {
+                    prev == ',' || (is_ident_char(prev) && is_ident_char(next))
+                }
+                _ => false,
+            };
+            if !keep {
+                continue;
+            }
+        }
+        result.push(c);
+    }
+    result
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+fn gen_method_match_expr(
+    InteractiveMethod {
+        method,
+        name,
+        attributes,
+    }: &InteractiveMethod<'_>,
+    callee_prefix: &TokenStream2,
+    arm_result: &TokenStream2,
+    source: ArgsSource,
+) -> TokenStream2 {
     let method_ident = &method.sig.ident;
-    let receiver = Some(quote! {self.});
+    let callee = quote! {#callee_prefix::#method_ident};
+
+    // `this` is a reference to the object, methods taking `self` by value need a copy of it
+    let receiver = match method.sig.receiver() {
+        Some(FnArg::Receiver(Receiver {
+            reference: None, ..
+        })) => quote! {*this},
+        _ => quote! {this},
+    };
 
-    let method_call = gen_method_call(method, &receiver);
+    let method_call = gen_method_call(method, &callee, &Some(receiver), &attributes.format, source);
 
     quote! {
-        stringify!(#method_ident) => {
+        #name => {
             #method_call
+            #arm_result
         }
     }
 }
@@ -166,17 +1300,31 @@ fn get_expected_arg_len(method: &ImplItemMethod, receiver: &Option<TokenStream2>
     }
 }
 
+/// Where the arguments of a generated method call come from.
+#[derive(Clone, Copy)]
+enum ArgsSource {
+    /// Parsed from the `args: &str` in scope.
+    Parsed,
+    /// Taken from the `args: &mut TypedArgs<'_>` in scope.
+    Typed,
+}
+
 /// Generate something like this:
 /// ```ignore
-/// "func" => match ::rusteval::arg_parse::parse_2_args(method_name, args) {
-///     Ok((arg0, arg1, mut arg2)) => f(Ok(&self.add(arg0, &arg1, &mut arg2))),
+/// "func" => match ::rusteval::arg_parse::parse_3_args(method_name, args, &[("a", "u32"), ("b", "u32"), ("c", "u32")]) {
+///     Ok((arg0, arg1, mut arg2)) => f(Ok(&<Struct>::add(this, arg0, &arg1, &mut arg2))),
 ///     Err(e) => f(Err(e)),
 /// },
 /// ```
 ///
-fn gen_method_call(method: &ImplItemMethod, receiver: &Option<TokenStream2>) -> TokenStream2 {
-    let method_ident = &method.sig.ident;
-
+/// With [`ArgsSource::Typed`] the arguments are taken as a `(u32, u32, u32)` tuple instead of being parsed.
+fn gen_method_call(
+    method: &ImplItemMethod,
+    callee: &TokenStream2,
+    receiver: &Option<TokenStream2>,
+    format: &Format,
+    source: ArgsSource,
+) -> TokenStream2 {
     let expected_arg_len = get_expected_arg_len(method, receiver);
     let args = if expected_arg_len == 1 { "arg" } else { "args" };
     let parse_func = Ident::new(
@@ -197,12 +1345,13 @@ fn gen_method_call(method: &ImplItemMethod, receiver: &Option<TokenStream2>) ->
             (
                 Ident::new(&format!("arg{}", arg_num), arg.span()),
                 reference_tokens(arg),
+                arg,
             )
         })
         .collect();
 
     // arg0, arg1, mut arg2
-    let tuple_args = arg_names.iter().map(|(arg_name, ref_tokens)| {
+    let tuple_args = arg_names.iter().map(|(arg_name, ref_tokens, _)| {
         let mut_token = match ref_tokens {
             ReferenceTokens::NotStr { mut_token, .. } => mut_token,
             ReferenceTokens::Str { mut_token } => mut_token,
@@ -214,7 +1363,7 @@ fn gen_method_call(method: &ImplItemMethod, receiver: &Option<TokenStream2>) ->
     // arg0, &arg1, &mut arg2
     let call_args = arg_names
         .iter()
-        .map(|(arg_name, ref_tokens)| match ref_tokens {
+        .map(|(arg_name, ref_tokens, _)| match ref_tokens {
             ReferenceTokens::NotStr {
                 and_token,
                 mut_token,
@@ -231,17 +1380,86 @@ fn gen_method_call(method: &ImplItemMethod, receiver: &Option<TokenStream2>) ->
             },
         });
 
+    // this, arg0, &arg1, &mut arg2
+    let receiver_arg = receiver.as_ref().map(|receiver| quote! {#receiver,});
+
+    let eval_result = eval_result(method, format, &quote! {method_name});
+
+    let args = match source {
+        ArgsSource::Parsed => {
+            let params = arg_names.iter().map(|(_, _, arg)| param_names(arg));
+            quote! {
+                ::rusteval::arg_parse::#parse_func(method_name, args, &[#(#params,)*])
+            }
+        }
+        ArgsSource::Typed => {
+            let owned_types = arg_names.iter().map(|(_, _, arg)| owned_arg_type(arg));
+            quote! {
+                ::rusteval::TypedArgs::take::<(#(#owned_types,)*)>(args, method_name)
+            }
+        }
+    };
+
     quote! {
-        match ::rusteval::arg_parse::#parse_func(method_name, args){
+        match #args {
             ::core::result::Result::Ok((#(#tuple_args)*)) => {
-                let result = #receiver #method_ident(#(#call_args)*);
-                f(::rusteval::specialization::AsDebug::try_as_debug(&result))
+                let result = #callee(#receiver_arg #(#call_args)*);
+                #eval_result
             },
             ::core::result::Result::Err(e) => f(::core::result::Result::Err(e)),
         }
     }
 }
 
+/// Passes `result` to the closure `f`, shown in the given format.
+///
+/// If the method returns a `Result` it is passed as a `MethodResult`,
+/// with the format applied to the `Ok` value.
+fn eval_result(method: &ImplItemMethod, format: &Format, name: &TokenStream2) -> TokenStream2 {
+    let output = match format.debug_expr(&quote! {result}) {
+        Some(debug_expr) => quote! {
+            ::core::result::Result::Ok(&#debug_expr)
+        },
+        None => quote! {
+            ::rusteval::specialization::AsOutput::try_as_output(&result)
+        },
+    };
+
+    if returns_result(method) {
+        quote! {
+            let type_name = ::core::any::type_name_of_val(&result);
+            match result {
+                ::core::result::Result::Ok(result) => {
+                    ::rusteval::format::method_result(#name, type_name, ::core::result::Result::Ok(#output), f);
+                }
+                ::core::result::Result::Err(error) => {
+                    ::rusteval::format::method_result(
+                        #name,
+                        type_name,
+                        ::core::result::Result::Err(::rusteval::specialization::AsOutput::try_as_output(&error)),
+                        f,
+                    );
+                }
+            }
+        }
+    } else {
+        quote! {
+            f(#output);
+        }
+    }
+}
+
+/// true for return types like `Result<u8, Error>`, `io::Result<()>` or `fmt::Result`
+fn returns_result(method: &ImplItemMethod) -> bool {
+    match &method.sig.output {
+        ReturnType::Type(_, box Type::Path(TypePath { qself: None, path })) => path
+            .segments
+            .last()
+            .map_or(false, |segment| segment.ident == "Result"),
+        _ => false,
+    }
+}
+
 /// true for bool, &bool, &mut bool, etc
 /// false for &&bool or more complicated types like arrays, slices or generic types
 fn is_supported_fn_arg(arg: &FnArg) -> bool {
@@ -263,6 +1481,97 @@ fn is_supported_fn_arg(arg: &FnArg) -> bool {
     }
 }
 
+/// The type a typed argument is passed as:
+/// `u32`, `&u32` and `&mut u32` -> `u32`, `&str` and `&mut str` -> `String`
+fn owned_arg_type(arg: &FnArg) -> TokenStream2 {
+    match arg {
+        FnArg::Typed(PatType {
+            ty: box Type::Reference(TypeReference { elem, .. }),
+            ..
+        }) => match &**elem {
+            Type::Path(type_path) if type_path.path.is_ident("str") => {
+                quote! {::std::string::String}
+            }
+            elem => quote! {#elem},
+        },
+        FnArg::Typed(PatType { ty, .. }) => quote! {#ty},
+        FnArg::Receiver(_) => unreachable!("receivers are not arguments"),
+    }
+}
+
+/// Generate the `Signature` of a method or function, e.g.:
+/// ```ignore
+/// ::rusteval::Signature {
+///     name: "add",
+///     receiver: Some(::rusteval::Receiver::Ref),
+///     params: &[::rusteval::Parameter { name: "a", type_name: "f32" }],
+///     return_type: Some("f32"),
+///     docs: "Adds one.",
+/// }
+/// ```
+fn gen_signature(method: &ImplItemMethod, name: &str) -> TokenStream2 {
+    let receiver = match method.sig.receiver() {
+        Some(FnArg::Receiver(Receiver {
+            reference: None, ..
+        })) => quote! {::core::option::Option::Some(::rusteval::Receiver::Value)},
+        Some(FnArg::Receiver(Receiver {
+            mutability: None, ..
+        })) => quote! {::core::option::Option::Some(::rusteval::Receiver::Ref)},
+        Some(FnArg::Receiver(_)) => {
+            quote! {::core::option::Option::Some(::rusteval::Receiver::RefMut)}
+        }
+        _ => quote! {::core::option::Option::None},
+    };
+
+    let params = method.sig.inputs.iter().filter_map(|arg| match arg {
+        FnArg::Typed(PatType { pat, ty, .. }) => {
+            let name = type_string(&quote! {#pat});
+            let type_name = type_string(&quote! {#ty});
+            Some(quote! {
+                ::rusteval::Parameter { name: #name, type_name: #type_name },
+            })
+        }
+        FnArg::Receiver(_) => None,
+    });
+
+    let return_type = match &method.sig.output {
+        ReturnType::Default => quote! {::core::option::Option::None},
+        ReturnType::Type(_, ty) => {
+            let return_type = type_string(&quote! {#ty});
+            quote! {::core::option::Option::Some(#return_type)}
+        }
+    };
+
+    let docs = doc_comment(&method.attrs);
+
+    quote! {
+        ::rusteval::Signature {
+            name: #name,
+            receiver: #receiver,
+            params: &[#(#params)*],
+            return_type: #return_type,
+            docs: #docs,
+        }
+    }
+}
+
+/// The name and type of an argument as shown in error messages:
+/// `a: u32`, `a: &u32` and `a: &mut u32` -> `("a", "u32")`
+fn param_names(arg: &FnArg) -> TokenStream2 {
+    match arg {
+        FnArg::Typed(PatType { pat, ty, .. }) => {
+            let ty = match &**ty {
+                Type::Reference(TypeReference { elem, .. }) => elem,
+                ty => ty,
+            };
+            let name = type_string(&quote! {#pat});
+            let type_name = type_string(&quote! {#ty});
+            quote! {(#name, #type_name)}
+        }
+        FnArg::Receiver(_) => unreachable!("receivers are not arguments"),
+    }
+}
+
 enum ReferenceTokens<'a> {
     NotStr {
         and_token: Option<&'a Token!(&)>,
@@ -311,7 +1620,7 @@ fn reference_tokens(arg: &FnArg) -> ReferenceTokens<'_> {
     }
 }
 
-fn hash(input: &TokenStream) -> u64 {
+fn hash(input: &TokenStream2) -> u64 {
     use std::collections::hash_map;
     use std::hash::Hasher;
 
@@ -7,28 +7,29 @@
 
 use proc_macro::TokenStream;
 
+mod attributes;
 mod derive;
 mod functions;
 
-#[proc_macro_derive(Interactive)]
+#[proc_macro_derive(Interactive, attributes(interactive))]
 pub fn derive_interactive(input: TokenStream) -> TokenStream {
     derive::derive_interactive(input)
 }
 
-#[proc_macro_derive(InteractiveRoot)]
+#[proc_macro_derive(InteractiveRoot, attributes(interactive))]
 pub fn derive_interactive_root(input: TokenStream) -> TokenStream {
     derive::derive_root(input)
 }
 
-#[proc_macro_derive(PartialDebug)]
+#[proc_macro_derive(PartialDebug, attributes(interactive))]
 pub fn derive_partial_debug(input: TokenStream) -> TokenStream {
     derive::derive_partial_debug(input)
 }
 
 #[allow(non_snake_case)]
 #[proc_macro_attribute]
-pub fn Methods(_attr: TokenStream, input: TokenStream) -> TokenStream {
-    functions::methods(input)
+pub fn Methods(attr: TokenStream, input: TokenStream) -> TokenStream {
+    functions::methods(attr, input)
 }
 
 #[allow(non_snake_case)]
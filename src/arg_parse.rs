@@ -7,17 +7,27 @@ pub trait ArgParse: Sized {
     fn arg_parse(s: &str) -> Result<Self, ArgParseError<'_>>;
 }
 
+/// The name and type of a parameter, e.g. `("b", "f32")`.
+pub type Param<'a> = (&'a str, &'a str);
+
 fn parse_arg<'a, T: ArgParse>(
     method_name: &'a str,
     haystack: &mut &'a str,
-    expected: usize,
-    found: usize,
+    params: &[Param<'a>],
+    arg_index: usize,
 ) -> crate::Result<'a, T> {
-    let arg_str = get_next_arg(method_name, haystack, expected, found)?;
-
-    ArgParse::arg_parse(arg_str).map_err(|e| InteractiveError::ArgParseError {
-        method_name,
-        error: e,
+    let arg_str = get_next_arg(method_name, haystack, params.len(), arg_index)?;
+
+    ArgParse::arg_parse(arg_str).map_err(|e| {
+        let (arg_name, expected) = params[arg_index];
+        InteractiveError::ArgParseError {
+            method_name,
+            arg_index,
+            arg_name,
+            expected,
+            found: arg_str,
+            error: e,
+        }
     })
 }
 
@@ -85,15 +95,11 @@ fn find_next_separator_index(s: &str) -> Option<usize> {
                     return Some(idx);
                 }
             }
-            '\'' => {
-                if !inside_double_quotes {
-                    inside_single_quotes = !inside_single_quotes;
-                }
+            '\'' if !inside_double_quotes => {
+                inside_single_quotes = !inside_single_quotes;
             }
-            '"' => {
-                if !inside_single_quotes {
-                    inside_double_quotes = !inside_double_quotes;
-                }
+            '"' if !inside_single_quotes => {
+                inside_double_quotes = !inside_double_quotes;
             }
             _ => {}
         }
@@ -102,7 +108,11 @@ fn find_next_separator_index(s: &str) -> Option<usize> {
 }
 
 #[allow(missing_docs)]
-pub fn parse_0_args<'a>(method_name: &'a str, mut args: &'a str) -> crate::Result<'a, ()> {
+pub fn parse_0_args<'a>(
+    method_name: &'a str,
+    mut args: &'a str,
+    _params: &[Param<'a>; 0],
+) -> crate::Result<'a, ()> {
     clear_args(method_name, &mut args, 0, 0)
 }
 
@@ -112,8 +122,9 @@ macro_rules! parse_x_args {
         pub fn $funcname<'a, $($TN: ArgParse,)*>(
             method_name: &'a str,
             mut args: &'a str,
+            params: &[Param<'a>; $x],
         ) -> crate::Result<'a, ($($TN,)*)> {
-            $(let $TN  = parse_arg(method_name, &mut args, $x, $i)?;)*
+            $(let $TN  = parse_arg(method_name, &mut args, params, $i)?;)*
             clear_args(method_name, &mut args, $x, $x)?;
             Ok(($($TN,)*))
         }
@@ -278,7 +289,7 @@ mod tests {
     use super::*;
 
     fn test_parse_one_arg<T: ArgParse + PartialEq + core::fmt::Debug>(arg: &str, expected: T) {
-        let result: T = parse_1_arg("", arg).unwrap().0;
+        let result: T = parse_1_arg("", arg, &[("a", "T")]).unwrap().0;
         assert_eq!(result, expected);
     }
 
@@ -342,7 +353,8 @@ mod tests {
 
     #[test]
     fn test_parse_five_args() {
-        let result: (u8, u16, u32, u64, u128) = parse_5_args("", "1, 2, 3, 4, 5").unwrap();
+        let result: (u8, u16, u32, u64, u128) =
+            parse_5_args("", "1, 2, 3, 4, 5", &[("", ""); 5]).unwrap();
         assert_eq!(result, (1, 2, 3, 4, 5));
     }
 
@@ -356,7 +368,8 @@ mod tests {
     #[test]
     fn test_too_many_args() {
         assert_eq!(
-            parse_2_args::<u32, u32>("test", "1, 2, 3, 4").unwrap_err(),
+            parse_2_args::<u32, u32>("test", "1, 2, 3, 4", &[("a", "u32"), ("b", "u32")])
+                .unwrap_err(),
             InteractiveError::WrongNumberOfArguments {
                 method_name: "test",
                 expected: 2,
@@ -368,7 +381,7 @@ mod tests {
     #[test]
     fn test_too_few_args() {
         assert_eq!(
-            parse_2_args::<u32, u32>("test", "1").unwrap_err(),
+            parse_2_args::<u32, u32>("test", "1", &[("a", "u32"), ("b", "u32")]).unwrap_err(),
             InteractiveError::WrongNumberOfArguments {
                 method_name: "test",
                 expected: 2,
@@ -376,4 +389,29 @@ mod tests {
             }
         )
     }
+
+    #[test]
+    fn test_arg_parse_error() {
+        let error =
+            parse_2_args::<f32, f32>("add", "1, \"x\"", &[("a", "f32"), ("b", "f32")]).unwrap_err();
+        assert_eq!(
+            error,
+            InteractiveError::ArgParseError {
+                method_name: "add",
+                arg_index: 1,
+                arg_name: "b",
+                expected: "f32",
+                found: "\"x\"",
+                error: ArgParseError::ParseFloatError("\"x\"".parse::<f32>().unwrap_err()),
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "argument 2 `b` of `add`: expected f32, found `\"x\"`"
+        );
+        assert_eq!(
+            core::error::Error::source(&error).map(|source| source.to_string()),
+            Some("invalid float literal".to_string())
+        );
+    }
 }
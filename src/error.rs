@@ -17,27 +17,187 @@ pub enum InteractiveError<'a> {
     DebugNotImplemented {
         type_name: &'static str,
     },
+    DisplayNotImplemented {
+        type_name: &'static str,
+    },
     FieldNotFound {
         type_name: &'a str,
         field_name: &'a str,
+        /// The closest field name, see [`did_you_mean`](crate::did_you_mean).
+        suggestion: Option<&'static str>,
     },
     MethodNotFound {
         type_name: &'a str,
         method_name: &'a str,
+        /// The closest method name, see [`did_you_mean`](crate::did_you_mean).
+        suggestion: Option<&'static str>,
     },
     FunctionNotFound {
         function_name: &'a str,
+        /// The closest function name, see [`did_you_mean`](crate::did_you_mean).
+        suggestion: Option<&'static str>,
+    },
+    PropertyNotWritable {
+        property_name: &'a str,
+    },
+    /// The method or property setter exists, but the query was evaluated immutably,
+    /// with [`InteractiveRoot::try_eval`](crate::InteractiveRoot::try_eval) or as a wildcard match.
+    MutableAccessRequired {
+        name: &'a str,
+    },
+    TypeMismatch {
+        expected: &'static str,
+        found: &'static str,
     },
     WrongNumberOfArguments {
         method_name: &'a str,
         expected: usize,
         found: usize,
     },
+    WrongArgumentTypes {
+        method_name: &'a str,
+        expected: &'static str,
+        found: &'static str,
+    },
     ArgParseError {
         method_name: &'a str,
+        /// The position of the argument, starting at 0.
+        arg_index: usize,
+        /// The name of the parameter, as written in the signature.
+        arg_name: &'a str,
+        /// The type of the parameter, without any references.
+        expected: &'a str,
+        /// The argument that couldn't be parsed.
+        found: &'a str,
         error: ArgParseError<'a>,
     },
+    MethodReturnedError {
+        method_name: &'a str,
+        error: &'a str,
+    },
+    Panicked {
+        query: &'a str,
+        message: &'a str,
+    },
+    SyntaxError,
+}
+
+impl InteractiveError<'_> {
+    /// Returns which kind of error this is, without any of its details.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            InteractiveError::InteractiveNotImplemented { .. } => {
+                ErrorKind::InteractiveNotImplemented
+            }
+            InteractiveError::MethodsNotImplemented { .. } => ErrorKind::MethodsNotImplemented,
+            InteractiveError::DebugNotImplemented { .. } => ErrorKind::DebugNotImplemented,
+            InteractiveError::DisplayNotImplemented { .. } => ErrorKind::DisplayNotImplemented,
+            InteractiveError::FieldNotFound { .. } => ErrorKind::FieldNotFound,
+            InteractiveError::MethodNotFound { .. } => ErrorKind::MethodNotFound,
+            InteractiveError::FunctionNotFound { .. } => ErrorKind::FunctionNotFound,
+            InteractiveError::PropertyNotWritable { .. } => ErrorKind::PropertyNotWritable,
+            InteractiveError::MutableAccessRequired { .. } => ErrorKind::MutableAccessRequired,
+            InteractiveError::TypeMismatch { .. } => ErrorKind::TypeMismatch,
+            InteractiveError::WrongNumberOfArguments { .. } => ErrorKind::WrongNumberOfArguments,
+            InteractiveError::WrongArgumentTypes { .. } => ErrorKind::WrongArgumentTypes,
+            InteractiveError::ArgParseError { .. } => ErrorKind::ArgParseError,
+            InteractiveError::MethodReturnedError { .. } => ErrorKind::MethodReturnedError,
+            InteractiveError::Panicked { .. } => ErrorKind::Panicked,
+            InteractiveError::SyntaxError => ErrorKind::SyntaxError,
+        }
+    }
+
+    /// Returns the stable code of this error, e.g. `E0005`, see [`ErrorKind::code`].
+    pub fn code(&self) -> &'static str {
+        self.kind().code()
+    }
+}
+
+/// The kind of an [`InteractiveError`], one for each of its variants.
+///
+/// Unlike `InteractiveError` it doesn't borrow anything, so it can be matched on
+/// after converting an error into an [`OwnedInteractiveError`](crate::OwnedInteractiveError).
+#[allow(missing_docs)]
+#[non_exhaustive]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum ErrorKind {
+    InteractiveNotImplemented,
+    MethodsNotImplemented,
+    DebugNotImplemented,
+    DisplayNotImplemented,
+    FieldNotFound,
+    MethodNotFound,
+    FunctionNotFound,
+    PropertyNotWritable,
+    TypeMismatch,
+    WrongNumberOfArguments,
+    WrongArgumentTypes,
+    ArgParseError,
+    MethodReturnedError,
+    Panicked,
     SyntaxError,
+    MutableAccessRequired,
+}
+
+impl ErrorKind {
+    /// Returns the stable code of this kind of error, e.g. `E0005` for [`ErrorKind::FieldNotFound`].
+    ///
+    /// Unlike the error messages, the codes never change,
+    /// so clients can rely on them to tell errors apart.
+    /// Codes of kinds that get added later will not reuse existing ones.
+    pub fn code(self) -> &'static str {
+        match self {
+            ErrorKind::InteractiveNotImplemented => "E0001",
+            ErrorKind::MethodsNotImplemented => "E0002",
+            ErrorKind::DebugNotImplemented => "E0003",
+            ErrorKind::DisplayNotImplemented => "E0004",
+            ErrorKind::FieldNotFound => "E0005",
+            ErrorKind::MethodNotFound => "E0006",
+            ErrorKind::FunctionNotFound => "E0007",
+            ErrorKind::PropertyNotWritable => "E0008",
+            ErrorKind::TypeMismatch => "E0009",
+            ErrorKind::WrongNumberOfArguments => "E0010",
+            ErrorKind::WrongArgumentTypes => "E0011",
+            ErrorKind::ArgParseError => "E0012",
+            ErrorKind::MethodReturnedError => "E0013",
+            ErrorKind::Panicked => "E0014",
+            ErrorKind::SyntaxError => "E0015",
+            ErrorKind::MutableAccessRequired => "E0016",
+        }
+    }
+
+    /// Returns the stable name of this kind of error, e.g. `field_not_found` for [`ErrorKind::FieldNotFound`].
+    pub fn name(self) -> &'static str {
+        match self {
+            ErrorKind::InteractiveNotImplemented => "interactive_not_implemented",
+            ErrorKind::MethodsNotImplemented => "methods_not_implemented",
+            ErrorKind::DebugNotImplemented => "debug_not_implemented",
+            ErrorKind::DisplayNotImplemented => "display_not_implemented",
+            ErrorKind::FieldNotFound => "field_not_found",
+            ErrorKind::MethodNotFound => "method_not_found",
+            ErrorKind::FunctionNotFound => "function_not_found",
+            ErrorKind::PropertyNotWritable => "property_not_writable",
+            ErrorKind::TypeMismatch => "type_mismatch",
+            ErrorKind::WrongNumberOfArguments => "wrong_number_of_arguments",
+            ErrorKind::WrongArgumentTypes => "wrong_argument_types",
+            ErrorKind::ArgParseError => "arg_parse_error",
+            ErrorKind::MethodReturnedError => "method_returned_error",
+            ErrorKind::Panicked => "panicked",
+            ErrorKind::SyntaxError => "syntax_error",
+            ErrorKind::MutableAccessRequired => "mutable_access_required",
+        }
+    }
+}
+
+/// The reason an argument couldn't be parsed is the [`source`](core::error::Error::source)
+/// of an [`InteractiveError::ArgParseError`], unless it is an [`ArgParseError::UnescapeError`].
+impl core::error::Error for InteractiveError<'_> {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            InteractiveError::ArgParseError { error, .. } => error.parse_error(),
+            _ => None,
+        }
+    }
 }
 
 impl Display for InteractiveError<'_> {
@@ -52,24 +212,53 @@ impl Display for InteractiveError<'_> {
             InteractiveError::DebugNotImplemented { type_name } => {
                 write!(f, "´{}´ doesn't implement ´Debug´", type_name)
             }
+            InteractiveError::DisplayNotImplemented { type_name } => {
+                write!(f, "`{}` doesn't implement `Display`", type_name)
+            }
             InteractiveError::FieldNotFound {
                 type_name,
                 field_name,
-            } => write!(
-                f,
-                "No field `{}` found for type `{}`",
-                field_name, type_name
-            ),
+                suggestion,
+            } => {
+                write!(
+                    f,
+                    "No field `{}` found for type `{}`",
+                    field_name, type_name
+                )?;
+                write_suggestion(f, suggestion)
+            }
             InteractiveError::MethodNotFound {
                 method_name,
                 type_name,
-            } => write!(
+                suggestion,
+            } => {
+                write!(
+                    f,
+                    "No method named `{}` found for type `{}`",
+                    method_name, type_name
+                )?;
+                write_suggestion(f, suggestion)
+            }
+            InteractiveError::FunctionNotFound {
+                function_name,
+                suggestion,
+            } => {
+                write!(f, "No function named `{}` found", function_name)?;
+                write_suggestion(f, suggestion)
+            }
+            InteractiveError::PropertyNotWritable { property_name } => {
+                write!(f, "Property `{}` is not writable", property_name)
+            }
+            InteractiveError::MutableAccessRequired { name } => {
+                write!(f, "`{}` requires mutable access", name)
+            }
+            InteractiveError::TypeMismatch { expected, found } if expected == found => write!(
                 f,
-                "No method named `{}` found for type `{}`",
-                method_name, type_name
+                "Expected type `{}`, but `{}` doesn't implement `StaticType`",
+                expected, found
             ),
-            InteractiveError::FunctionNotFound { function_name } => {
-                write!(f, "No function named `{}` found", function_name)
+            InteractiveError::TypeMismatch { expected, found } => {
+                write!(f, "Expected type `{}`, found `{}`", expected, found)
             }
             InteractiveError::WrongNumberOfArguments {
                 method_name,
@@ -89,15 +278,48 @@ impl Display for InteractiveError<'_> {
                     method_name, expected, arguments_1, found, arguments_2, was_were
                 )
             }
-            InteractiveError::ArgParseError { error, .. } => write!(
+            InteractiveError::WrongArgumentTypes {
+                method_name,
+                expected,
+                found,
+            } => write!(
                 f,
-                "Couldn't parse method/function argument(s)\n{:?}",
-                error // TODO improve message
+                "`{}` takes arguments of type `{}`, but `{}` was supplied",
+                method_name, expected, found
             ),
+            InteractiveError::ArgParseError {
+                method_name,
+                arg_index,
+                arg_name,
+                expected,
+                found,
+                ..
+            } => write!(
+                f,
+                "argument {} `{}` of `{}`: expected {}, found `{}`",
+                arg_index + 1,
+                arg_name,
+                method_name,
+                expected,
+                found
+            ),
+            InteractiveError::MethodReturnedError { method_name, error } => {
+                write!(f, "`{}` returned an error: {}", method_name, error)
+            }
+            InteractiveError::Panicked { query, message } => {
+                write!(f, "`{}` panicked: {}", query, message)
+            }
             InteractiveError::SyntaxError => write!(f, "Syntax Error"),
         }
     }
 }
+fn write_suggestion(f: &mut Formatter<'_>, suggestion: &Option<&str>) -> core::fmt::Result {
+    match suggestion {
+        Some(suggestion) => write!(f, ", did you mean `{}`?", suggestion),
+        None => Ok(()),
+    }
+}
+
 /// Contains information about function or method argument parsing errors.
 ///
 /// It is used inside the [`InteractiveError::ArgParseError`] variant.
@@ -112,3 +334,52 @@ pub enum ArgParseError<'a> {
     /// Produced when parsing string-like types.
     UnescapeError(&'a str),
 }
+
+impl ArgParseError<'_> {
+    /// Returns the stable code of this error, e.g. `E0103` for [`ArgParseError::ParseFloatError`].
+    ///
+    /// Like [`ErrorKind::code`] but for the reason an argument couldn't be parsed.
+    pub fn code(&self) -> &'static str {
+        match self {
+            ArgParseError::ParseIntError(_) => "E0101",
+            ArgParseError::ParseCharError(_) => "E0102",
+            ArgParseError::ParseFloatError(_) => "E0103",
+            ArgParseError::ParseBoolError(_) => "E0104",
+            ArgParseError::UnescapeError(_) => "E0105",
+        }
+    }
+
+    /// Returns the error of the failed `FromStr` conversion, if any.
+    fn parse_error(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            ArgParseError::ParseIntError(error) => Some(error),
+            ArgParseError::ParseCharError(error) => Some(error),
+            ArgParseError::ParseFloatError(error) => Some(error),
+            ArgParseError::ParseBoolError(error) => Some(error),
+            ArgParseError::UnescapeError(_) => None,
+        }
+    }
+
+    /// Returns the stable name of this error, e.g. `parse_float_error` for [`ArgParseError::ParseFloatError`].
+    pub fn name(&self) -> &'static str {
+        match self {
+            ArgParseError::ParseIntError(_) => "parse_int_error",
+            ArgParseError::ParseCharError(_) => "parse_char_error",
+            ArgParseError::ParseFloatError(_) => "parse_float_error",
+            ArgParseError::ParseBoolError(_) => "parse_bool_error",
+            ArgParseError::UnescapeError(_) => "unescape_error",
+        }
+    }
+}
+
+impl Display for ArgParseError<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ArgParseError::ParseIntError(error) => write!(f, "{}", error),
+            ArgParseError::ParseCharError(error) => write!(f, "{}", error),
+            ArgParseError::ParseFloatError(error) => write!(f, "{}", error),
+            ArgParseError::ParseBoolError(error) => write!(f, "{}", error),
+            ArgParseError::UnescapeError(s) => write!(f, "invalid string literal `{}`", s),
+        }
+    }
+}
@@ -0,0 +1,381 @@
+//! Implementation details.
+//!
+//! Fields marked with `#[interactive(flatten)]` are exposed through
+//! [`Interactive::get_flattened_field`] and [`Interactive::get_flattened_field_mut`].
+//! The code generated by the macros falls back to the functions in this module
+//! when a field or method wasn't found on the type itself.
+use crate::specialization::{AsInteractive, AsInteractiveMut};
+use crate::{did_you_mean, Interactive, InteractiveError, Methods, Output, Result, TypedArgs};
+
+/// The names of all fields a derived type gives access to, excluding those of flattened fields.
+///
+/// Used to detect ambiguous field names of flattened fields at compile time.
+pub trait FieldNames {
+    /// The field names.
+    const FIELD_NAMES: &'static [&'static str];
+}
+
+/// Use specialization to retrieve the [`FieldNames`] of types that implement it
+/// or no names if they don't.
+pub trait AsFieldNames {
+    /// The field names.
+    const FIELD_NAMES: &'static [&'static str];
+}
+
+impl<T: ?Sized> AsFieldNames for T {
+    default const FIELD_NAMES: &'static [&'static str] = &[];
+}
+
+impl<T: ?Sized + FieldNames> AsFieldNames for T {
+    const FIELD_NAMES: &'static [&'static str] = <T as FieldNames>::FIELD_NAMES;
+}
+
+/// Looks for the field in the flattened fields of the given object.
+pub fn get_field<'a>(
+    object: &'a dyn Interactive,
+    field_name: &'a str,
+    type_name: &'static str,
+) -> Result<'a, &'a dyn Interactive> {
+    let mut index = 0;
+    while let Some(child) = object.get_flattened_field(index) {
+        match child.get_field(field_name) {
+            Err(InteractiveError::FieldNotFound { .. }) => index += 1,
+            result => return result,
+        }
+    }
+
+    Err(InteractiveError::FieldNotFound {
+        type_name,
+        field_name,
+        suggestion: did_you_mean(field_name, object.get_all_field_names()),
+    })
+}
+
+/// Same as [`get_field`] but returns a mutable reference.
+pub fn get_field_mut<'a>(
+    object: &'a mut dyn Interactive,
+    field_name: &'a str,
+    type_name: &'static str,
+) -> Result<'a, &'a mut dyn Interactive> {
+    // find the field first, so that the borrow returned in the end isn't conditional
+    let mut index = 0;
+    let found = loop {
+        if object.get_flattened_field(index).is_none() {
+            break None;
+        }
+        if let Some(child) = object.get_flattened_field_mut(index) {
+            if !matches!(
+                child.get_field_mut(field_name),
+                Err(InteractiveError::FieldNotFound { .. })
+            ) {
+                break Some(index);
+            }
+        }
+        index += 1;
+    };
+
+    let suggestion = match found {
+        Some(_) => None,
+        None => did_you_mean(field_name, object.get_all_field_names()),
+    };
+
+    match found.and_then(move |index| object.get_flattened_field_mut(index)) {
+        Some(child) => child.get_field_mut(field_name),
+        None => Err(InteractiveError::FieldNotFound {
+            type_name,
+            field_name,
+            suggestion,
+        }),
+    }
+}
+
+/// Looks for the field in the flattened fields of the given object and evaluates it.
+pub fn eval_field(
+    object: &dyn Interactive,
+    field_name: &str,
+    type_name: &'static str,
+    f: &mut dyn FnMut(Result<'_, &dyn Output>),
+) {
+    let mut index = 0;
+    while let Some(child) = object.get_flattened_field(index) {
+        let mut found = true;
+        child.eval_field_output(field_name, &mut |result| match result {
+            Err(InteractiveError::FieldNotFound { .. }) => found = false,
+            result => f(result),
+        });
+        if found {
+            return;
+        }
+        index += 1;
+    }
+
+    f(Err(InteractiveError::FieldNotFound {
+        type_name,
+        field_name,
+        suggestion: did_you_mean(field_name, object.get_all_field_names()),
+    }))
+}
+
+/// Looks for the method in the flattened fields of the given object and evaluates it.
+///
+/// Returns `false` without calling the closure if the method wasn't found.
+pub fn eval_method<T>(
+    object: &T,
+    method_name: &str,
+    args: &str,
+    f: &mut dyn FnMut(Result<'_, &dyn Output>),
+) -> bool {
+    find_method(object, f, &mut |child, f| {
+        child.shared().eval_method_output(method_name, args, f)
+    })
+}
+
+/// Same as [`eval_method`] but the arguments are passed as they are instead of being parsed.
+pub fn call_typed_method<T>(
+    object: &T,
+    method_name: &str,
+    args: &mut TypedArgs<'_>,
+    f: &mut dyn FnMut(Result<'_, &dyn Output>),
+) -> bool {
+    find_method(object, f, &mut |child, f| {
+        child.shared().call_typed_method(method_name, args, f)
+    })
+}
+
+/// Looks for the property in the flattened fields of the given object and calls its setter.
+///
+/// Returns `false` without calling the closure if the property wasn't found.
+pub fn set_property<T>(
+    object: &mut T,
+    property_name: &str,
+    value: &str,
+    f: &mut dyn FnMut(Result<'_, &dyn Output>),
+) -> bool {
+    let object = match object.try_as_interactive_mut() {
+        Ok(object) => object,
+        Err(_) => return false,
+    };
+
+    let mut index = 0;
+    while object.get_flattened_field(index).is_some() {
+        if let Some(Ok(child)) = object
+            .get_flattened_field_mut(index)
+            .map(|child| child.try_as_methods_mut())
+        {
+            let mut found = true;
+            child.set_property(property_name, value, &mut |result| match result {
+                Err(InteractiveError::FieldNotFound { .. }) => found = false,
+                result => f(result),
+            });
+            if found {
+                return true;
+            }
+        }
+        index += 1;
+    }
+    false
+}
+
+/// Same as [`eval_method`] but with access to methods that take `&mut self` as their receiver.
+pub fn eval_method_mut<T>(
+    object: &mut T,
+    method_name: &str,
+    args: &str,
+    f: &mut dyn FnMut(Result<'_, &dyn Output>),
+) -> bool {
+    find_method_mut(object, f, &mut |child, f| match child {
+        MethodsRef::Shared(child) => child.eval_method_output(method_name, args, f),
+        MethodsRef::Mut(child) => child.eval_method_mut_output(method_name, args, f),
+    })
+}
+
+/// Same as [`call_typed_method`] but with access to methods that take `&mut self` as their receiver.
+pub fn call_typed_method_mut<T>(
+    object: &mut T,
+    method_name: &str,
+    args: &mut TypedArgs<'_>,
+    f: &mut dyn FnMut(Result<'_, &dyn Output>),
+) -> bool {
+    find_method_mut(object, f, &mut |child, f| match child {
+        MethodsRef::Shared(child) => child.call_typed_method(method_name, args, f),
+        MethodsRef::Mut(child) => child.call_typed_method_mut(method_name, args, f),
+    })
+}
+
+/// The flattened field a method is looked for in, mutable if possible.
+enum MethodsRef<'a> {
+    Shared(&'a dyn Methods),
+    Mut(&'a mut dyn Methods),
+}
+
+impl MethodsRef<'_> {
+    fn shared(&self) -> &dyn Methods {
+        match self {
+            MethodsRef::Shared(methods) => *methods,
+            MethodsRef::Mut(methods) => &**methods,
+        }
+    }
+}
+
+type MethodCall<'a> = dyn FnMut(MethodsRef<'_>, &mut dyn FnMut(Result<'_, &dyn Output>)) + 'a;
+
+/// Passes each flattened field to `call` until one of them has the method.
+fn find_method<T>(
+    object: &T,
+    f: &mut dyn FnMut(Result<'_, &dyn Output>),
+    call: &mut MethodCall<'_>,
+) -> bool {
+    let object = match object.try_as_interactive() {
+        Ok(object) => object,
+        Err(_) => return false,
+    };
+
+    let mut index = 0;
+    while let Some(child) = object.get_flattened_field(index) {
+        if call_child(child.try_as_methods().map(MethodsRef::Shared), f, call) {
+            return true;
+        }
+        index += 1;
+    }
+    false
+}
+
+/// Same as [`find_method`] but passes the flattened fields mutably if possible.
+fn find_method_mut<T>(
+    object: &mut T,
+    f: &mut dyn FnMut(Result<'_, &dyn Output>),
+    call: &mut MethodCall<'_>,
+) -> bool {
+    let object = match object.try_as_interactive_mut() {
+        Ok(object) => object,
+        Err(_) => return false,
+    };
+
+    let mut index = 0;
+    while object.get_flattened_field(index).is_some() {
+        let found = match object.get_flattened_field_mut(index) {
+            Some(child) => call_child(child.try_as_methods_mut().map(MethodsRef::Mut), f, call),
+            // not accessible mutably, but methods that take `&self` still are
+            None => match object.get_flattened_field(index) {
+                Some(child) => call_child(child.try_as_methods().map(MethodsRef::Shared), f, call),
+                None => false,
+            },
+        };
+        if found {
+            return true;
+        }
+        index += 1;
+    }
+    false
+}
+
+fn call_child(
+    child: Result<'_, MethodsRef<'_>>,
+    f: &mut dyn FnMut(Result<'_, &dyn Output>),
+    call: &mut MethodCall<'_>,
+) -> bool {
+    let mut found = false;
+    if let Ok(child) = child {
+        found = true;
+        call(child, &mut |result| match result {
+            Err(InteractiveError::MethodNotFound { .. }) => found = false,
+            result => f(result),
+        });
+    }
+    found
+}
+
+/// Appends the field names of all flattened fields to the given field names.
+///
+/// The generated code only calls this once per type and caches the result.
+#[cfg(feature = "std")]
+pub fn get_all_field_names(
+    object: &dyn Interactive,
+    field_names: &[&'static str],
+) -> Vec<&'static str> {
+    let mut names = field_names.to_vec();
+    let mut index = 0;
+    while let Some(child) = object.get_flattened_field(index) {
+        names.extend_from_slice(child.get_all_field_names());
+        index += 1;
+    }
+    names
+}
+
+/// Appends the method names of all flattened fields to the given method names.
+///
+/// The generated code only calls this once per type and caches the result.
+#[cfg(feature = "std")]
+pub fn get_all_method_names<T>(object: &T, method_names: &[&'static str]) -> Vec<&'static str> {
+    let mut names = method_names.to_vec();
+    if let Ok(object) = object.try_as_interactive() {
+        let mut index = 0;
+        while let Some(child) = object.get_flattened_field(index) {
+            if let Ok(child) = child.try_as_methods() {
+                names.extend_from_slice(child.get_all_method_names());
+            }
+            index += 1;
+        }
+    }
+    names
+}
+
+/// Appends the method signatures of all flattened fields to the given method signatures.
+///
+/// The generated code only calls this once per type and caches the result.
+#[cfg(feature = "std")]
+pub fn get_all_method_signatures<T>(
+    object: &T,
+    signatures: &[crate::Signature],
+) -> Vec<crate::Signature> {
+    let mut all_signatures = signatures.to_vec();
+    if let Ok(object) = object.try_as_interactive() {
+        let mut index = 0;
+        while let Some(child) = object.get_flattened_field(index) {
+            if let Ok(child) = child.try_as_methods() {
+                all_signatures.extend_from_slice(child.get_all_method_signatures());
+            }
+            index += 1;
+        }
+    }
+    all_signatures
+}
+
+/// Returns `true` if `names` contains `name`.
+pub const fn contains(names: &[&str], name: &str) -> bool {
+    let mut i = 0;
+    while i < names.len() {
+        if str_eq(names[i], name) {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+/// Returns `true` if `a` and `b` have a name in common.
+pub const fn intersects(a: &[&str], b: &[&str]) -> bool {
+    let mut i = 0;
+    while i < a.len() {
+        if contains(b, a[i]) {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+const fn str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
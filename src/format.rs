@@ -0,0 +1,271 @@
+//! Implementation details.
+//!
+//! Used by the code generated for the `format` and `unit` options of `#[interactive(...)]`
+//! and for methods that return a `Result`.
+use core::fmt::{Debug, Formatter, Result, Write};
+
+use crate::{InteractiveError, MethodResult, Output};
+
+/// A value together with a closure that shows it, implements [`Debug`] by calling the closure.
+#[derive(Clone, Copy)]
+pub struct Formatted<'a, T, F> {
+    value: &'a T,
+    fmt: F,
+}
+
+impl<'a, T, F> Formatted<'a, T, F> {
+    /// The unformatted value.
+    pub fn value(&self) -> &'a T {
+        self.value
+    }
+}
+
+impl<T, F> Debug for Formatted<'_, T, F>
+where
+    F: Fn(&mut Formatter<'_>) -> Result,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        (self.fmt)(f)
+    }
+}
+
+/// Creates a [`Formatted`], helps inferring the closure's signature.
+pub fn formatted<T, F>(value: &T, fmt: F) -> Formatted<'_, T, F>
+where
+    F: Fn(&mut Formatter<'_>) -> Result,
+{
+    Formatted { value, fmt }
+}
+
+/// Integers that can be shown with all their digits in a different radix.
+pub trait RadixInteger {
+    /// The number of bits of the type.
+    const BITS: u32;
+
+    /// The bits of the value, negative values in two's complement.
+    fn to_bits(&self) -> u128;
+}
+
+macro_rules! radix_integer {
+    ($($int:ty => $uint:ty),* $(,)?) => {
+        $(
+            impl RadixInteger for $int {
+                const BITS: u32 = <$int>::BITS;
+
+                fn to_bits(&self) -> u128 {
+                    *self as $uint as u128
+                }
+            }
+        )*
+    };
+}
+
+radix_integer!(
+    u8 => u8, u16 => u16, u32 => u32, u64 => u64, u128 => u128, usize => usize,
+    i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize,
+);
+
+impl<T: RadixInteger + ?Sized> RadixInteger for &T {
+    const BITS: u32 = T::BITS;
+
+    fn to_bits(&self) -> u128 {
+        (**self).to_bits()
+    }
+}
+
+impl<T: RadixInteger + ?Sized> RadixInteger for &mut T {
+    const BITS: u32 = T::BITS;
+
+    fn to_bits(&self) -> u128 {
+        (**self).to_bits()
+    }
+}
+
+/// Writes the value as hexadecimal with all digits in groups of four, e.g. `0x0000_00A4` for a `u32`.
+pub fn fmt_hex<T: RadixInteger + ?Sized>(value: &T, f: &mut Formatter<'_>) -> Result {
+    fmt_radix(value.to_bits(), T::BITS / 4, 4, "0x", f)
+}
+
+/// Writes the value as binary with all digits in groups of four, e.g. `0b0000_0101` for a `u8`.
+pub fn fmt_bin<T: RadixInteger + ?Sized>(value: &T, f: &mut Formatter<'_>) -> Result {
+    fmt_radix(value.to_bits(), T::BITS, 1, "0b", f)
+}
+
+fn fmt_radix(
+    bits: u128,
+    digits: u32,
+    bits_per_digit: u32,
+    prefix: &str,
+    f: &mut Formatter<'_>,
+) -> Result {
+    f.write_str(prefix)?;
+
+    let mask = (1 << bits_per_digit) - 1;
+    for digit_index in (0..digits).rev() {
+        let digit = (bits >> (digit_index * bits_per_digit)) & mask;
+        // digit < 16
+        let digit = char::from_digit(digit as u32, 16).unwrap_or('?');
+        write!(f, "{}", digit.to_ascii_uppercase())?;
+
+        if digit_index != 0 && digit_index % 4 == 0 {
+            f.write_str("_")?;
+        }
+    }
+    Ok(())
+}
+
+/// Passes the `Ok` value or the `Err` returned by a method to the closure as a [`MethodResult`].
+///
+/// If the value can't be shown its error is passed instead.
+pub fn method_result(
+    method_name: &str,
+    type_name: &'static str,
+    result: core::result::Result<crate::Result<'_, &dyn Output>, crate::Result<'_, &dyn Output>>,
+    f: &mut dyn FnMut(crate::Result<'_, &dyn Output>),
+) {
+    let result = match result {
+        Ok(Ok(value)) => Ok(value),
+        Err(Ok(error)) => Err(error),
+        Ok(Err(e)) | Err(Err(e)) => return f(Err(e)),
+    };
+    f(Ok(&MethodResult {
+        method_name,
+        type_name,
+        result,
+    }))
+}
+
+/// Passes only the `Ok` value of a [`MethodResult`] to the closure,
+/// and its `Err` as an [`InteractiveError::MethodReturnedError`].
+///
+/// Other results are passed unchanged.
+pub(crate) fn unwrap_method_result(
+    result: crate::Result<'_, &dyn Output>,
+    f: &mut dyn FnMut(crate::Result<'_, &dyn Output>),
+) {
+    let method_result = match &result {
+        Ok(value) => (*value).as_method_result(),
+        Err(_) => None,
+    };
+    match method_result {
+        Some(MethodResult {
+            result: Ok(value), ..
+        }) => f(Ok(value)),
+        Some(MethodResult {
+            method_name,
+            result: Err(error),
+            ..
+        }) => method_returned_error(method_name, error, f),
+        None => f(result),
+    }
+}
+
+/// Passes the `Err` returned by a method to the closure as an [`InteractiveError::MethodReturnedError`].
+///
+/// The error is shown with `Display` if it implements it and with `Debug` otherwise.
+/// Without the `std` feature the text is cut off after 128 bytes.
+fn method_returned_error(
+    method_name: &str,
+    error: &dyn Output,
+    f: &mut dyn FnMut(crate::Result<'_, &dyn Output>),
+) {
+    let mut text = ErrorText::default();
+    let _ = match error.as_display() {
+        Some(display) => write!(text, "{}", display),
+        None => write!(text, "{:?}", error),
+    };
+    f(Err(InteractiveError::MethodReturnedError {
+        method_name,
+        error: text.as_str(),
+    }))
+}
+
+#[cfg(feature = "std")]
+type ErrorText = String;
+
+/// A fixed size buffer that silently drops everything after the first char that doesn't fit.
+#[cfg(not(feature = "std"))]
+struct ErrorText {
+    buf: [u8; 128],
+    len: usize,
+    full: bool,
+}
+
+#[cfg(not(feature = "std"))]
+impl Default for ErrorText {
+    fn default() -> Self {
+        ErrorText {
+            buf: [0; 128],
+            len: 0,
+            full: false,
+        }
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl ErrorText {
+    fn as_str(&self) -> &str {
+        // only whole chars are written
+        core::str::from_utf8(&self.buf[..self.len]).unwrap_or_default()
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl Write for ErrorText {
+    fn write_str(&mut self, s: &str) -> Result {
+        for c in s.chars() {
+            let end = self.len + c.len_utf8();
+            if self.full || end > self.buf.len() {
+                self.full = true;
+                break;
+            }
+            c.encode_utf8(&mut self.buf[self.len..end]);
+            self.len = end;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Hex<T>(T);
+
+    impl<T: RadixInteger> Debug for Hex<T> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+            fmt_hex(&self.0, f)
+        }
+    }
+
+    struct Bin<T>(T);
+
+    impl<T: RadixInteger> Debug for Bin<T> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+            fmt_bin(&self.0, f)
+        }
+    }
+
+    #[test]
+    fn test_hex() {
+        assert_eq!(format!("{:?}", Hex(0xA4u32)), "0x0000_00A4");
+        assert_eq!(format!("{:?}", Hex(0xABu8)), "0xAB");
+        assert_eq!(format!("{:?}", Hex(-1i16)), "0xFFFF");
+        assert_eq!(format!("{:?}", Hex(u64::MAX)), "0xFFFF_FFFF_FFFF_FFFF");
+    }
+
+    #[test]
+    fn test_bin() {
+        assert_eq!(format!("{:?}", Bin(5u8)), "0b0000_0101");
+        assert_eq!(format!("{:?}", Bin(-1i8)), "0b1111_1111");
+        assert_eq!(format!("{:?}", Bin(1u16)), "0b0000_0000_0000_0001");
+    }
+
+    #[test]
+    fn test_formatted() {
+        let value = 1.23456f32;
+        let debug = formatted(&value, |f: &mut Formatter<'_>| write!(f, "{:.3} V", value));
+        assert_eq!(format!("{:?}", debug), "1.235 V");
+        assert_eq!(*debug.value(), value);
+    }
+}
@@ -1,6 +1,6 @@
 use core::fmt::Debug;
 
-use crate::Result;
+use crate::{InteractiveError, Output, Result, Signature, TypedArgs};
 
 /// A trait that allows to interactively evaluate a function and pass its result to the given closure.
 ///
@@ -16,10 +16,51 @@ pub trait Function: Sync {
     /// On error an `Err(InteractiveError)` is passed to the closure instead.
     fn eval(&self, args: &str, f: &mut dyn FnMut(Result<'_, &dyn Debug>));
 
+    /// Same as [`eval`](#tymethod.eval) but passes the result as a `Ok(&dyn Output)`,
+    /// which can also be shown with `Display`, converted to JSON or downcast.
+    ///
+    /// Defaults to wrapping the `&dyn Debug` passed by `eval`.
+    fn eval_output(&self, args: &str, f: &mut dyn FnMut(Result<'_, &dyn Output>)) {
+        self.eval(args, &mut |result| match result {
+            Ok(value) => f(Ok(&value)),
+            Err(e) => f(Err(e)),
+        })
+    }
+
+    /// Same as [`eval`](#tymethod.eval) but the arguments are passed as they are instead of being parsed.
+    ///
+    /// If the arguments don't match the parameters of the function
+    /// an `Err(InteractiveError::WrongArgumentTypes)` is passed to the closure.
+    fn call(&self, args: &mut TypedArgs<'_>, f: &mut dyn FnMut(Result<'_, &dyn Output>)) {
+        let _ = args;
+        f(Err(InteractiveError::FunctionNotFound {
+            function_name: self.function_name(),
+            suggestion: None,
+        }))
+    }
+
     /// Returns the functions name.
     ///
     /// Can be used to drive auto-completion in a CLI.
     fn function_name(&self) -> &'static str;
+
+    /// Returns the path of the module the function is defined in, e.g. `my_crate::commands`.
+    fn module_path(&self) -> &'static str {
+        ""
+    }
+
+    /// Returns the functions signature.
+    ///
+    /// Defaults to a signature without any parameters or return type.
+    fn signature(&self) -> Signature {
+        Signature {
+            name: self.function_name(),
+            receiver: None,
+            params: &[],
+            return_type: None,
+            docs: "",
+        }
+    }
 }
 
 // Implement inventory::Collect for ´&dyn Function´
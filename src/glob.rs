@@ -0,0 +1,273 @@
+//! Queries with `*` and `**` wildcards,
+//! see [`InteractiveRoot::try_eval_matches`](crate::InteractiveRoot::try_eval_matches).
+use core::fmt::{Display, Formatter, Result as FmtResult, Write};
+
+use crate::format::unwrap_method_result;
+use crate::help::Text;
+use crate::root::{find_object, parse_access_type, parse_object_path, AccessType};
+use crate::tree::Node;
+use crate::{Interactive, InteractiveError, Output, Result};
+
+/// Called with the path and result of each match.
+type MatchFn<'f> = dyn FnMut(&dyn Display, Result<'_, &dyn Output>) + 'f;
+
+/// What is evaluated for each matched object.
+enum Target<'a> {
+    /// `*`
+    Fields,
+    /// `**`
+    FieldsRecursive,
+    Field(&'a str),
+    /// The method name, its args and the whole expression for the path.
+    Method(&'a str, &'a str, &'a str),
+}
+
+/// A parsed query with wildcards.
+struct Glob<'a> {
+    /// The object at the path before the first wildcard.
+    object: &'a dyn Interactive,
+    prefix: &'a str,
+    /// The rest of the object path, starting with the first wildcard.
+    pattern: &'a str,
+    target: Target<'a>,
+}
+
+/// Whether any segment of the query is `*` or `**`.
+pub(crate) fn is_glob(query: &str) -> bool {
+    let (object_path, rest_expression) = parse_object_path(query);
+    object_path
+        .split('.')
+        .chain(Some(rest_expression))
+        .any(|segment| is_wildcard(segment.trim()))
+}
+
+/// Calls the closure with the path and result of every match of the query.
+///
+/// Errors that don't belong to a single match are passed with the query as the path.
+pub(crate) fn eval_matches(root: &dyn Interactive, query: &str, f: &mut MatchFn<'_>) {
+    match Glob::parse(root, query) {
+        Ok(glob) => glob.for_each(f),
+        Err(e) => f(&query, Err(e)),
+    }
+}
+
+/// Evaluates the query if it contains wildcards and passes all matches as text to the closure,
+/// one per line: `path = value`.
+///
+/// Returns `false` without calling the closure otherwise.
+pub(crate) fn eval_glob(
+    root: &dyn Interactive,
+    query: &str,
+    f: &mut dyn FnMut(Result<'_, &dyn Output>),
+) -> bool {
+    if !is_glob(query) {
+        return false;
+    }
+    let glob = match Glob::parse(root, query) {
+        Ok(glob) => glob,
+        Err(e) => {
+            f(Err(e));
+            return true;
+        }
+    };
+
+    f(Ok(&Text(|f: &mut Formatter<'_>| {
+        let mut result = Ok(());
+        let mut first = true;
+        glob.for_each(&mut |path, value| {
+            if result.is_err() {
+                return;
+            }
+            if !first {
+                result = f.write_char('\n');
+            }
+            first = false;
+            result = result.and_then(|()| match value {
+                Ok(value) => write!(f, "{} = {:?}", path, value),
+                Err(e) => write!(f, "{}: {}", path, e),
+            });
+        });
+        result
+    })));
+    true
+}
+
+impl<'a> Glob<'a> {
+    fn parse(root: &'a dyn Interactive, query: &'a str) -> Result<'a, Self> {
+        let (object_path, rest_expression) = parse_object_path(query);
+        let rest_expression = rest_expression.trim();
+
+        // the objects before the first wildcard have to exist
+        let (prefix, pattern) = split_at_wildcard(object_path);
+        let object = find_object(root, prefix)?;
+
+        let target = match rest_expression {
+            "*" => Target::Fields,
+            "**" => Target::FieldsRecursive,
+            _ => match parse_access_type(rest_expression)? {
+                AccessType::FieldAccess(field_name) => Target::Field(field_name),
+                AccessType::MethodAccess(method_name, args) => {
+                    Target::Method(method_name, args, rest_expression)
+                }
+                // matches are only evaluated immutably
+                AccessType::PropertyAssignment(property_name, _) => {
+                    return Err(InteractiveError::PropertyNotWritable { property_name })
+                }
+            },
+        };
+
+        Ok(Glob {
+            object,
+            prefix: prefix.trim(),
+            pattern,
+            target,
+        })
+    }
+
+    fn for_each(&self, f: &mut MatchFn<'_>) {
+        let node = Node {
+            object: self.object,
+            name: self.prefix,
+            parent: None,
+        };
+        walk(&node, self.pattern, &self.target, f);
+    }
+}
+
+fn is_wildcard(segment: &str) -> bool {
+    segment == "*" || segment == "**"
+}
+
+/// `a.b.*.c` -> (`a.b`, `*.c`)
+fn split_at_wildcard(object_path: &str) -> (&str, &str) {
+    let mut start: usize = 0;
+    for segment in object_path.split('.') {
+        if is_wildcard(segment.trim()) {
+            return (
+                &object_path[..start.saturating_sub(1)],
+                &object_path[start..],
+            );
+        }
+        start += segment.len() + 1;
+    }
+    (object_path, "")
+}
+
+/// Follows the pattern from the node and evaluates the target for every object it matches.
+fn walk(node: &Node<'_>, pattern: &str, target: &Target<'_>, f: &mut MatchFn<'_>) {
+    if pattern.is_empty() {
+        return eval_target(node, target, f);
+    }
+    let (segment, remainder) = pattern.split_once('.').unwrap_or((pattern, ""));
+
+    match segment.trim() {
+        // the node itself and everything below it
+        "**" => {
+            walk(node, remainder, target, f);
+            for_each_field(node, &mut |field| walk(field, pattern, target, f));
+        }
+        "*" => for_each_field(node, &mut |field| walk(field, remainder, target, f)),
+        name => {
+            // objects without the field are no match
+            if let Ok(object) = node.object.get_field(name) {
+                let field = Node {
+                    object,
+                    name,
+                    parent: Some(node),
+                };
+                walk(&field, remainder, target, f);
+            }
+        }
+    }
+}
+
+/// Calls the closure with every interactive field of the node, except those that contain themselves.
+fn for_each_field(node: &Node<'_>, f: &mut dyn FnMut(&Node<'_>)) {
+    for &name in node.object.get_all_field_names() {
+        if let Ok(object) = node.object.get_field(name) {
+            let field = Node {
+                object,
+                name,
+                parent: Some(node),
+            };
+            if !field.is_cycle() {
+                f(&field);
+            }
+        }
+    }
+}
+
+fn eval_target(node: &Node<'_>, target: &Target<'_>, f: &mut MatchFn<'_>) {
+    match *target {
+        Target::Field(field_name) => node.object.eval_field_output(field_name, &mut |result| {
+            report(&Path(node, field_name), result, f)
+        }),
+        Target::Method(method_name, args, expression) => {
+            if let Ok(methods) = node.object.try_as_methods() {
+                methods.eval_method_output(method_name, args, &mut |result| match result {
+                    // matches are only evaluated immutably
+                    Err(InteractiveError::MethodNotFound { .. })
+                        if methods.get_all_method_names().contains(&method_name) =>
+                    {
+                        f(
+                            &Path(node, expression),
+                            Err(InteractiveError::MutableAccessRequired { name: method_name }),
+                        )
+                    }
+                    result => report(&Path(node, expression), result, f),
+                });
+            }
+        }
+        Target::Fields => {
+            for &field_name in node.object.get_all_field_names() {
+                node.object.eval_field_output(field_name, &mut |result| {
+                    report(&Path(node, field_name), result, f)
+                });
+            }
+        }
+        Target::FieldsRecursive => {
+            for &field_name in node.object.get_all_field_names() {
+                node.object.eval_field_output(field_name, &mut |result| {
+                    report(&Path(node, field_name), result, f)
+                });
+                if let Ok(object) = node.object.get_field(field_name) {
+                    let field = Node {
+                        object,
+                        name: field_name,
+                        parent: Some(node),
+                    };
+                    if !field.is_cycle() {
+                        eval_target(&field, target, f);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Passes the result on, unless the object simply doesn't have the field or method.
+///
+/// The `Result` returned by a method is unwrapped.
+fn report(path: &Path<'_>, result: Result<'_, &dyn Output>, f: &mut MatchFn<'_>) {
+    match result {
+        Err(
+            InteractiveError::FieldNotFound { .. }
+            | InteractiveError::MethodNotFound { .. }
+            | InteractiveError::FunctionNotFound { .. },
+        ) => {}
+        result => unwrap_method_result(result, &mut |result| f(path, result)),
+    }
+}
+
+/// The path of a field or method below a node.
+struct Path<'a>(&'a Node<'a>, &'a str);
+
+impl Display for Path<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        // only the first node can have an empty path
+        if !self.0.name.is_empty() {
+            write!(f, "{}.", self.0)?;
+        }
+        f.write_str(self.1)
+    }
+}
@@ -0,0 +1,318 @@
+//! The built-in `help`, `type_of` and `tree` queries, see [`InteractiveRoot`](crate::InteractiveRoot#built-in-queries).
+use core::fmt::{Debug, Display, Formatter, Result as FmtResult};
+
+use crate::property::property_signature;
+use crate::tree::write_tree;
+use crate::{
+    did_you_mean, ArgParseError, Interactive, InteractiveError, InteractiveRoot, Output, Result,
+};
+
+enum Builtin {
+    Help,
+    TypeOf,
+    Tree,
+}
+
+/// Evaluates the query with `eval`, passing the results on to the closure,
+/// unless it is a built-in query and the root has no field, property, method or function of the same name.
+///
+/// Returns `true` without calling the closure in that case, the query is a built-in one then.
+/// The root is tried first so that even its hidden fields and methods take precedence.
+pub(crate) fn try_root_first(
+    query: &str,
+    f: &mut dyn FnMut(Result<'_, &dyn Output>),
+    eval: impl FnOnce(&mut dyn FnMut(Result<'_, &dyn Output>)),
+) -> bool {
+    let name = match parse_builtin(query) {
+        Some((_, name, _)) => name,
+        None => {
+            eval(f);
+            return false;
+        }
+    };
+
+    let mut not_found = false;
+    eval(&mut |result| match result {
+        Err(
+            InteractiveError::FieldNotFound {
+                field_name: found, ..
+            }
+            | InteractiveError::MethodNotFound {
+                method_name: found, ..
+            }
+            | InteractiveError::FunctionNotFound {
+                function_name: found,
+                ..
+            },
+        ) if found == name => not_found = true,
+        result => f(result),
+    });
+    not_found
+}
+
+/// Evaluates the built-in query and passes the result to the closure.
+///
+/// Does nothing if the query isn't a built-in one, see [`try_root_first`].
+pub(crate) fn eval_builtin<R: InteractiveRoot>(
+    root: &R,
+    query: &str,
+    f: &mut dyn FnMut(Result<'_, &dyn Output>),
+) {
+    match parse_builtin(query) {
+        Some((Builtin::Help, _, path)) => help(root, path, f),
+        Some((Builtin::TypeOf, _, path)) => type_of(root, path, f),
+        Some((Builtin::Tree, _, path)) => tree(root, path, f),
+        None => {}
+    }
+}
+
+/// `help`, `help(path)`, `type_of(path)`, `tree` or `tree(path, depth)`
+/// -> the builtin, its name and the arguments
+fn parse_builtin(query: &str) -> Option<(Builtin, &str, &str)> {
+    let query = query.trim();
+    match query {
+        "help" => return Some((Builtin::Help, query, "")),
+        "tree" => return Some((Builtin::Tree, query, "")),
+        _ => {}
+    }
+    let (name, path) = query.strip_suffix(')')?.split_once('(')?;
+    let builtin = match name.trim() {
+        "help" => Builtin::Help,
+        "type_of" => Builtin::TypeOf,
+        "tree" => Builtin::Tree,
+        _ => return None,
+    };
+    Some((builtin, name.trim(), path.trim()))
+}
+
+fn help<R: InteractiveRoot>(root: &R, path: &str, f: &mut dyn FnMut(Result<'_, &dyn Output>)) {
+    if path.is_empty() {
+        return f(Ok(&Text(|f: &mut Formatter<'_>| {
+            f.write_str(root.get_type_name())?;
+            write_members(f, root)?;
+            f.write_str("\n\nBuilt-in queries:")?;
+            write_line(
+                f,
+                &"help(path)",
+                "Shows the fields and methods of an object or the documentation of a field or method.",
+            )?;
+            write_line(
+                f,
+                &"type_of(path)",
+                "Shows the type of a field or the signature of a method.",
+            )?;
+            write_line(
+                f,
+                &"tree(path, depth)",
+                "Shows all fields below an object up to the given depth, both are optional.",
+            )
+        })));
+    }
+
+    let (object, name) = match root.get_queried_object(path) {
+        Ok(found) => found,
+        Err(e) => return f(Err(e)),
+    };
+    if name.is_empty() {
+        return f(Ok(&Text(|f: &mut Formatter<'_>| {
+            f.write_str(object.get_type_name())?;
+            write_members(f, object)
+        })));
+    }
+
+    if let Some(info) = object.get_field_info(name) {
+        let child = object.get_field(name).ok();
+        return f(Ok(&Text(|f: &mut Formatter<'_>| {
+            write!(f, "{}: {}", info.name, info.type_name)?;
+            write_docs(f, info.docs)?;
+            match child {
+                Some(child) => write_members(f, child),
+                None => Ok(()),
+            }
+        })));
+    }
+
+    let methods = object.try_as_methods().ok();
+    if let Some(signature) = methods.and_then(|methods| methods.get_method_signature(name)) {
+        return f(Ok(&Text(|f: &mut Formatter<'_>| {
+            write!(f, "{}", signature)?;
+            write_docs(f, signature.docs)
+        })));
+    }
+
+    // the getter isn't called, it might have side effects
+    if let Some(signature) = property_signature(object, name) {
+        return f(Ok(&Text(|f: &mut Formatter<'_>| {
+            write!(f, "{}: {}", name, signature.return_type.unwrap_or("()"))?;
+            write_docs(f, signature.docs)
+        })));
+    }
+
+    f(Err(not_found(object, name)))
+}
+
+fn type_of<R: InteractiveRoot>(root: &R, path: &str, f: &mut dyn FnMut(Result<'_, &dyn Output>)) {
+    let (object, name) = match root.get_queried_object(path) {
+        Ok(found) => found,
+        Err(e) => return f(Err(e)),
+    };
+    if name.is_empty() {
+        return f(Ok(&Text(|f: &mut Formatter<'_>| {
+            f.write_str(object.get_type_name())
+        })));
+    }
+
+    if let Some(info) = object.get_field_info(name) {
+        return f(Ok(&Text(|f: &mut Formatter<'_>| {
+            f.write_str(info.type_name)
+        })));
+    }
+
+    let methods = object.try_as_methods().ok();
+    if let Some(signature) = methods.and_then(|methods| methods.get_method_signature(name)) {
+        return f(Ok(&Text(|f: &mut Formatter<'_>| {
+            write!(f, "{}", signature)
+        })));
+    }
+
+    if let Some(signature) = property_signature(object, name) {
+        return f(Ok(&Text(|f: &mut Formatter<'_>| {
+            f.write_str(signature.return_type.unwrap_or("()"))
+        })));
+    }
+
+    f(Err(not_found(object, name)))
+}
+
+fn tree<R: InteractiveRoot>(root: &R, args: &str, f: &mut dyn FnMut(Result<'_, &dyn Output>)) {
+    let (path, max_depth) = match args.rsplit_once(',') {
+        Some((path, depth)) => match depth.trim().parse() {
+            Ok(depth) => (path.trim(), depth),
+            Err(e) => {
+                return f(Err(InteractiveError::ArgParseError {
+                    method_name: "tree",
+                    arg_index: 1,
+                    arg_name: "depth",
+                    expected: "usize",
+                    found: depth.trim(),
+                    error: ArgParseError::ParseIntError(e),
+                }))
+            }
+        },
+        // field names can't start with a digit
+        None => match args.parse() {
+            Ok(depth) => ("", depth),
+            Err(_) => (args, usize::MAX),
+        },
+    };
+
+    let (object, name) = match root.get_queried_object(path) {
+        Ok(found) => found,
+        Err(e) => return f(Err(e)),
+    };
+    if name.is_empty() {
+        return f(Ok(&Text(|f: &mut Formatter<'_>| {
+            write_tree(f, object, path, max_depth)
+        })));
+    }
+
+    if let Ok(field) = object.get_field(name) {
+        return f(Ok(&Text(|f: &mut Formatter<'_>| {
+            write_tree(f, field, path, max_depth)
+        })));
+    }
+
+    object.eval_field_output(name, &mut |result| match result {
+        Ok(value) => f(Ok(&Text(|f: &mut Formatter<'_>| {
+            write!(f, "{} = {:?}", path, value)
+        }))),
+        Err(_) => f(Err(not_found(object, name))),
+    });
+}
+
+/// Neither a field nor a method, suggests the closest of both.
+fn not_found<'a>(object: &'a dyn Interactive, name: &'a str) -> InteractiveError<'a> {
+    let method_names = match object.try_as_methods() {
+        Ok(methods) => methods.get_all_method_names(),
+        Err(_) => &[],
+    };
+    InteractiveError::FieldNotFound {
+        type_name: object.get_type_name(),
+        field_name: name,
+        suggestion: did_you_mean(name, object.get_all_field_names())
+            .or_else(|| did_you_mean(name, method_names)),
+    }
+}
+
+/// Lists the fields, methods and functions of the object with the first line of their docs.
+fn write_members(f: &mut Formatter<'_>, object: &dyn Interactive) -> FmtResult {
+    let field_names = object.get_all_field_names();
+    if !field_names.is_empty() {
+        f.write_str("\n\nFields:")?;
+        for name in field_names {
+            match object.get_field_info(name) {
+                Some(info) => write_line(
+                    f,
+                    &Text(|f: &mut Formatter<'_>| write!(f, "{}: {}", info.name, info.type_name)),
+                    info.docs,
+                )?,
+                None => write_line(f, name, "")?,
+            }
+        }
+    }
+
+    let signatures = match object.try_as_methods() {
+        Ok(methods) => methods.get_all_method_signatures(),
+        Err(_) => &[],
+    };
+    for (heading, is_method) in [("\n\nMethods:", true), ("\n\nFunctions:", false)] {
+        let mut signatures = signatures
+            .iter()
+            .filter(|signature| signature.receiver.is_some() == is_method)
+            .peekable();
+        if signatures.peek().is_some() {
+            f.write_str(heading)?;
+        }
+        for signature in signatures {
+            write_line(f, signature, signature.docs)?;
+        }
+    }
+    Ok(())
+}
+
+/// `  name - first line of docs`
+fn write_line(f: &mut Formatter<'_>, name: &dyn Display, docs: &str) -> FmtResult {
+    write!(f, "\n  {}", name)?;
+    match docs.lines().next() {
+        Some(summary) if !summary.is_empty() => write!(f, " - {}", summary),
+        _ => Ok(()),
+    }
+}
+
+fn write_docs(f: &mut Formatter<'_>, docs: &str) -> FmtResult {
+    if docs.is_empty() {
+        return Ok(());
+    }
+    write!(f, "\n\n{}", docs)
+}
+
+/// Text that is shown as it is, with `Debug` as well as `Display`.
+pub(crate) struct Text<F>(pub(crate) F);
+
+impl<F> Debug for Text<F>
+where
+    F: Fn(&mut Formatter<'_>) -> FmtResult,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        (self.0)(f)
+    }
+}
+
+impl<F> Display for Text<F>
+where
+    F: Fn(&mut Formatter<'_>) -> FmtResult,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        (self.0)(f)
+    }
+}
@@ -1,10 +1,10 @@
 use core::any::type_name;
-use core::fmt::Debug;
+use core::fmt::{Debug, Display, Formatter};
 
 use auto_impl::auto_impl;
 
-use crate::specialization::{AsDebug, AsMethods, AsMethodsMut};
-use crate::{InteractiveError, Result};
+use crate::specialization::{AsDebug, AsMethods, AsMethodsMut, AsOutput};
+use crate::{did_you_mean, InteractiveError, Output, Result, Signature, TypedArgs};
 
 /// A trait that gives interactive access to its fields as `dyn Interactive` or `dyn Debug`.
 ///
@@ -14,12 +14,16 @@ use crate::{InteractiveError, Result};
 /// [`Interactive`]: macro@crate::Interactive
 #[cfg_attr(feature = "std", auto_impl(&, &mut, Box, Rc, Arc))]
 #[cfg_attr(not(feature = "std"), auto_impl(&, &mut))]
-pub trait Interactive: AsDebug + AsMethods + AsMethodsMut {
+pub trait Interactive: AsDebug + AsOutput + AsMethods + AsMethodsMut {
     /// Looks for a field with the given name and on success return a shared reference to it.
-    fn get_field<'a>(&'a self, field_name: &'a str) -> crate::Result<'a, &dyn crate::Interactive> {
+    fn get_field<'a>(
+        &'a self,
+        field_name: &'a str,
+    ) -> crate::Result<'a, &'a dyn crate::Interactive> {
         Err(InteractiveError::FieldNotFound {
             type_name: type_name::<Self>(),
             field_name,
+            suggestion: did_you_mean(field_name, self.get_all_field_names()),
         })
     }
 
@@ -28,10 +32,11 @@ pub trait Interactive: AsDebug + AsMethods + AsMethodsMut {
     fn get_field_mut<'a>(
         &'a mut self,
         field_name: &'a str,
-    ) -> crate::Result<'a, &mut dyn crate::Interactive> {
+    ) -> crate::Result<'a, &'a mut dyn crate::Interactive> {
         Err(InteractiveError::FieldNotFound {
             type_name: type_name::<Self>(),
             field_name,
+            suggestion: did_you_mean(field_name, self.get_all_field_names()),
         })
     }
 
@@ -43,15 +48,65 @@ pub trait Interactive: AsDebug + AsMethods + AsMethodsMut {
         f(Err(InteractiveError::FieldNotFound {
             type_name: type_name::<Self>(),
             field_name,
+            suggestion: did_you_mean(field_name, self.get_all_field_names()),
         }))
     }
 
+    /// Same as [`eval_field`](#method.eval_field) but passes the field as a `Ok(&dyn Output)`,
+    /// which can also be shown with `Display`, converted to JSON or downcast.
+    ///
+    /// Defaults to wrapping the `&dyn Debug` passed by `eval_field`.
+    fn eval_field_output(&self, field_name: &str, f: &mut dyn FnMut(Result<'_, &dyn Output>)) {
+        self.eval_field(field_name, &mut |result| match result {
+            Ok(value) => f(Ok(&value)),
+            Err(e) => f(Err(e)),
+        })
+    }
+
     /// Returns all interactive field names of this type.
     ///
     /// Can be used to drive auto-completion in a CLI.
     fn get_all_field_names(&self) -> &'static [&'static str] {
         &[]
     }
+
+    /// Returns information about the interactive field with the given name,
+    /// e.g. to show it in an object browser.
+    ///
+    /// Fields of flattened fields are found as well, properties are not.
+    fn get_field_info(&self, field_name: &str) -> Option<FieldInfo> {
+        let _ = field_name;
+        None
+    }
+
+    /// Returns the name of this type, as used in error messages.
+    fn get_type_name(&self) -> &'static str {
+        type_name::<Self>()
+    }
+
+    /// Returns the full path of this type, including its generic arguments.
+    ///
+    /// Unlike [`get_type_name`](Interactive::get_type_name) it tells types of the same name apart.
+    fn get_type_path(&self) -> &'static str {
+        type_name::<Self>()
+    }
+
+    /// Returns the field at the given position among the fields marked with `#[interactive(flatten)]`.
+    ///
+    /// The fields and methods of these fields are accessible as if they belonged to this type.
+    fn get_flattened_field(&self, index: usize) -> Option<&dyn crate::Interactive> {
+        let _ = index;
+        None
+    }
+
+    /// Same as [`get_flattened_field`](#method.get_flattened_field) but returns a mutable reference.
+    ///
+    /// Returns `None` for flattened fields that are not accessible mutably.
+    #[auto_impl(keep_default_for(&, Rc, Arc))]
+    fn get_flattened_field_mut(&mut self, index: usize) -> Option<&mut dyn crate::Interactive> {
+        let _ = index;
+        None
+    }
 }
 
 /// A trait that allows to interactively evaluate a structs methods and pass their result to the given closure.
@@ -86,6 +141,7 @@ pub trait Methods {
             f(Err(InteractiveError::MethodNotFound {
                 type_name: type_name::<Self>(),
                 method_name,
+                suggestion: did_you_mean(method_name, self.get_all_method_names()),
             }));
         }
     }
@@ -107,6 +163,81 @@ pub trait Methods {
         f(Err(InteractiveError::MethodNotFound {
             type_name: type_name::<Self>(),
             method_name,
+            suggestion: did_you_mean(method_name, self.get_all_method_names()),
+        }));
+    }
+
+    /// Same as [`eval_method`] but passes the result as a `Ok(&dyn Output)`,
+    /// which can also be shown with `Display`, converted to JSON or downcast.
+    ///
+    /// Defaults to wrapping the `&dyn Debug` passed by `eval_method`.
+    ///
+    /// [`eval_method`]: #method.eval_method
+    fn eval_method_output(
+        &self,
+        method_name: &str,
+        args: &str,
+        f: &mut dyn FnMut(Result<'_, &dyn Output>),
+    ) {
+        self.eval_method(method_name, args, &mut |result| match result {
+            Ok(value) => f(Ok(&value)),
+            Err(e) => f(Err(e)),
+        })
+    }
+
+    /// Same as [`eval_method_mut`] but passes the result as a `Ok(&dyn Output)`.
+    ///
+    /// Defaults to wrapping the `&dyn Debug` passed by `eval_method_mut`.
+    ///
+    /// [`eval_method_mut`]: #method.eval_method_mut
+    #[auto_impl(keep_default_for(&, Rc, Arc))]
+    fn eval_method_mut_output(
+        &mut self,
+        method_name: &str,
+        args: &str,
+        f: &mut dyn FnMut(Result<'_, &dyn Output>),
+    ) {
+        self.eval_method_mut(method_name, args, &mut |result| match result {
+            Ok(value) => f(Ok(&value)),
+            Err(e) => f(Err(e)),
+        })
+    }
+
+    /// Same as [`eval_method`] but the arguments are passed as they are instead of being parsed.
+    ///
+    /// If the arguments don't match the parameters of the method
+    /// an `Err(InteractiveError::WrongArgumentTypes)` is passed to the closure.
+    ///
+    /// [`eval_method`]: #method.eval_method
+    fn call_typed_method(
+        &self,
+        method_name: &str,
+        args: &mut TypedArgs<'_>,
+        f: &mut dyn FnMut(Result<'_, &dyn Output>),
+    ) {
+        let _ = args;
+        f(Err(InteractiveError::MethodNotFound {
+            type_name: type_name::<Self>(),
+            method_name,
+            suggestion: did_you_mean(method_name, self.get_all_method_names()),
+        }));
+    }
+
+    /// Same as [`eval_method_mut`] but the arguments are passed as they are instead of being parsed.
+    ///
+    /// [`eval_method_mut`]: #method.eval_method_mut
+    #[auto_impl(keep_default_for(&, Rc, Arc))]
+    fn call_typed_method_mut(
+        &mut self,
+        method_name: &str,
+        args: &mut TypedArgs<'_>,
+        f: &mut dyn FnMut(Result<'_, &dyn Output>),
+    ) {
+        let _ = args;
+        f(Err(InteractiveError::MethodNotFound {
+            type_name: type_name::<Self>(),
+            method_name,
+            suggestion: did_you_mean(method_name, self.get_all_method_names()),
         }));
     }
 
@@ -116,4 +247,148 @@ pub trait Methods {
     fn get_all_method_names(&self) -> &'static [&'static str] {
         &[]
     }
+
+    /// Returns the signatures of all interactive methods of this type,
+    /// in the same order as [`get_all_method_names`](#method.get_all_method_names).
+    ///
+    /// Can be used to show parameter hints in a CLI.
+    fn get_all_method_signatures(&self) -> &'static [Signature] {
+        &[]
+    }
+
+    /// Returns the signature of the interactive method with the given name.
+    fn get_method_signature(&self, method_name: &str) -> Option<&'static Signature> {
+        self.get_all_method_signatures()
+            .iter()
+            .find(|signature| signature.name == method_name)
+    }
+
+    /// Looks for a property with the given name,
+    /// calls its getter and passes the result as a `Ok(&dyn Output)` to the given closure.
+    ///
+    /// Properties are methods marked with `#[interactive(property)]`.
+    /// [`Interactive::eval_field`] gives access to them as if they were fields.
+    ///
+    /// On error the `Err(InteractiveError)` is passed to the closure instead.
+    fn eval_property(&self, property_name: &str, f: &mut dyn FnMut(Result<'_, &dyn Output>)) {
+        f(Err(InteractiveError::FieldNotFound {
+            type_name: type_name::<Self>(),
+            field_name: property_name,
+            suggestion: did_you_mean(property_name, self.get_all_property_names()),
+        }))
+    }
+
+    /// Looks for a property with the given name,
+    /// parses the value string into the expected argument of its setter,
+    /// calls the setter and passes the result as a `Ok(&dyn Output)` to the given closure.
+    ///
+    /// On error the `Err(InteractiveError)` is passed to the closure instead.
+    #[auto_impl(keep_default_for(&, Rc, Arc))]
+    fn set_property(
+        &mut self,
+        property_name: &str,
+        value: &str,
+        f: &mut dyn FnMut(Result<'_, &dyn Output>),
+    ) {
+        let _ = value;
+        f(Err(InteractiveError::FieldNotFound {
+            type_name: type_name::<Self>(),
+            field_name: property_name,
+            suggestion: did_you_mean(property_name, self.get_all_property_names()),
+        }))
+    }
+
+    /// Returns all property names of this type.
+    fn get_all_property_names(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Returns the names of all properties that have a setter.
+    fn get_all_writable_property_names(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Returns the signature of the getter of the property with the given name,
+    /// hidden properties included.
+    ///
+    /// Gives the type of a property without calling its getter.
+    fn get_property_signature(&self, property_name: &str) -> Option<&'static Signature> {
+        let _ = property_name;
+        None
+    }
+
+    /// Returns all methods of this type that couldn't be made interactive, together with the reason.
+    ///
+    /// The [`Methods`] attribute also warns about these at compile time.
+    ///
+    /// [`Methods`]: macro@crate::Methods
+    fn get_all_skipped_methods(&self) -> &'static [SkippedMethod] {
+        &[]
+    }
+}
+
+/// Information about an interactive field, see [`Interactive::get_field_info`].
+///
+/// # Example
+///
+/// ```
+/// use rusteval::{FieldInfo, Interactive};
+///
+/// #[derive(Interactive, Debug, Default)]
+/// struct Child {
+///     /// The current temperature in °C.
+///     temperature: f32,
+/// }
+///
+/// #[derive(Interactive, Debug)]
+/// struct Parent<'a> {
+///     child: &'a Child,
+/// }
+///
+/// let parent = Parent { child: &Child::default() };
+/// assert_eq!(
+///     parent.child.get_field_info("temperature"),
+///     Some(FieldInfo {
+///         name: "temperature",
+///         type_name: "f32",
+///         mutable: true,
+///         interactive: false,
+///         docs: "The current temperature in °C.",
+///     })
+/// );
+/// let child = parent.get_field_info("child").unwrap();
+/// assert_eq!(child.type_name, "&'a Child");
+/// assert!(!child.mutable);
+/// assert!(child.interactive);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct FieldInfo {
+    /// The name the field is accessed with in a query.
+    pub name: &'static str,
+    /// The type of the field, as written in the source code.
+    pub type_name: &'static str,
+    /// Whether the field can be accessed mutably,
+    /// i.e. it is owned or a `&mut` and not marked `#[interactive(readonly)]`.
+    pub mutable: bool,
+    /// Whether the field itself implements [`Interactive`], so its fields can be accessed as well.
+    pub interactive: bool,
+    /// The doc comment, without the leading `///`.
+    pub docs: &'static str,
+}
+
+/// A method that was skipped by the [`Methods`] attribute, because it can't be made interactive.
+///
+/// [`Methods`]: macro@crate::Methods
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct SkippedMethod {
+    /// The name of the skipped method.
+    pub method_name: &'static str,
+    /// Why the method was skipped, e.g. ``parameter `data` has unsupported type `&[u8]` ``.
+    pub reason: &'static str,
+}
+
+impl Display for SkippedMethod {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "`{}` was skipped: {}", self.method_name, self.reason)
+    }
 }
@@ -15,7 +15,7 @@
 //! * Define a new struct that owns or holds references to the objects you want to access
 //! * Derive [`InteractiveRoot`] for it
 //! * Use the trait's methods to evaluate a string
-//! (the simplest one is [`eval_to_string`](InteractiveRoot::eval_to_string) but others allow for more custom behaviour)
+//!   (the simplest one is [`eval_to_string`](InteractiveRoot::eval_to_string) but others allow for more custom behaviour)
 //! * Accessing a field will give you its Debug representation
 //! * Calling a function or a method will parse its arguments and give you the Debug representation of its return value
 //!
@@ -32,6 +32,9 @@
 //! ### CLI Usage
 //! Functions like [`get_all_field_names`](Interactive::get_all_field_names) are provided.
 //! This makes it possible to implement things like auto-completion.
+//! [`get_field_info`](Interactive::get_field_info) and
+//! [`get_all_method_signatures`](Methods::get_all_method_signatures) describe fields and methods further,
+//! e.g. their types and doc comments.
 //!
 //! Have a look at the autocomplete example for how this might be done using the [rustyline](https://docs.rs/crate/rustyline) crate.
 //!
@@ -94,6 +97,9 @@
 //! #     field2: (),
 //! # }
 //! # impl Stub {
+//! # fn get_all_field_names(&self) -> &'static [&'static str] {
+//! #     &["field1", "field2"]
+//! # }
 //! fn get_field<'a>(&'a self, field_name: &'a str) -> Result<'_, &dyn Interactive> {
 //!     match field_name {
 //!         "field1" => self.field1.try_as_interactive(),
@@ -101,6 +107,7 @@
 //!         _ => Err(InteractiveError::FieldNotFound {
 //!             type_name: "Struct",
 //!             field_name,
+//!             suggestion: did_you_mean(field_name, self.get_all_field_names()),
 //!         }),
 //!     }
 //! }
@@ -109,8 +116,13 @@
 //!
 //! See the macro's documentation for more details.
 //!
+//! # Features
+//! * `std` (default): free functions, multiple `#[Methods]` impl blocks per type ([link](macro@Methods#multiple-impl-blocks)), [`eval_to_string`](InteractiveRoot::eval_to_string), etc.
+//! * `serde`: results as JSON with [`eval_to_json`](InteractiveRoot::eval_to_json)
+//!
 //! # Current limitations:
 //! * Methods and functions can only be made interactive if their argument types are supported
+//! * Generic types can only have a single `#[Methods]` impl block
 //! * Enums are not supported
 
 #![allow(incomplete_features)] // TODO re-enable warning
@@ -160,6 +172,49 @@
 /// assert_eq!(root.eval_to_string("field.ping()"), "\"pong\"");
 /// assert_eq!(root.eval_to_string("add_one(42)"), "43");
 /// ```
+///
+/// # Catching panics
+/// By default a panic in an interactive method propagates like any other panic.
+/// With `#[interactive(catch_panics)]` on the root, [`try_eval`](trait@InteractiveRoot#method.try_eval),
+/// [`try_eval_mut`](trait@InteractiveRoot#method.try_eval_mut), [`try_eval_matches`](trait@InteractiveRoot#method.try_eval_matches)
+/// and everything built on top of them, like [`eval_to_string`](trait@InteractiveRoot#method.eval_to_string),
+/// catch it with [`std::panic::catch_unwind`] and report an [`InteractiveError::Panicked`]
+/// with the query and the panic message instead.
+/// A panic in the closure passed to them is raised again with its original payload.
+///
+/// Unwind safety is only asserted, not checked:
+/// the objects the query had access to can be left in an inconsistent state,
+/// e.g. a `Vec` that was only partly updated, so they should be treated with suspicion afterwards.
+/// The panic hook still runs and panics can't be caught with `panic = "abort"`.
+///
+/// Without the `std` feature use `#[interactive(panic_handler = "path::to::handler")]`
+/// with a [`PanicHandler`](panic::PanicHandler) of your own.
+///
+/// ```
+/// use rusteval::{Interactive, InteractiveRoot, Methods};
+///
+/// #[derive(Interactive, Default)]
+/// struct Motor;
+///
+/// #[Methods]
+/// impl Motor {
+///     fn spin(&mut self, rpm: u32) {
+///         assert!(rpm < 1000, "{} rpm is too fast", rpm);
+///     }
+/// }
+///
+/// #[derive(InteractiveRoot, Default)]
+/// #[interactive(catch_panics)]
+/// struct Root {
+///     motor: Motor,
+/// }
+///
+/// let mut root = Root::default();
+/// assert_eq!(
+///     root.eval_to_string("motor.spin(2000)"),
+///     "`motor.spin(2000)` panicked: 2000 rpm is too fast"
+/// );
+/// ```
 pub use rusteval_derive::InteractiveRoot;
 
 /// Gives interactive access to a structs fields.
@@ -193,6 +248,7 @@ pub use rusteval_derive::InteractiveRoot;
 ///             _ => Err(FieldNotFound {
 ///                 type_name: "Struct",
 ///                 field_name,
+///                 suggestion: did_you_mean(field_name, self.get_all_field_names()),
 ///             }),
 ///         }
 ///     }
@@ -212,6 +268,190 @@ pub use rusteval_derive::InteractiveRoot;
 ///     }
 /// }
 /// ```
+/// [`Interactive::eval_field_output`] is generated the same way,
+/// but passes the fields as `&dyn Output` so they can also be shown with `Display` or converted to JSON.
+///
+/// # Attributes
+/// Fields can be configured with `#[interactive(...)]`:
+/// * `skip`: the field is not accessible at all
+/// * `rename = "name"`: the field is accessible under a different name
+/// * `readonly`: the field is never accessible mutably
+/// * `hidden`: the field is accessible but not listed by [`get_all_field_names`](trait@Interactive#method.get_all_field_names)
+/// * `flatten`: the fields and methods of the field are accessible as if they belonged to the struct ([link](#flatten))
+/// * `format = "..."`, `display` and `unit = "..."`: change how the field's value is shown ([link](#format-hints))
+///
+/// Skipped fields are also left out by [`PartialDebug`](macro@PartialDebug).
+///
+/// ```
+/// use rusteval::{Interactive, InteractiveRoot, Methods};
+///
+/// #[derive(Interactive, Default)]
+/// struct Counter {
+///     count: u32,
+/// }
+///
+/// #[Methods]
+/// impl Counter {
+///     fn increment(&mut self) {
+///         self.count += 1;
+///     }
+/// }
+///
+/// #[derive(Interactive, Default)]
+/// struct Account {
+///     #[interactive(rename = "user")]
+///     user_name: &'static str,
+///     #[interactive(skip)]
+///     password: &'static str,
+///     #[interactive(readonly)]
+///     logins: Counter,
+///     #[interactive(hidden)]
+///     debug_flags: u8,
+/// }
+///
+/// #[derive(InteractiveRoot, Default)]
+/// struct Root {
+///     account: Account,
+/// }
+///
+/// let mut root = Root::default();
+/// assert_eq!(root.account.get_all_field_names(), &["user", "logins"]);
+/// assert_eq!(root.eval_to_string("account.user"), "\"\"");
+/// assert_eq!(root.eval_to_string("account.debug_flags"), "0");
+/// assert_eq!(
+///     root.eval_to_string("account.password"),
+///     "No field `password` found for type `Account`"
+/// );
+/// assert_eq!(
+///     root.eval_to_string("account.logins.increment()"),
+///     "No method named `increment` found for type `Counter`"
+/// );
+/// ```
+///
+/// # Flatten
+/// Similar to serde's `flatten`, a field marked with `#[interactive(flatten)]` is not accessible by its own name.
+/// Instead, fields and methods that aren't found on the struct itself are looked up on its flattened fields in declaration order.
+/// The flattened field's type has to implement [`trait@Interactive`].
+///
+/// Ambiguous field names are detected at compile time, unless the struct is generic
+/// or the flattened field's type has lifetime parameters.
+/// Ambiguous method names are resolved in favor of the struct's own methods, then of the first flattened field.
+///
+/// The names of properties and of flattened fields and methods are listed for auto-completion only with default features on
+/// and only for non-generic structs, since the merged lists are cached per type.
+///
+/// ```
+/// use rusteval::{Interactive, InteractiveRoot, Methods};
+///
+/// #[derive(Interactive, Default)]
+/// struct Board {
+///     led: bool,
+/// }
+///
+/// #[Methods]
+/// impl Board {
+///     fn led_on(&mut self) {
+///         self.led = true;
+///     }
+/// }
+///
+/// #[derive(Interactive, Default)]
+/// struct Sensors {
+///     temperature: f32,
+/// }
+///
+/// #[derive(InteractiveRoot, Default)]
+/// struct Root {
+///     #[interactive(flatten)]
+///     board: Board,
+///     #[interactive(flatten)]
+///     sensors: Sensors,
+/// }
+///
+/// let mut root = Root::default();
+/// root.eval_to_string("led_on()");
+/// assert_eq!(root.eval_to_string("led"), "true");
+/// assert_eq!(root.eval_to_string("temperature"), "0.0");
+/// assert_eq!(root.get_all_field_names(), &["led", "temperature"]);
+/// ```
+///
+/// Without default features and for generic structs,
+/// flattened methods are only reachable if the struct has a `#[Methods]` impl block, which may be empty.
+///
+/// Without default features [`get_all_field_names`](trait@Interactive#method.get_all_field_names) and
+/// [`get_all_method_names`](trait@Methods#method.get_all_method_names) only list the struct's own fields and methods.
+///
+/// # Format hints
+/// By default values are shown with their [`Debug`](core::fmt::Debug) implementation.
+/// `#[interactive(format = "...")]` changes that to one of:
+/// * `"hex"`: all hexadecimal digits of an integer in groups of four, e.g. `0x0000_00A4` for a `u32`
+/// * `"bin"`: all binary digits of an integer in groups of four, e.g. `0b0000_0101` for a `u8`
+/// * a format string with a single argument, e.g. `"{:.3}"` or `"{:#x}"`
+///
+/// `#[interactive(display)]` shows the value with its [`Display`](core::fmt::Display) implementation instead.
+/// To prefer `Display` for all results use [`OutputOptions::prefer_display`].
+/// Values that implement `Display` but not `Debug` are always shown with `Display`.
+///
+/// `#[interactive(unit = "...")]` appends a unit to the value.
+///
+/// They are applied by [`eval_field`](trait@Interactive#method.eval_field) and by [`PartialDebug`](macro@PartialDebug).
+/// The same options on a method apply to its return value.
+/// ```
+/// use rusteval::{Interactive, InteractiveRoot, PartialDebug};
+///
+/// #[derive(Interactive, PartialDebug, Default)]
+/// struct Board {
+///     #[interactive(format = "hex")]
+///     status: u32,
+///     #[interactive(format = "{:.3}", unit = "V")]
+///     voltage: f32,
+/// }
+///
+/// #[derive(InteractiveRoot, Default)]
+/// struct Root {
+///     board: Board,
+/// }
+///
+/// let mut root = Root::default();
+/// root.board.status = 0xA4;
+/// root.board.voltage = 3.3;
+/// assert_eq!(root.eval_to_string("board.status"), "0x0000_00A4");
+/// assert_eq!(root.eval_to_string("board"), "Board { status: 0x0000_00A4, voltage: 3.300 V }");
+/// ```
+///
+/// # Downcasting
+/// `#[interactive(any)]` on the struct implements [`StaticType`] for it,
+/// so its values can be downcast with [`eval_as`](trait@InteractiveRoot#method.eval_as).
+/// Structs with lifetime parameters can't opt in and type parameters have to implement `StaticType` as well.
+///
+/// ```
+/// use rusteval::{Interactive, InteractiveRoot};
+///
+/// #[derive(Interactive, Debug, Clone, Default, PartialEq)]
+/// #[interactive(any)]
+/// struct Child {
+///     value: u8,
+/// }
+///
+/// #[derive(InteractiveRoot, Default)]
+/// struct Root {
+///     child: Child,
+/// }
+///
+/// let mut root = Root::default();
+/// assert_eq!(root.eval_as::<Child>("child"), Ok(Child { value: 0 }));
+/// ```
+///
+/// ```compile_fail
+/// # use rusteval::Interactive;
+/// #
+/// // error: `#[interactive(any)]` can't be used on structs with lifetime parameters
+/// #[derive(Interactive, Debug)]
+/// #[interactive(any)]
+/// struct Borrowed<'a> {
+///     value: &'a u8,
+/// }
+/// ```
 pub use rusteval_derive::Interactive;
 
 /// Gives interactive access to a structs methods.
@@ -229,10 +469,236 @@ pub use rusteval_derive::Interactive;
 ///
 /// Both `String` and `str` are only available with default features on.
 ///
-/// # What it does:
+/// # Skipped methods
+/// Methods with unsupported argument types are skipped with a compile time warning.
+/// The warning is reported through the `deprecated` lint,
+/// so `#![allow(deprecated)]` in the surrounding module silences it.
+/// Use `#[Methods(strict)]` to turn these warnings into errors.
+///
+/// Skipped methods can also be listed at runtime together with the reason they were skipped.
+/// ```
+/// use rusteval::{Interactive, Methods};
+///
+/// #[derive(Interactive)]
+/// struct Device;
+///
+/// #[Methods]
+/// impl Device {
+///     fn write(&mut self, data: &[u8]) {}
+/// }
+///
+/// let skipped = Device.get_all_skipped_methods();
+/// assert_eq!(skipped[0].method_name, "write");
+/// assert_eq!(skipped[0].reason, "parameter `data` has unsupported type `&[u8]`");
+/// ```
+///
+/// # Multiple impl blocks
+/// With default features on, every `#[Methods]` impl block of a non-generic type,
+/// including trait impls, submits its methods to a global registry
+/// (using the [inventory](https://docs.rs/inventory/*/inventory/) crate).
+/// `#[derive(Interactive)]` and `#[derive(InteractiveRoot)]` implement [`trait@Methods`] for the type
+/// by dispatching to all of its registered impl blocks, so a type can have any number of them.
+///
 /// ```
+/// use rusteval::{Interactive, InteractiveRoot, Methods};
+///
+/// trait Sensor {
+///     fn read(&self) -> f32;
+/// }
+///
+/// #[derive(Interactive, Default)]
+/// struct Board;
+///
+/// #[Methods]
+/// impl Board {
+///     fn ping(&self) -> &str {
+///         "pong"
+///     }
+/// }
+///
+/// #[Methods]
+/// impl Sensor for Board {
+///     fn read(&self) -> f32 {
+///         4.2
+///     }
+/// }
+///
+/// #[derive(InteractiveRoot, Default)]
+/// struct Root {
+///     board: Board,
+/// }
+///
+/// #[Methods]
+/// impl Root {
+///     fn reset(&mut self) {}
+/// }
+///
+/// let mut root = Root::default();
+/// assert_eq!(root.eval_to_string("board.ping()"), "\"pong\"");
+/// assert_eq!(root.eval_to_string("board.read()"), "4.2");
+/// assert_eq!(root.eval_to_string("reset()"), "()");
+/// ```
+///
+/// Types that don't derive `Interactive`, like enums, mark one of their impl blocks with `#[Methods(primary)]`,
+/// which implements [`trait@Methods`] the same way.
+/// `#[interactive(manual_methods)]` on a struct keeps the derive from implementing [`trait@Methods`],
+/// so it can be implemented by hand.
+///
+/// Impl blocks of a type that doesn't implement [`trait@Methods`] through the registry
+/// would never be called, so they fail to compile:
+/// ```compile_fail
 /// # use rusteval::Methods;
 /// #
+/// enum Mode {
+///     Idle,
+/// }
+///
+/// // error: the `#[Methods]` impl blocks of `Mode` are not reachable
+/// #[Methods]
+/// impl Mode {
+///     fn is_idle(&self) -> bool {
+///         true
+///     }
+/// }
+/// ```
+///
+/// Generic types and builds without default features don't use the registry.
+/// Their `#[Methods]` impl block implements [`trait@Methods`] directly, so they can only have a single one.
+///
+/// # Attributes
+/// Methods can be configured with `#[interactive(...)]`:
+/// * `skip`: the method is not interactive and not reported as skipped
+/// * `rename = "name"`: the method is callable under a different name
+/// * `hidden`: the method is callable but not listed by [`get_all_method_names`](trait@Methods#method.get_all_method_names)
+/// * `property`: the method is accessible like a read-only field ([link](#properties))
+/// * `setter = "property"`: the method gives write access to a property ([link](#properties))
+/// * `format = "..."`, `display` and `unit = "..."`: change how the return value is shown ([link](macro@Interactive#format-hints))
+///
+/// ```
+/// use rusteval::{Interactive, Methods};
+///
+/// #[derive(Interactive)]
+/// struct Device;
+///
+/// #[Methods]
+/// impl Device {
+///     #[interactive(rename = "reset")]
+///     fn reset_device(&mut self) {}
+///     #[interactive(hidden)]
+///     fn self_test(&self) -> bool {
+///         true
+///     }
+///     #[interactive(skip)]
+///     fn erase_flash(&mut self) {}
+/// }
+///
+/// assert_eq!(Device.get_all_method_names(), &["reset"]);
+/// ```
+///
+/// # Properties
+/// Methods marked with `#[interactive(property)]` have to take `&self` and no other arguments.
+/// They can be read like fields and are listed by [`get_all_field_names`](trait@Interactive#method.get_all_field_names)
+/// instead of [`get_all_method_names`](trait@Methods#method.get_all_method_names).
+///
+/// A method of the same impl block that takes `&mut self` and one argument
+/// can be marked with `#[interactive(setter = "property")]`.
+/// It is called by assigning to the property with `property = value`.
+/// Assigning to a property without setter returns [`InteractiveError::PropertyNotWritable`].
+/// ```
+/// use rusteval::{Interactive, InteractiveRoot, Methods};
+///
+/// #[derive(Interactive, Default)]
+/// struct Cpu {
+///     busy_cycles: u32,
+///     total_cycles: u32,
+/// }
+///
+/// #[Methods]
+/// impl Cpu {
+///     #[interactive(property)]
+///     fn utilization(&self) -> f32 {
+///         self.busy_cycles as f32 / self.total_cycles as f32
+///     }
+///
+///     #[interactive(property)]
+///     fn idle_cycles(&self) -> u32 {
+///         self.total_cycles - self.busy_cycles
+///     }
+///
+///     #[interactive(setter = "idle_cycles")]
+///     fn set_idle_cycles(&mut self, idle_cycles: u32) {
+///         self.total_cycles = self.busy_cycles + idle_cycles;
+///     }
+/// }
+///
+/// #[derive(InteractiveRoot, Default)]
+/// struct Root {
+///     cpu: Cpu,
+/// }
+///
+/// let mut root = Root::default();
+/// root.cpu.busy_cycles = 1;
+/// assert_eq!(root.eval_to_string("cpu.idle_cycles = 3"), "()");
+/// assert_eq!(root.eval_to_string("cpu.utilization"), "0.25");
+/// assert_eq!(root.eval_to_string("cpu.utilization = 1.0"), "Property `utilization` is not writable");
+/// ```
+///
+/// Without default features properties are not listed by [`get_all_field_names`](trait@Interactive#method.get_all_field_names).
+///
+/// # Methods returning `Result`
+/// If the return type of a method is named `Result`, e.g. `Result<u8, Error>`, `io::Result<()>` or `fmt::Result`,
+/// the evaluation methods of [`InteractiveRoot`] pass on only its `Ok` value,
+/// and an `Err` as [`InteractiveError::MethodReturnedError`] instead,
+/// carrying the error shown with `Display` if it implements it and with `Debug` otherwise.
+/// This also applies to properties, setters and [`Function`](macro@Function)s.
+///
+/// The [`trait@Methods`] trait itself passes on the `Result` as a [`MethodResult`], which is shown like the `Result` itself.
+/// With [`OutputOptions::keep_results`] it is also written that way by
+/// [`eval_to_string_with`](InteractiveRoot::eval_to_string_with).
+/// ```
+/// use rusteval::{Interactive, InteractiveRoot, Methods, OutputOptions};
+///
+/// #[derive(Interactive, Default)]
+/// struct Sensor;
+///
+/// #[Methods]
+/// impl Sensor {
+///     fn read(&self, channel: u8) -> Result<f32, String> {
+///         match channel {
+///             0 => Ok(1.5),
+///             _ => Err(format!("no channel {}", channel)),
+///         }
+///     }
+/// }
+///
+/// #[derive(InteractiveRoot, Default)]
+/// struct Root {
+///     sensor: Sensor,
+/// }
+///
+/// let mut root = Root::default();
+/// assert_eq!(root.eval_to_string("sensor.read(0)"), "1.5");
+/// assert_eq!(root.eval_to_string("sensor.read(1)"), "`read` returned an error: no channel 1");
+/// assert_eq!(root.eval_as::<f32>("sensor.read(0)"), Ok(1.5));
+///
+/// let keep = OutputOptions { keep_results: true, ..OutputOptions::default() };
+/// assert_eq!(root.eval_to_string_with("sensor.read(0)", &keep), "Ok(1.5)");
+///
+/// root.sensor.eval_method_output("read", "1", &mut |result| {
+///     let method_result = result.unwrap().as_method_result().unwrap();
+///     assert_eq!(format!("{:?}", method_result.result.unwrap_err()), "\"no channel 1\"");
+/// });
+/// ```
+///
+/// A `#[Methods]` impl block of a generic type or without default features implements [`trait@Methods`] as shown below.
+/// With default features on, the impl blocks of non-generic types implement [`MethodsFragment`](registry::MethodsFragment) instead
+/// and the [`trait@Methods`] impl of the type looks up their methods in the registry before the flattened fields.
+///
+/// # What it does:
+/// ```
+/// # use rusteval::{Interactive, Methods};
+/// #
+/// # #[derive(Interactive)]
 /// # struct Struct;
 /// #
 /// #[Methods]
@@ -267,29 +733,31 @@ pub use rusteval_derive::Interactive;
 /// impl Methods for Struct {
 ///     fn eval_method(&self, method_name: &str, args: &str, f: &mut dyn FnMut(Result<'_, &dyn Debug>)) {
 ///         match method_name {
-///             "ping" => match parse_0_args(method_name, args) {
+///             "ping" => match parse_0_args(method_name, args, &[]) {
 ///                 Ok(()) => f(Ok(&self.ping())),
 ///                 Err(e) => f(Err(e)),
 ///             },
 ///             _ => f(Err(MethodNotFound {
 ///                 type_name: "Struct",
 ///                 method_name,
+///                 suggestion: did_you_mean(method_name, self.get_all_method_names()),
 ///             })),
 ///         }
 ///     }
 ///     fn eval_method_mut(&mut self, method_name: &str, args: &str, f: &mut dyn FnMut(Result<'_, &dyn Debug>)) {
 ///         match method_name {
-///             "ping" => match parse_0_args(method_name, args) {
+///             "ping" => match parse_0_args(method_name, args, &[]) {
 ///                 Ok(()) => f(Ok(&self.ping())),
 ///                 Err(e) => f(Err(e)),
 ///             },
-///             "frob" => match parse_1_arg(method_name, args) {
+///             "frob" => match parse_1_arg(method_name, args, &[("arg", "u32")]) {
 ///                 Ok((arg0,)) => f(Ok(&self.frob(arg0))),
 ///                 Err(e) => f(Err(e)),
 ///             },
 ///             _ => f(Err(MethodNotFound {
 ///                 type_name: "Struct",
 ///                 method_name,
+///                 suggestion: did_you_mean(method_name, self.get_all_method_names()),
 ///             })),
 ///         }
 ///     }
@@ -298,6 +766,12 @@ pub use rusteval_derive::Interactive;
 ///     }
 /// }
 /// ```
+/// [`Methods::eval_method_output`] and [`Methods::eval_method_mut_output`] are generated the same way,
+/// but pass the results as `&dyn Output`.
+/// [`Methods::call_typed_method`] and [`Methods::call_typed_method_mut`] are generated the same way as well,
+/// but take the arguments out of a [`TypedArgs`] as a `(u32,)` tuple instead of parsing them.
+/// [`Methods::get_all_method_signatures`] returns a [`Signature`] for every method in
+/// `get_all_method_names`, with its parameters, return type, receiver and doc comment.
 pub use rusteval_derive::Methods;
 
 /// Implements [`Debug`] for a struct replacing all fields that do not implement `Debug` with a placeholder.
@@ -379,7 +853,7 @@ pub use rusteval_derive::PartialDebug;
 /// struct FunctionXYZ;
 /// impl Function for FunctionXYZ {
 ///     fn eval(&self, args: &str, f: &mut dyn FnMut(Result<'_, &dyn Debug>)) {
-///         match parse_1_arg("add_one", args) {
+///         match parse_1_arg("add_one", args, &[("a", "u32")]) {
 ///             Ok((arg0,)) => f(Ok(&add_one(arg0))),
 ///             Err(e) => f(Err(e)),
 ///         }
@@ -396,19 +870,55 @@ pub use rusteval_derive::PartialDebug;
 #[cfg(feature = "std")]
 pub use rusteval_derive::Function;
 
-pub use error::{ArgParseError, InteractiveError, Result};
+pub use error::{ArgParseError, ErrorKind, InteractiveError, Result};
 #[cfg(feature = "std")]
 pub use function::Function;
-pub use interactive::{Interactive, Methods};
+pub use interactive::{FieldInfo, Interactive, Methods, SkippedMethod};
+pub use output::{MethodResult, Output, OutputOptions, Radix};
+#[cfg(feature = "std")]
+pub use owned_error::OwnedInteractiveError;
 pub use root::InteractiveRoot;
+#[cfg(feature = "std")]
+pub use schema::{FieldSchema, FunctionSchema, Schema, TypeSchema};
+pub use signature::{Parameter, Receiver, Signature};
+pub use static_type::StaticType;
+pub use suggest::did_you_mean;
+pub use typed_args::TypedArgs;
+#[cfg(feature = "std")]
+pub use value::Value;
 
 #[cfg(feature = "std")]
 #[doc(hidden)]
 pub use inventory;
+#[cfg(feature = "std")]
+#[doc(hidden)]
+pub use lazy_static;
 
 pub mod arg_parse;
 mod error;
+pub mod flatten;
+pub mod format;
 mod function;
+mod glob;
+mod help;
 mod interactive;
+mod output;
+#[cfg(feature = "std")]
+mod owned_error;
+pub mod panic;
+pub mod property;
+#[cfg(feature = "std")]
+mod reference;
+#[cfg(feature = "std")]
+pub mod registry;
 mod root;
+#[cfg(feature = "std")]
+mod schema;
+mod signature;
 pub mod specialization;
+mod static_type;
+mod suggest;
+mod tree;
+mod typed_args;
+#[cfg(feature = "std")]
+mod value;
@@ -0,0 +1,805 @@
+use core::any::{type_name, Any};
+use core::fmt::{self, Debug, Display, Write};
+
+use crate::format::Formatted;
+#[cfg(feature = "serde")]
+use crate::specialization::AsSerialize;
+use crate::specialization::{AsAny, AsDisplay};
+#[cfg(feature = "std")]
+use crate::value::{ToValue, Value};
+
+/// The result of an evaluation.
+///
+/// Implemented for all types that implement [`Debug`].
+/// Everything else a result can be shown as is looked up with specialization.
+pub trait Output: Debug {
+    /// Returns the result as `&dyn Display` if it implements [`Display`].
+    ///
+    /// Used by [`OutputOptions::prefer_display`].
+    fn as_display(&self) -> Option<&dyn Display>;
+
+    /// Returns the result as `&dyn Any` if it implements [`StaticType`](crate::StaticType).
+    ///
+    /// Used by [`eval_as`](crate::InteractiveRoot::eval_as) to downcast the result.
+    fn as_any(&self) -> Option<&dyn Any>;
+
+    /// Returns the name of the result's type.
+    fn type_name(&self) -> &'static str;
+
+    /// Returns the result as a [`MethodResult`] if it is the `Result` returned by a method.
+    ///
+    /// Used to unwrap the `Result`, see [`OutputOptions::keep_results`].
+    fn as_method_result(&self) -> Option<MethodResult<'_>>;
+
+    /// Converts the result into JSON if it implements `Serialize`,
+    /// otherwise returns its `Debug` representation wrapped in a JSON object: `{"debug": "..."}`.
+    ///
+    /// Only available with the `serde` feature.
+    #[cfg(feature = "serde")]
+    fn to_json(&self) -> serde_json::Value;
+
+    /// Converts the result into an owned [`Value`].
+    ///
+    /// Not available in no_std contexts.
+    #[cfg(feature = "std")]
+    fn to_value(&self) -> Value;
+}
+
+impl<T: Debug> Output for T {
+    default fn as_display(&self) -> Option<&dyn Display> {
+        self.try_as_display().ok()
+    }
+
+    default fn as_any(&self) -> Option<&dyn Any> {
+        self.try_as_any()
+    }
+
+    default fn type_name(&self) -> &'static str {
+        type_name::<T>()
+    }
+
+    default fn as_method_result(&self) -> Option<MethodResult<'_>> {
+        None
+    }
+
+    #[cfg(feature = "serde")]
+    default fn to_json(&self) -> serde_json::Value {
+        to_json_or_debug(self, self)
+    }
+
+    #[cfg(feature = "std")]
+    default fn to_value(&self) -> Value {
+        ToValue::to_value(self)
+    }
+}
+
+/// Values with a format hint are always shown with it, but otherwise behave like the value itself.
+impl<T, F> Output for Formatted<'_, T, F>
+where
+    F: Fn(&mut fmt::Formatter<'_>) -> fmt::Result,
+{
+    fn as_display(&self) -> Option<&dyn Display> {
+        None
+    }
+
+    fn as_any(&self) -> Option<&dyn Any> {
+        self.value().try_as_any()
+    }
+
+    fn type_name(&self) -> &'static str {
+        type_name::<T>()
+    }
+
+    #[cfg(feature = "serde")]
+    fn to_json(&self) -> serde_json::Value {
+        to_json_or_debug(self.value(), self)
+    }
+
+    #[cfg(feature = "std")]
+    fn to_value(&self) -> Value {
+        self.value().to_value()
+    }
+}
+
+/// The `Result` returned by a method, with its `Ok` value or its `Err` as a `&dyn Output`.
+///
+/// It is passed to the closures of the [`Methods`](trait@crate::Methods) and [`Function`](trait@crate::Function) traits
+/// and shown like the `Result` itself, e.g. `Ok(42)`.
+/// Queries evaluated by an [`InteractiveRoot`](trait@crate::InteractiveRoot) unwrap it,
+/// unless [`OutputOptions::keep_results`] is set.
+#[derive(Clone, Copy)]
+pub struct MethodResult<'a> {
+    /// The name of the method that returned the `Result`.
+    pub method_name: &'a str,
+    /// The name of the returned type, e.g. `core::result::Result<u8, app::Error>`.
+    pub type_name: &'static str,
+    /// The `Ok` value or the `Err`.
+    pub result: core::result::Result<&'a dyn Output, &'a dyn Output>,
+}
+
+impl Debug for MethodResult<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.result.fmt(f)
+    }
+}
+
+impl Output for MethodResult<'_> {
+    fn as_display(&self) -> Option<&dyn Display> {
+        None
+    }
+
+    fn as_any(&self) -> Option<&dyn Any> {
+        None
+    }
+
+    fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    fn as_method_result(&self) -> Option<MethodResult<'_>> {
+        Some(*self)
+    }
+
+    #[cfg(feature = "serde")]
+    fn to_json(&self) -> serde_json::Value {
+        match self.result {
+            Ok(value) => serde_json::json!({ "Ok": value.to_json() }),
+            Err(error) => serde_json::json!({ "Err": error.to_json() }),
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn to_value(&self) -> Value {
+        Value::Debug(format!("{:?}", self))
+    }
+}
+
+/// A value that only implements [`Display`], shown with it in place of `Debug`.
+///
+/// Created by [`AsOutput`](crate::specialization::AsOutput) for results that don't implement `Debug`.
+#[repr(transparent)]
+pub(crate) struct DisplayOnly<T>(T);
+
+impl<T> DisplayOnly<T> {
+    pub(crate) fn new(value: &T) -> &Self {
+        // SAFETY: `DisplayOnly<T>` is a `repr(transparent)` wrapper around `T`
+        unsafe { &*core::ptr::from_ref(value).cast::<Self>() }
+    }
+}
+
+impl<T> Debug for DisplayOnly<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0.try_as_display() {
+            Ok(display) => display.fmt(f),
+            Err(_) => f.write_str(type_name::<T>()),
+        }
+    }
+}
+
+impl<T> Output for DisplayOnly<T> {
+    fn as_display(&self) -> Option<&dyn Display> {
+        self.0.try_as_display().ok()
+    }
+
+    fn as_any(&self) -> Option<&dyn Any> {
+        self.0.try_as_any()
+    }
+
+    fn type_name(&self) -> &'static str {
+        type_name::<T>()
+    }
+
+    #[cfg(feature = "serde")]
+    fn to_json(&self) -> serde_json::Value {
+        to_json_or_debug(&self.0, self)
+    }
+
+    #[cfg(feature = "std")]
+    fn to_value(&self) -> Value {
+        Value::Debug(format!("{:?}", self))
+    }
+}
+
+#[cfg(feature = "serde")]
+fn to_json_or_debug<T>(value: &T, debug: &dyn Debug) -> serde_json::Value {
+    value
+        .try_to_json()
+        .unwrap_or_else(|| serde_json::json!({ "debug": format!("{:?}", debug) }))
+}
+
+/// Options that change how results are written by
+/// [`eval_to_string_with`](crate::InteractiveRoot::eval_to_string_with) and
+/// [`eval_and_write_with`](crate::InteractiveRoot::eval_and_write_with).
+///
+/// The options are applied to the `Debug` output of a result, so they work without changing the result's type.
+/// Errors are written unchanged.
+///
+/// # Example
+/// ```
+/// use rusteval::{OutputOptions, Radix};
+///
+/// let options = OutputOptions {
+///     pretty: true,
+///     radix: Some(Radix::Hex),
+///     ..OutputOptions::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutputOptions {
+    /// Use pretty printing (`{:#?}`).
+    pub pretty: bool,
+    /// Nesting levels deeper than this are replaced with `..`, e.g. `Parent { child: Child { .. } }` for a max depth of 1.
+    pub max_depth: Option<usize>,
+    /// Output longer than this many chars is cut off and ends with the [`truncation_marker`](#structfield.truncation_marker).
+    pub max_len: Option<usize>,
+    /// Appended to truncated output.
+    pub truncation_marker: &'static str,
+    /// Show all integers in this radix instead of decimal.
+    pub radix: Option<Radix>,
+    /// Show all floats with this many digits after the decimal point.
+    pub float_precision: Option<usize>,
+    /// Show results with `Display` if they implement it and fall back to `Debug` otherwise.
+    ///
+    /// Only applies to the result itself, the fields of a result are still shown with `Debug`.
+    /// `Display` output is written as it is, apart from [`max_len`](#structfield.max_len):
+    /// [`radix`](#structfield.radix) and [`float_precision`](#structfield.float_precision)
+    /// only apply if the result itself is an integer or a float.
+    pub prefer_display: bool,
+    /// Show a `Result` returned by a method as it is, e.g. `Ok(42)` or `Err(Timeout)`.
+    ///
+    /// By default only the `Ok` value is shown,
+    /// and an `Err` is written as [`InteractiveError::MethodReturnedError`](crate::InteractiveError::MethodReturnedError).
+    /// The matches of a query with wildcards are always unwrapped.
+    pub keep_results: bool,
+}
+
+impl Default for OutputOptions {
+    fn default() -> Self {
+        Self {
+            pretty: false,
+            max_depth: None,
+            max_len: None,
+            truncation_marker: "...",
+            radix: None,
+            float_precision: None,
+            prefer_display: false,
+            keep_results: false,
+        }
+    }
+}
+
+/// An integer radix used by [`OutputOptions::radix`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Radix {
+    /// `0b101`
+    Binary,
+    /// `0o5`
+    Octal,
+    /// `0x5`
+    Hex,
+}
+
+/// Writes the value's `Debug` or `Display` representation with the given options applied.
+pub(crate) fn write_value<W: Write>(
+    buf: &mut W,
+    value: &dyn Output,
+    options: &OutputOptions,
+) -> fmt::Result {
+    let mut writer = OutputWriter::new(buf, options);
+
+    let display = value.as_display().filter(|_| options.prefer_display);
+    let result = match display {
+        // `Display` text is written as it is, only numbers are converted
+        Some(display) => match value
+            .as_any()
+            .and_then(|any| write_number(&mut writer.output, any, options))
+        {
+            Some(result) => result,
+            None => write!(writer.output, "{}", display),
+        },
+        None if options.pretty => write!(writer, "{:#?}", value),
+        None => write!(writer, "{:?}", value),
+    };
+
+    // the writer errors on purpose to stop formatting truncated output
+    if !writer.output.truncated {
+        result?;
+        writer.finish()?;
+    }
+    if writer.output.truncated {
+        writer.output.inner.write_str(options.truncation_marker)?;
+    }
+    Ok(())
+}
+
+/// Writes the value with the radix or float precision of the options if it is a primitive number.
+///
+/// Returns `None` without writing anything otherwise.
+fn write_number<W: Write>(
+    output: &mut LimitedWriter<'_, W>,
+    value: &dyn Any,
+    options: &OutputOptions,
+) -> Option<fmt::Result> {
+    macro_rules! unsigned {
+        ($($int:ty),*) => {
+            $(if let Some(&int) = value.downcast_ref::<$int>() {
+                return Some(write_radix(output, false, int as u128, options.radix?));
+            })*
+        };
+    }
+    macro_rules! signed {
+        ($($int:ty),*) => {
+            $(if let Some(&int) = value.downcast_ref::<$int>() {
+                return Some(write_radix(output, int < 0, int.unsigned_abs() as u128, options.radix?));
+            })*
+        };
+    }
+    macro_rules! float {
+        ($($float:ty),*) => {
+            $(if let Some(&float) = value.downcast_ref::<$float>() {
+                return Some(write!(output, "{:.*}", options.float_precision?, float));
+            })*
+        };
+    }
+
+    unsigned!(u8, u16, u32, u64, u128, usize);
+    signed!(i8, i16, i32, i64, i128, isize);
+    float!(f32, f64);
+    None
+}
+
+/// `-0b101`, `0o5` or `0xA4`
+fn write_radix<W: Write>(output: &mut W, negative: bool, int: u128, radix: Radix) -> fmt::Result {
+    if negative {
+        output.write_char('-')?;
+    }
+    match radix {
+        Radix::Binary => write!(output, "{:#b}", int),
+        Radix::Octal => write!(output, "{:#o}", int),
+        Radix::Hex => write!(output, "0x{:X}", int),
+    }
+}
+
+/// Where inside the `Debug` output we are.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Literal {
+    None,
+    Str {
+        escaped: bool,
+    },
+    /// Right after a `'`, which only starts a char literal like `'x'` or `'\n'`,
+    /// not e.g. in `it's` written by a `Display` implementation
+    CharStart,
+    /// `'x` that is only a char literal if the next char is a `'`
+    CharPending(char),
+    /// `'\`, an escape sequence can only be part of a char literal
+    Char {
+        escaped: bool,
+    },
+}
+
+/// Applies [`OutputOptions`] while the `Debug` output is written to it.
+struct OutputWriter<'a, W> {
+    output: LimitedWriter<'a, W>,
+    options: &'a OutputOptions,
+    literal: Literal,
+    /// The previous char is part of an identifier, so digits don't start a number
+    in_ident: bool,
+    number: NumberBuffer,
+    depth: usize,
+}
+
+impl<'a, W: Write> OutputWriter<'a, W> {
+    fn new(inner: &'a mut W, options: &'a OutputOptions) -> Self {
+        Self {
+            output: LimitedWriter {
+                inner,
+                max_len: options.max_len,
+                len: 0,
+                truncated: false,
+                hidden: false,
+            },
+            options,
+            literal: Literal::None,
+            in_ident: false,
+            number: NumberBuffer::default(),
+            depth: 0,
+        }
+    }
+
+    fn push(&mut self, c: char) -> fmt::Result {
+        match &mut self.literal {
+            Literal::Str { escaped } | Literal::Char { escaped } if *escaped => {
+                *escaped = false;
+                return self.output.write_char(c);
+            }
+            Literal::Str { escaped } | Literal::Char { escaped } if c == '\\' => {
+                *escaped = true;
+                return self.output.write_char(c);
+            }
+            Literal::Str { .. } if c == '"' => {
+                self.literal = Literal::None;
+                return self.output.write_char(c);
+            }
+            Literal::Char { .. } if c == '\'' => {
+                self.literal = Literal::None;
+                return self.output.write_char(c);
+            }
+            Literal::Str { .. } | Literal::Char { .. } => return self.output.write_char(c),
+            Literal::CharStart if c == '\\' => {
+                self.literal = Literal::Char { escaped: true };
+                return self.output.write_char(c);
+            }
+            Literal::CharStart => {
+                self.literal = Literal::CharPending(c);
+                return Ok(());
+            }
+            Literal::CharPending(pending) if c == '\'' => {
+                let pending = *pending;
+                self.literal = Literal::None;
+                self.output.write_char(pending)?;
+                return self.output.write_char(c);
+            }
+            Literal::CharPending(pending) => {
+                // not a char literal after all, so the chars are handled as usual
+                let pending = *pending;
+                self.literal = Literal::None;
+                self.push(pending)?;
+                return self.push(c);
+            }
+            Literal::None => {}
+        }
+
+        if !self.number.is_empty() {
+            if self.number.continues_with(c) {
+                return self.number.push(c, &mut self.output);
+            }
+            self.flush_number()?;
+        }
+
+        let converts_numbers =
+            self.options.radix.is_some() || self.options.float_precision.is_some();
+        if converts_numbers && c.is_ascii_digit() && !self.in_ident {
+            return self.number.push(c, &mut self.output);
+        }
+
+        self.in_ident = c.is_alphanumeric() || c == '_';
+
+        match c {
+            '"' => self.literal = Literal::Str { escaped: false },
+            '\'' => self.literal = Literal::CharStart,
+            '{' | '[' | '(' => return self.open(c),
+            '}' | ']' | ')' => return self.close(c),
+            _ => {}
+        }
+        self.output.write_char(c)
+    }
+
+    fn open(&mut self, c: char) -> fmt::Result {
+        self.output.write_char(c)?;
+        self.depth += 1;
+
+        if self
+            .options
+            .max_depth
+            .map(|max_depth| self.depth == max_depth + 1)
+            == Some(true)
+        {
+            self.output
+                .write_str(if c == '{' { " .. " } else { ".." })?;
+            self.output.hidden = true;
+        }
+        Ok(())
+    }
+
+    fn close(&mut self, c: char) -> fmt::Result {
+        if self
+            .options
+            .max_depth
+            .map(|max_depth| self.depth == max_depth + 1)
+            == Some(true)
+        {
+            self.output.hidden = false;
+        }
+        self.depth = self.depth.saturating_sub(1);
+        self.output.write_char(c)
+    }
+
+    /// Writes what is still buffered at the end of the output.
+    fn finish(&mut self) -> fmt::Result {
+        if let Literal::CharPending(pending) = self.literal {
+            self.literal = Literal::None;
+            self.push(pending)?;
+        }
+        self.flush_number()
+    }
+
+    /// Writes the buffered number, converted according to the options if possible.
+    fn flush_number(&mut self) -> fmt::Result {
+        let number = core::mem::take(&mut self.number);
+        let text = number.as_str();
+        if text.is_empty() {
+            return Ok(());
+        }
+        self.in_ident = false;
+
+        if text.bytes().all(|b| b.is_ascii_digit()) {
+            if let (Some(radix), Ok(int)) = (self.options.radix, text.parse::<u128>()) {
+                return write_radix(&mut self.output, false, int, radix);
+            }
+        } else if is_float(text) {
+            if let (Some(precision), Ok(float)) =
+                (self.options.float_precision, text.parse::<f64>())
+            {
+                return write!(self.output, "{:.*}", precision, float);
+            }
+        }
+        self.output.write_str(text)
+    }
+}
+
+impl<W: Write> Write for OutputWriter<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            self.push(c)?;
+        }
+        Ok(())
+    }
+}
+
+/// `1.5`, `1e-7` or `1.5e16`
+fn is_float(text: &str) -> bool {
+    let (mantissa, exponent) = match text.split_once('e') {
+        Some((mantissa, exponent)) => (mantissa, Some(exponent)),
+        None => (text, None),
+    };
+    let exponent_ok = match exponent {
+        Some(exponent) => {
+            let digits = exponent.strip_prefix('-').unwrap_or(exponent);
+            !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit())
+        }
+        None => true,
+    };
+    let mantissa_ok = match mantissa.split_once('.') {
+        Some((int, frac)) => {
+            !int.is_empty()
+                && !frac.is_empty()
+                && int.bytes().all(|b| b.is_ascii_digit())
+                && frac.bytes().all(|b| b.is_ascii_digit())
+        }
+        None => exponent.is_some() && mantissa.bytes().all(|b| b.is_ascii_digit()),
+    };
+    exponent_ok && mantissa_ok
+}
+
+/// Collects a token that starts with a digit, without allocating.
+struct NumberBuffer {
+    bytes: [u8; 64],
+    len: usize,
+}
+
+impl Default for NumberBuffer {
+    fn default() -> Self {
+        Self {
+            bytes: [0; 64],
+            len: 0,
+        }
+    }
+}
+
+impl NumberBuffer {
+    fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn as_str(&self) -> &str {
+        // only ascii is pushed
+        core::str::from_utf8(&self.bytes[..self.len]).unwrap_or_default()
+    }
+
+    /// Numbers and everything that looks like one, e.g. `0x0000_00A4` or `1e-7`.
+    fn continues_with(&self, c: char) -> bool {
+        c.is_ascii_alphanumeric()
+            || c == '_'
+            || c == '.'
+            || (c == '-' && self.bytes[self.len - 1] == b'e')
+    }
+
+    /// Writes everything out unchanged if the token gets too long.
+    fn push<W: Write>(&mut self, c: char, output: &mut LimitedWriter<'_, W>) -> fmt::Result {
+        if self.len == self.bytes.len() {
+            output.write_str(self.as_str())?;
+            self.len = 0;
+        }
+        // only ascii chars continue a number
+        self.bytes[self.len] = c as u8;
+        self.len += 1;
+        Ok(())
+    }
+}
+
+/// Counts the written chars and stops writing after `max_len`.
+struct LimitedWriter<'a, W> {
+    inner: &'a mut W,
+    max_len: Option<usize>,
+    len: usize,
+    truncated: bool,
+    /// Drop everything written, used for nesting levels deeper than `max_depth`.
+    hidden: bool,
+}
+
+impl<W: Write> Write for LimitedWriter<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            self.write_char(c)?;
+        }
+        Ok(())
+    }
+
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        if self.hidden {
+            return Ok(());
+        }
+        if self.max_len == Some(self.len) {
+            self.truncated = true;
+            return Err(fmt::Error);
+        }
+        self.len += 1;
+        self.inner.write_char(c)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(dead_code)]
+    #[derive(Debug)]
+    struct Child {
+        a: u8,
+        name: &'static str,
+        list: [f32; 2],
+    }
+
+    #[allow(dead_code)]
+    #[derive(Debug)]
+    struct Parent {
+        child: Child,
+        id2: u32,
+    }
+
+    fn parent() -> Parent {
+        Parent {
+            child: Child {
+                a: 164,
+                name: "{x1}",
+                list: [1.5, 1e-7],
+            },
+            id2: 10,
+        }
+    }
+
+    fn to_string(value: &dyn Output, options: &OutputOptions) -> String {
+        let mut s = String::new();
+        write_value(&mut s, value, options).unwrap();
+        s
+    }
+
+    #[test]
+    fn test_default() {
+        assert_eq!(
+            to_string(&parent(), &OutputOptions::default()),
+            format!("{:?}", parent())
+        );
+    }
+
+    #[test]
+    fn test_pretty() {
+        let options = OutputOptions {
+            pretty: true,
+            ..OutputOptions::default()
+        };
+        assert_eq!(to_string(&parent(), &options), format!("{:#?}", parent()));
+    }
+
+    #[test]
+    fn test_max_depth() {
+        let options = OutputOptions {
+            max_depth: Some(1),
+            ..OutputOptions::default()
+        };
+        assert_eq!(
+            to_string(&parent(), &options),
+            "Parent { child: Child { .. }, id2: 10 }"
+        );
+
+        let options = OutputOptions {
+            max_depth: Some(2),
+            pretty: true,
+            ..OutputOptions::default()
+        };
+        assert_eq!(
+            to_string(&parent(), &options),
+            "Parent {\n    child: Child {\n        a: 164,\n        name: \"{x1}\",\n        list: [..],\n    },\n    id2: 10,\n}"
+        );
+    }
+
+    #[test]
+    fn test_max_len() {
+        let options = OutputOptions {
+            max_len: Some(10),
+            ..OutputOptions::default()
+        };
+        assert_eq!(to_string(&parent(), &options), "Parent { c...");
+        assert_eq!(to_string(&"short", &options), "\"short\"");
+    }
+
+    #[test]
+    fn test_radix() {
+        let mut options = OutputOptions {
+            radix: Some(Radix::Hex),
+            ..OutputOptions::default()
+        };
+        assert_eq!(
+            to_string(&parent(), &options),
+            "Parent { child: Child { a: 0xA4, name: \"{x1}\", list: [1.5, 1e-7] }, id2: 0xA }"
+        );
+        options.radix = Some(Radix::Binary);
+        assert_eq!(to_string(&-5, &options), "-0b101");
+        options.radix = Some(Radix::Octal);
+        assert_eq!(to_string(&8u8, &options), "0o10");
+    }
+
+    #[test]
+    fn test_float_precision() {
+        let options = OutputOptions {
+            float_precision: Some(2),
+            ..OutputOptions::default()
+        };
+        assert_eq!(
+            to_string(&parent(), &options),
+            "Parent { child: Child { a: 164, name: \"{x1}\", list: [1.50, 0.00] }, id2: 10 }"
+        );
+    }
+
+    #[test]
+    fn test_char_literals() {
+        let options = OutputOptions {
+            radix: Some(Radix::Hex),
+            max_depth: Some(0),
+            ..OutputOptions::default()
+        };
+        assert_eq!(to_string(&('1', '\'', 1), &options), "(..)");
+        let options = OutputOptions {
+            radix: Some(Radix::Hex),
+            ..OutputOptions::default()
+        };
+        assert_eq!(
+            to_string(&('1', '\'', '(', 1), &options),
+            "('1', '\\'', '(', 0x1)"
+        );
+    }
+
+    #[test]
+    fn test_apostrophes() {
+        struct Text(&'static str);
+
+        impl Debug for Text {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.0)
+            }
+        }
+
+        let options = OutputOptions {
+            radix: Some(Radix::Hex),
+            ..OutputOptions::default()
+        };
+        assert_eq!(
+            to_string(&Text("it's 10 items"), &options),
+            "it's 0xA items"
+        );
+        assert_eq!(to_string(&Text("10 o'clock"), &options), "0xA o'clock");
+        assert_eq!(to_string(&Text("ends with '5"), &options), "ends with '0x5");
+        assert_eq!(to_string(&Text("'5' 5"), &options), "'5' 0x5");
+    }
+}
@@ -0,0 +1,95 @@
+use core::fmt::{Display, Formatter};
+
+use crate::{ErrorKind, InteractiveError};
+
+/// An owned version of [`InteractiveError`] that can be stored, sent to another thread
+/// or returned from a server handler.
+///
+/// Errors returned by methods like [`eval_as`](crate::InteractiveRoot::eval_as) also carry
+/// the query that failed and the types of the objects that were walked until the error occurred.
+/// Converting an `InteractiveError` with `From` leaves both empty.
+///
+/// Displays the same message as the `InteractiveError` it was created from.
+///
+/// # Example
+///
+/// ```
+/// use rusteval::{ErrorKind, Interactive, InteractiveRoot};
+///
+/// #[derive(Interactive, Debug, Default)]
+/// struct Child {
+///     value: u8,
+/// }
+///
+/// #[derive(InteractiveRoot, Debug, Default)]
+/// struct Root {
+///     child: Child,
+/// }
+///
+/// let error = Root::default().eval_as::<u8>("child.vlue").unwrap_err();
+/// assert_eq!(error.kind(), ErrorKind::FieldNotFound);
+/// assert_eq!(error.query(), Some("child.vlue"));
+/// assert_eq!(error.type_path(), &["Root", "Child"]);
+/// assert_eq!(
+///     error.to_string(),
+///     "No field `vlue` found for type `Child`, did you mean `value`?"
+/// );
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct OwnedInteractiveError {
+    kind: ErrorKind,
+    message: String,
+    query: Option<String>,
+    type_path: Vec<&'static str>,
+}
+
+impl OwnedInteractiveError {
+    /// Returns which kind of error this is.
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    /// Returns the error message, the same as the `Display` representation.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns the query that failed, if known.
+    pub fn query(&self) -> Option<&str> {
+        self.query.as_deref()
+    }
+
+    /// Returns the type names of the objects that were walked until the error occurred,
+    /// starting with the root.
+    ///
+    /// E.g. `["Root", "Parent"]` for the query `parent.child.field` if `Parent` has no field `child`.
+    pub fn type_path(&self) -> &[&'static str] {
+        &self.type_path
+    }
+
+    /// Adds the query that failed and the types walked for it.
+    pub(crate) fn with_context(mut self, query: &str, type_path: Vec<&'static str>) -> Self {
+        self.query = Some(query.to_string());
+        self.type_path = type_path;
+        self
+    }
+}
+
+impl From<InteractiveError<'_>> for OwnedInteractiveError {
+    fn from(error: InteractiveError<'_>) -> Self {
+        OwnedInteractiveError {
+            kind: error.kind(),
+            message: error.to_string(),
+            query: None,
+            type_path: Vec::new(),
+        }
+    }
+}
+
+impl Display for OwnedInteractiveError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for OwnedInteractiveError {}
@@ -0,0 +1,41 @@
+//! Catching panics during the evaluation of a query.
+//!
+//! A root opts in with `#[interactive(catch_panics)]` or `#[interactive(panic_handler = "...")]`,
+//! see [`InteractiveRoot`](macro@crate::InteractiveRoot).
+//! [`InteractiveRoot::try_eval`](crate::InteractiveRoot::try_eval), [`InteractiveRoot::try_eval_mut`](crate::InteractiveRoot::try_eval_mut)
+//! and the methods built on top of them then pass panics
+//! to their closure as [`InteractiveError::Panicked`](crate::InteractiveError::Panicked).
+
+/// Runs `call` and if it panicked calls `on_panic` with the panic message.
+///
+/// Without the `std` feature there is no [`catch_unwind`],
+/// so a handler has to be supplied with `#[interactive(panic_handler = "path::to::handler")]`,
+/// e.g. one that is built on top of an unwinding implementation for the target.
+/// A panic in the closure passed to an `eval` method is raised again,
+/// without `std` with `panic!` and the message `on_panic` was called with.
+pub type PanicHandler = fn(call: &mut dyn FnMut(), on_panic: &mut dyn FnMut(&str));
+
+/// A [`PanicHandler`] using [`std::panic::catch_unwind`].
+/// Used by `#[interactive(catch_panics)]`.
+///
+/// The message is the panic's payload if it is a `&str` or `String`, like for `panic!("...")`,
+/// and `Box<dyn Any>` otherwise.
+///
+/// The panic hook still runs as usual, so the panic is still printed to stderr by default.
+/// Panics can't be caught if the application is built with `panic = "abort"`.
+#[cfg(feature = "std")]
+pub fn catch_unwind(call: &mut dyn FnMut(), on_panic: &mut dyn FnMut(&str)) {
+    use std::panic::AssertUnwindSafe;
+
+    // the objects a query accesses might be left in an inconsistent state, that's documented
+    if let Err(payload) = std::panic::catch_unwind(AssertUnwindSafe(call)) {
+        let message = match payload.downcast_ref::<&str>() {
+            Some(message) => message,
+            None => match payload.downcast_ref::<String>() {
+                Some(message) => message.as_str(),
+                None => "Box<dyn Any>",
+            },
+        };
+        on_panic(message)
+    }
+}
@@ -0,0 +1,75 @@
+//! Implementation details.
+//!
+//! Methods marked with `#[interactive(property)]` are exposed through [`Methods::eval_property`](crate::Methods::eval_property).
+//! The code generated by [`Interactive`](macro@crate::Interactive) falls back to the functions in this module
+//! when a field wasn't found on the type itself.
+use crate::specialization::AsMethods;
+use crate::{Interactive, InteractiveError, Output, Result, Signature};
+
+/// Looks for the property on the given object and evaluates it.
+///
+/// Returns `false` without calling the closure if the property wasn't found.
+pub fn eval_property<T>(
+    object: &T,
+    property_name: &str,
+    f: &mut dyn FnMut(Result<'_, &dyn Output>),
+) -> bool {
+    let mut found = false;
+    if let Ok(object) = object.try_as_methods() {
+        found = true;
+        object.eval_property(property_name, &mut |result| match result {
+            Err(InteractiveError::FieldNotFound { .. }) => found = false,
+            result => f(result),
+        });
+    }
+    found
+}
+
+/// Appends the property names of the given object to the given field names.
+///
+/// The generated code only calls this once per type and caches the result.
+#[cfg(feature = "std")]
+pub fn get_all_field_names<T>(object: &T, field_names: &[&'static str]) -> Vec<&'static str> {
+    let mut names = field_names.to_vec();
+    if let Ok(object) = object.try_as_methods() {
+        names.extend_from_slice(object.get_all_property_names());
+    }
+    names
+}
+
+/// Whether the object or one of its flattened fields has a setter for the property.
+pub(crate) fn is_writable_property(object: &dyn Interactive, name: &str) -> bool {
+    if let Ok(methods) = object.try_as_methods() {
+        if methods.get_all_writable_property_names().contains(&name) {
+            return true;
+        }
+    }
+    let mut index = 0;
+    while let Some(child) = object.get_flattened_field(index) {
+        if is_writable_property(child, name) {
+            return true;
+        }
+        index += 1;
+    }
+    false
+}
+
+/// Looks for the getter signature of the property on the object and its flattened fields.
+pub(crate) fn property_signature(
+    object: &dyn Interactive,
+    name: &str,
+) -> Option<&'static Signature> {
+    if let Ok(methods) = object.try_as_methods() {
+        if let Some(signature) = methods.get_property_signature(name) {
+            return Some(signature);
+        }
+    }
+    let mut index = 0;
+    while let Some(child) = object.get_flattened_field(index) {
+        if let Some(signature) = property_signature(child, name) {
+            return Some(signature);
+        }
+        index += 1;
+    }
+    None
+}
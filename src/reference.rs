@@ -0,0 +1,157 @@
+use core::fmt::{Result, Write};
+
+use crate::property::is_writable_property;
+use crate::tree::Node;
+use crate::{Function, Interactive, Signature};
+
+/// Renders the Markdown command reference, see
+/// [`InteractiveRoot::command_reference`](crate::InteractiveRoot::command_reference).
+pub(crate) fn command_reference(root: &dyn Interactive) -> String {
+    let mut markdown = String::new();
+    write_reference(&mut markdown, root).expect("writing to a String can't fail");
+    markdown
+}
+
+// every line written ends with a newline, blocks start with an empty line
+
+fn write_reference(out: &mut String, root: &dyn Interactive) -> Result {
+    writeln!(out, "# `{}` command reference", root.get_type_name())?;
+    let node = Node {
+        object: root,
+        name: "",
+        parent: None,
+    };
+    write_object(out, &node, true)?;
+
+    let mut functions: Vec<_> = inventory::iter::<&dyn Function>
+        .into_iter()
+        .map(|function| (function.module_path(), function.signature()))
+        .collect();
+    if functions.is_empty() {
+        return Ok(());
+    }
+    functions.sort_by_key(|(module_path, signature)| (*module_path, signature.name));
+
+    out.write_str("\n## Functions\n")?;
+    let mut current_module = None;
+    for (module_path, signature) in functions {
+        if current_module != Some(module_path) {
+            write!(out, "\n### `{}`\n\n", module_path)?;
+            current_module = Some(module_path);
+        }
+        write_signature(out, "", &signature)?;
+    }
+    Ok(())
+}
+
+/// Writes a section for the object and then for each of its interactive fields.
+///
+/// `mutable` is false if the object can only be reached through a read-only field.
+/// Objects that are their own ancestors, e.g. through an `Rc`, already have a section and are skipped.
+fn write_object(out: &mut String, node: &Node<'_>, mutable: bool) -> Result {
+    if node.is_cycle() {
+        return Ok(());
+    }
+    let object = node.object;
+    let path = node.to_string();
+    let path = path.as_str();
+    let field_names = object.get_all_field_names();
+    let signatures: Vec<_> = match object.try_as_methods() {
+        Ok(methods) => methods
+            .get_all_method_signatures()
+            .iter()
+            // functions are listed in their own section
+            .filter(|signature| signature.receiver.is_some())
+            .collect(),
+        Err(_) => Vec::new(),
+    };
+
+    if !field_names.is_empty() || !signatures.is_empty() {
+        match path {
+            "" => writeln!(out, "\n## `{}`", object.get_type_name())?,
+            _ => writeln!(out, "\n## `{}`: `{}`", path, object.get_type_name())?,
+        }
+    }
+
+    if !field_names.is_empty() {
+        out.write_str("\n### Fields\n\n")?;
+        out.write_str("| Query | Type | Access | Description |\n")?;
+        out.write_str("| --- | --- | --- | --- |\n")?;
+        for name in field_names {
+            // properties have no metadata
+            let info = object.get_field_info(name);
+            let type_name = match info {
+                Some(info) => format!("`{}`", escape_cell(info.type_name)),
+                None => String::new(),
+            };
+            let writable = match info {
+                Some(info) => info.mutable,
+                None => is_writable_property(object, name),
+            };
+            let access = match mutable && writable {
+                true => "read-write",
+                false => "read-only",
+            };
+            let docs = info.map_or("", |info| info.docs).replace('\n', " ");
+            writeln!(
+                out,
+                "| `{}` | {} | {} | {} |",
+                join(path, name),
+                type_name,
+                access,
+                escape_cell(&docs)
+            )?;
+        }
+    }
+
+    if !signatures.is_empty() {
+        out.write_str("\n### Methods\n\n")?;
+        for signature in signatures {
+            write_signature(out, path, signature)?;
+        }
+    }
+
+    for name in field_names {
+        if let Ok(field) = object.get_field(name) {
+            let field_mutable = object.get_field_info(name).is_some_and(|info| info.mutable);
+            let child = Node {
+                object: field,
+                name,
+                parent: Some(node),
+            };
+            write_object(out, &child, mutable && field_mutable)?;
+        }
+    }
+    Ok(())
+}
+
+/// ``- `path.add(a: f32, b: f32) -> f32`: docs``, later lines of the docs are indented
+fn write_signature(out: &mut String, path: &str, signature: &Signature) -> Result {
+    match path {
+        "" => write!(out, "- `{}`", signature)?,
+        _ => write!(out, "- `{}.{}`", path, signature)?,
+    }
+    let mut lines = signature.docs.lines();
+    if let Some(summary) = lines.next() {
+        write!(out, ": {}", summary)?;
+    }
+    for line in lines {
+        match line {
+            "" => out.write_char('\n')?,
+            _ => write!(out, "\n  {}", line)?,
+        }
+    }
+    out.write_char('\n')
+}
+
+fn join(path: &str, name: &str) -> String {
+    match path {
+        "" => name.to_string(),
+        _ => format!("{}.{}", path, name),
+    }
+}
+
+/// Pipes would end a table cell early.
+fn escape_cell(text: &str) -> String {
+    text.replace('|', "\\|")
+}
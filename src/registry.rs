@@ -0,0 +1,239 @@
+//! Implementation details.
+//!
+//! Every `#[Methods]` impl block of a non-generic type submits a [`MethodsFragment`] to a global registry.
+//! The [`Methods`](crate::Methods) implementation of that type then dispatches to all fragments registered for it,
+//! which allows a type to spread its interactive methods over several inherent and trait impl blocks.
+//!
+//! That implementation comes from `#[derive(Interactive)]`, `#[derive(InteractiveRoot)]`
+//! or an impl block marked `#[Methods(primary)]`, which also implement [`RegisteredMethods`].
+use core::any::{Any, TypeId};
+
+use crate::{Output, Result, Signature, SkippedMethod, TypedArgs};
+
+/// Implemented by types whose [`Methods`](crate::Methods) implementation dispatches to the registry.
+///
+/// Every registered `#[Methods]` impl block checks that its type implements it,
+/// so the methods of the block can't be unreachable.
+#[diagnostic::on_unimplemented(
+    message = "the `#[Methods]` impl blocks of `{Self}` are not reachable",
+    label = "`{Self}` doesn't implement `Methods` through the registry",
+    note = "derive `Interactive` for `{Self}` or mark one of its impl blocks with `#[Methods(primary)]`"
+)]
+pub trait RegisteredMethods {}
+
+/// Fails to compile if `T` doesn't dispatch to its registered impl blocks.
+pub fn assert_registered<T: RegisteredMethods>() {}
+
+/// The interactive methods of a single `#[Methods]` impl block.
+///
+/// This trait gets implemented automatically when you use the [`Methods`] attribute on an impl block of a non-generic type.
+///
+/// [`Methods`]: macro@crate::Methods
+pub trait MethodsFragment: Sync {
+    /// Returns the [`TypeId`] of the type the impl block belongs to.
+    fn self_type_id(&self) -> TypeId;
+
+    /// Same as [`Methods::eval_method`](crate::Methods::eval_method),
+    /// but returns `false` without calling the closure if this fragment doesn't contain the method.
+    fn eval_method(
+        &self,
+        object: &dyn Any,
+        method_name: &str,
+        args: &str,
+        f: &mut dyn FnMut(Result<'_, &dyn Output>),
+    ) -> bool;
+
+    /// Same as [`Methods::eval_method_mut`](crate::Methods::eval_method_mut),
+    /// but returns `false` without calling the closure if this fragment doesn't contain the method.
+    fn eval_method_mut(
+        &self,
+        object: &mut dyn Any,
+        method_name: &str,
+        args: &str,
+        f: &mut dyn FnMut(Result<'_, &dyn Output>),
+    ) -> bool;
+
+    /// Same as [`Methods::call_typed_method`](crate::Methods::call_typed_method),
+    /// but returns `false` without calling the closure if this fragment doesn't contain the method.
+    fn call_typed_method(
+        &self,
+        object: &dyn Any,
+        method_name: &str,
+        args: &mut TypedArgs<'_>,
+        f: &mut dyn FnMut(Result<'_, &dyn Output>),
+    ) -> bool;
+
+    /// Same as [`Methods::call_typed_method_mut`](crate::Methods::call_typed_method_mut),
+    /// but returns `false` without calling the closure if this fragment doesn't contain the method.
+    fn call_typed_method_mut(
+        &self,
+        object: &mut dyn Any,
+        method_name: &str,
+        args: &mut TypedArgs<'_>,
+        f: &mut dyn FnMut(Result<'_, &dyn Output>),
+    ) -> bool;
+
+    /// Same as [`Methods::eval_property`](crate::Methods::eval_property),
+    /// but returns `false` without calling the closure if this fragment doesn't contain the property.
+    fn eval_property(
+        &self,
+        object: &dyn Any,
+        property_name: &str,
+        f: &mut dyn FnMut(Result<'_, &dyn Output>),
+    ) -> bool;
+
+    /// Same as [`Methods::set_property`](crate::Methods::set_property),
+    /// but returns `false` without calling the closure if this fragment doesn't contain the property.
+    fn set_property(
+        &self,
+        object: &mut dyn Any,
+        property_name: &str,
+        value: &str,
+        f: &mut dyn FnMut(Result<'_, &dyn Output>),
+    ) -> bool;
+
+    /// Returns all interactive method names of this fragment.
+    fn get_all_method_names(&self) -> &'static [&'static str];
+
+    /// Returns the signatures of all interactive methods of this fragment.
+    fn get_all_method_signatures(&self) -> &'static [Signature];
+
+    /// Returns all property names of this fragment.
+    fn get_all_property_names(&self) -> &'static [&'static str];
+
+    /// Returns the names of all properties of this fragment that have a setter.
+    fn get_all_writable_property_names(&self) -> &'static [&'static str];
+
+    /// Returns the signature of the getter of the property, if this fragment contains it.
+    fn get_property_signature(&self, property_name: &str) -> Option<&'static Signature>;
+
+    /// Returns all methods of this fragment that couldn't be made interactive.
+    fn get_all_skipped_methods(&self) -> &'static [SkippedMethod];
+}
+
+// Implement inventory::Collect for ´&dyn MethodsFragment´
+inventory::collect!(&'static dyn MethodsFragment);
+
+/// Returns all fragments registered for the given type.
+pub fn fragments(type_id: TypeId) -> impl Iterator<Item = &'static dyn MethodsFragment> {
+    inventory::iter::<&dyn MethodsFragment>
+        .into_iter()
+        .copied()
+        .filter(move |fragment| fragment.self_type_id() == type_id)
+}
+
+/// Evaluates the method with the first fragment registered for the object's type that contains it.
+///
+/// Returns `false` if no such fragment was found.
+pub fn eval_method(
+    object: &dyn Any,
+    method_name: &str,
+    args: &str,
+    f: &mut dyn FnMut(Result<'_, &dyn Output>),
+) -> bool {
+    fragments(object.type_id()).any(|fragment| fragment.eval_method(object, method_name, args, f))
+}
+
+/// Same as [`eval_method`] but with access to methods that take `&mut self` as their receiver.
+pub fn eval_method_mut(
+    object: &mut dyn Any,
+    method_name: &str,
+    args: &str,
+    f: &mut dyn FnMut(Result<'_, &dyn Output>),
+) -> bool {
+    fragments((*object).type_id())
+        .any(|fragment| fragment.eval_method_mut(object, method_name, args, f))
+}
+
+/// Calls the method with the first fragment registered for the object's type that contains it.
+///
+/// Returns `false` if no such fragment was found.
+pub fn call_typed_method(
+    object: &dyn Any,
+    method_name: &str,
+    args: &mut TypedArgs<'_>,
+    f: &mut dyn FnMut(Result<'_, &dyn Output>),
+) -> bool {
+    fragments(object.type_id())
+        .any(|fragment| fragment.call_typed_method(object, method_name, args, f))
+}
+
+/// Same as [`call_typed_method`] but with access to methods that take `&mut self` as their receiver.
+pub fn call_typed_method_mut(
+    object: &mut dyn Any,
+    method_name: &str,
+    args: &mut TypedArgs<'_>,
+    f: &mut dyn FnMut(Result<'_, &dyn Output>),
+) -> bool {
+    fragments((*object).type_id())
+        .any(|fragment| fragment.call_typed_method_mut(object, method_name, args, f))
+}
+
+/// Evaluates the property with the first fragment registered for the object's type that contains it.
+///
+/// Returns `false` if no such fragment was found.
+pub fn eval_property(
+    object: &dyn Any,
+    property_name: &str,
+    f: &mut dyn FnMut(Result<'_, &dyn Output>),
+) -> bool {
+    fragments(object.type_id()).any(|fragment| fragment.eval_property(object, property_name, f))
+}
+
+/// Sets the property with the first fragment registered for the object's type that contains it.
+///
+/// Returns `false` if no such fragment was found.
+pub fn set_property(
+    object: &mut dyn Any,
+    property_name: &str,
+    value: &str,
+    f: &mut dyn FnMut(Result<'_, &dyn Output>),
+) -> bool {
+    fragments((*object).type_id())
+        .any(|fragment| fragment.set_property(object, property_name, value, f))
+}
+
+/// Collects the method names of all fragments registered for the given type.
+pub fn get_all_method_names(type_id: TypeId) -> Vec<&'static str> {
+    fragments(type_id)
+        .flat_map(|fragment| fragment.get_all_method_names())
+        .copied()
+        .collect()
+}
+
+/// Collects the method signatures of all fragments registered for the given type.
+pub fn get_all_method_signatures(type_id: TypeId) -> Vec<Signature> {
+    fragments(type_id)
+        .flat_map(|fragment| fragment.get_all_method_signatures())
+        .copied()
+        .collect()
+}
+
+/// Collects the skipped methods of all fragments registered for the given type.
+pub fn get_all_skipped_methods(type_id: TypeId) -> Vec<SkippedMethod> {
+    fragments(type_id)
+        .flat_map(|fragment| fragment.get_all_skipped_methods())
+        .copied()
+        .collect()
+}
+
+/// Collects the property names of all fragments registered for the given type.
+pub fn get_all_property_names(type_id: TypeId) -> Vec<&'static str> {
+    fragments(type_id)
+        .flat_map(|fragment| fragment.get_all_property_names())
+        .copied()
+        .collect()
+}
+
+/// Collects the names of all properties with a setter of all fragments registered for the given type.
+pub fn get_all_writable_property_names(type_id: TypeId) -> Vec<&'static str> {
+    fragments(type_id)
+        .flat_map(|fragment| fragment.get_all_writable_property_names())
+        .copied()
+        .collect()
+}
+
+/// Returns the getter signature of the property from the first fragment registered for the given type that contains it.
+pub fn get_property_signature(type_id: TypeId, property_name: &str) -> Option<&'static Signature> {
+    fragments(type_id).find_map(|fragment| fragment.get_property_signature(property_name))
+}
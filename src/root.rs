@@ -1,10 +1,17 @@
-use core::fmt::Debug;
+use core::any::{type_name, Any};
+use core::cell::{Cell, RefCell};
+use core::fmt::{Debug, Display};
 
-use crate::{Interactive, InteractiveError, Result};
+use crate::format::unwrap_method_result;
+use crate::output::write_value;
+use crate::panic::PanicHandler;
+use crate::property::is_writable_property;
+use crate::{Interactive, InteractiveError, Output, OutputOptions, Result, TypedArgs};
 
-enum AccessType<'a> {
+pub(crate) enum AccessType<'a> {
     FieldAccess(&'a str),
     MethodAccess(&'a str, &'a str),
+    PropertyAssignment(&'a str, &'a str),
 }
 
 /// The main entry point to everything interactive.
@@ -21,6 +28,7 @@ enum AccessType<'a> {
 /// * `field_of_root`
 /// * `field_of_root.child_field`
 /// * `field_of_root.child_method()`
+/// * `field_of_root.*.child_field` or `**.child_field`, see [`try_eval_matches`](InteractiveRoot::try_eval_matches)
 /// * etc.
 ///
 /// Functions can be called with arguments just as you would in Rust:
@@ -42,31 +50,551 @@ enum AccessType<'a> {
 /// Generic argument types are not supported.
 ///
 /// Both `String` and `str` are only available with default features on.
+///
+/// # Built-in queries
+///
+/// * `help` lists the fields, methods and functions of the root
+/// * `help(path.to.obj)` lists the fields and methods of an object
+/// * `help(path.to.obj.method)` shows the signature and documentation of a method or field
+/// * `type_of(path.to.obj.field)` shows the type of a field or the signature of a method
+/// * `tree`, `tree(depth)`, `tree(path.to.obj)` or `tree(path.to.obj, depth)` shows all fields
+///   below an object, see [`dump`](InteractiveRoot::dump)
+///
+/// A field, property, method or function of the root with the same name takes precedence, even a hidden one:
+/// `help` and `tree` are only built-in if the root has no such field or property,
+/// `help(..)`, `type_of(..)` and `tree(..)` if it has no such method or function.
+///
+/// ```
+/// # use rusteval::{Interactive, InteractiveRoot, Methods};
+/// #
+/// #[derive(Interactive, Debug, Default)]
+/// struct Child {
+///     /// The current temperature in °C.
+///     temperature: f32,
+/// }
+///
+/// #[Methods]
+/// impl Child {
+///     /// Adds two numbers.
+///     fn add(&self, a: f32, b: f32) -> f32 {
+///         a + b
+///     }
+/// }
+///
+/// #[derive(InteractiveRoot, Debug, Default)]
+/// struct Root {
+///     child: Child,
+/// }
+///
+/// let mut root = Root::default();
+/// assert_eq!(
+///     root.eval_to_string("help(child)"),
+///     "child: Child\n\nFields:\n  temperature: f32 - The current temperature in °C.\n\nMethods:\n  add(a: f32, b: f32) -> f32 - Adds two numbers."
+/// );
+/// assert_eq!(root.eval_to_string("help(child.add)"), "add(a: f32, b: f32) -> f32\n\nAdds two numbers.");
+/// assert_eq!(root.eval_to_string("type_of(child.temperature)"), "f32");
+/// ```
 pub trait InteractiveRoot: Interactive + Sized {
     #[cfg(feature = "std")]
     /// Evaluates the query and returns the result as a String.
     /// Not available in no_std contexts.
     fn eval_to_string(&mut self, query: &str) -> String {
+        self.eval_to_string_with(query, &OutputOptions::default())
+    }
+
+    #[cfg(feature = "std")]
+    /// Same as [`eval_to_string`](#method.eval_to_string) but the result is written according to the given [`OutputOptions`].
+    /// Not available in no_std contexts.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rusteval::{Interactive, InteractiveRoot, OutputOptions, Radix};
+    /// #
+    /// #[derive(Interactive, Debug, Default)]
+    /// struct Child {
+    ///     status: u32,
+    /// }
+    ///
+    /// #[derive(InteractiveRoot, Debug, Default)]
+    /// struct Root {
+    ///     child: Child,
+    /// }
+    ///
+    /// let mut root = Root { child: Child { status: 164 } };
+    /// let hex = OutputOptions { radix: Some(Radix::Hex), ..OutputOptions::default() };
+    /// assert_eq!(root.eval_to_string_with("child", &hex), "Child { status: 0xA4 }");
+    ///
+    /// let pretty = OutputOptions { pretty: true, ..OutputOptions::default() };
+    /// assert_eq!(root.eval_to_string_with("child", &pretty), "Child {\n    status: 164,\n}");
+    /// ```
+    fn eval_to_string_with(&mut self, query: &str, options: &OutputOptions) -> String {
         let mut s = String::new();
-        self.try_eval_mut(query, |result| {
-            s = match result {
-                Ok(r) => format!("{:?}", r),
-                Err(e) => format!("{}", e),
+        // writing to a String never fails
+        let _ = self.eval_and_write_with(query, &mut s, options);
+        s
+    }
+
+    #[cfg(feature = "std")]
+    /// Evaluates the query and returns the result as an owned [`Value`](crate::Value).
+    /// On error an [`OwnedInteractiveError`](crate::OwnedInteractiveError) is returned instead.
+    /// Not available in no_std contexts.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rusteval::{Interactive, InteractiveRoot, Value};
+    /// #
+    /// #[derive(Interactive, Debug, Default)]
+    /// struct Child {
+    ///     count: u32,
+    ///     name: String,
+    /// }
+    ///
+    /// #[derive(InteractiveRoot, Debug, Default)]
+    /// struct Root {
+    ///     child: Child,
+    /// }
+    ///
+    /// let mut root = Root::default();
+    /// root.child.count = 3;
+    /// assert_eq!(root.try_eval_value("child.count"), Ok(Value::UInt(3)));
+    ///
+    /// let child = root.try_eval_value("child").unwrap();
+    /// assert_eq!(child.field("name"), Some(&Value::String(String::new())));
+    /// assert!(root.try_eval_value("nothing").is_err());
+    /// ```
+    fn try_eval_value(
+        &mut self,
+        query: &str,
+    ) -> core::result::Result<crate::Value, crate::OwnedInteractiveError> {
+        let mut value = None;
+        self.try_eval_mut_output(query, |result| {
+            value = Some(result.map(|r| r.to_value()).map_err(Into::into))
+        });
+        with_context(self, query, parse_object_path(query).0, value)
+    }
+
+    #[cfg(feature = "std")]
+    /// Evaluates the query and returns a clone of the result if it is of type `T`.
+    /// On error an [`OwnedInteractiveError`](crate::OwnedInteractiveError) is returned instead.
+    /// Not available in no_std contexts.
+    ///
+    /// See [`try_eval_as`](#method.try_eval_as) for which results can be downcast.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rusteval::{Interactive, InteractiveRoot, Methods};
+    /// #
+    /// #[derive(Interactive, Debug, Default)]
+    /// struct Child;
+    ///
+    /// #[Methods]
+    /// impl Child {
+    ///     fn add(&self, a: f32, b: f32) -> f32 {
+    ///         a + b
+    ///     }
+    /// }
+    ///
+    /// #[derive(InteractiveRoot, Debug, Default)]
+    /// struct Root {
+    ///     child: Child,
+    /// }
+    ///
+    /// let mut root = Root::default();
+    /// assert_eq!(root.eval_as::<f32>("child.add(1, 2)"), Ok(3.0));
+    /// assert_eq!(
+    ///     root.eval_as::<u32>("child.add(1, 2)").unwrap_err().to_string(),
+    ///     "Expected type `u32`, found `f32`"
+    /// );
+    /// ```
+    fn eval_as<T>(&mut self, query: &str) -> core::result::Result<T, crate::OwnedInteractiveError>
+    where
+        T: Any + Clone,
+    {
+        let mut value = None;
+        self.try_eval_as::<T, _>(query, |result| {
+            value = Some(result.cloned().map_err(Into::into))
+        });
+        with_context(self, query, parse_object_path(query).0, value)
+    }
+
+    #[cfg(feature = "std")]
+    /// Evaluates a query with wildcards and returns the path and owned [`Value`](crate::Value)
+    /// of every match, see [`try_eval_matches`](#method.try_eval_matches).
+    /// On the first error an [`OwnedInteractiveError`](crate::OwnedInteractiveError) is returned instead.
+    /// Not available in no_std contexts.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rusteval::{Interactive, InteractiveRoot, Value};
+    /// #
+    /// #[derive(Interactive, Debug, Default)]
+    /// struct Board {
+    ///     error_count: u32,
+    ///     name: String,
+    /// }
+    ///
+    /// #[derive(InteractiveRoot, Debug, Default)]
+    /// struct Root {
+    ///     left: Board,
+    ///     right: Board,
+    /// }
+    ///
+    /// let mut root = Root::default();
+    /// root.right.error_count = 2;
+    /// assert_eq!(
+    ///     root.eval_matches("**.error_count"),
+    ///     Ok(vec![
+    ///         ("left.error_count".to_string(), Value::UInt(0)),
+    ///         ("right.error_count".to_string(), Value::UInt(2)),
+    ///     ])
+    /// );
+    /// assert_eq!(root.eval_matches("left.*").unwrap().len(), 2);
+    /// ```
+    fn eval_matches(
+        &self,
+        query: &str,
+    ) -> core::result::Result<Vec<(String, crate::Value)>, crate::OwnedInteractiveError> {
+        let mut matches = Vec::new();
+        let mut error = None;
+        self.try_eval_matches(query, |path, result| {
+            if error.is_some() {
+                return;
+            }
+            let path = path.to_string();
+            match result {
+                Ok(value) => matches.push((path, value.to_value())),
+                Err(e) => error = Some((path, crate::OwnedInteractiveError::from(e))),
             }
         });
+        match error {
+            // the types are walked along the path of the match that failed
+            Some((path, e)) => with_context(self, query, parse_object_path(&path).0, Some(Err(e))),
+            None => Ok(matches),
+        }
+    }
+
+    #[cfg(feature = "std")]
+    /// Calls the method of the object at the given path with already typed arguments
+    /// and returns a clone of the result if it is of type `R`.
+    /// On error an [`OwnedInteractiveError`](crate::OwnedInteractiveError) is returned instead,
+    /// its query is `object_path.method_name`.
+    /// Not available in no_std contexts.
+    ///
+    /// See [`try_call_method`](#method.try_call_method) for how the arguments are passed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rusteval::{Interactive, InteractiveRoot, Methods};
+    /// #
+    /// #[derive(Interactive, Debug, Default)]
+    /// struct Child;
+    ///
+    /// #[Methods]
+    /// impl Child {
+    ///     fn add(&self, a: f32, b: f32) -> f32 {
+    ///         a + b
+    ///     }
+    /// }
+    ///
+    /// #[derive(Interactive, Debug, Default)]
+    /// struct Parent {
+    ///     child: Child,
+    /// }
+    ///
+    /// #[derive(InteractiveRoot, Debug, Default)]
+    /// struct Root {
+    ///     parent: Parent,
+    /// }
+    ///
+    /// let mut root = Root::default();
+    /// assert_eq!(root.call_method::<f32>("parent.child", "add", (1.0f32, 2.0f32)), Ok(3.0));
+    /// assert_eq!(
+    ///     root.call_method::<f32>("parent.child", "add", (1.0f64, 2.0f64)).unwrap_err().to_string(),
+    ///     "`add` takes arguments of type `(f32, f32)`, but `(f64, f64)` was supplied"
+    /// );
+    /// ```
+    fn call_method<R>(
+        &mut self,
+        object_path: &str,
+        method_name: &str,
+        args: impl Any,
+    ) -> core::result::Result<R, crate::OwnedInteractiveError>
+    where
+        R: Any + Clone,
+    {
+        let mut value = None;
+        self.try_call_method(object_path, method_name, args, |result| {
+            value = Some(downcast_result::<R>(result).cloned().map_err(Into::into))
+        });
+        let query = match object_path {
+            "" => method_name.to_string(),
+            _ => format!("{}.{}", object_path, method_name),
+        };
+        with_context(self, &query, object_path, value)
+    }
+
+    #[cfg(feature = "std")]
+    /// Calls the free function with already typed arguments
+    /// and returns a clone of the result if it is of type `R`.
+    /// On error an [`OwnedInteractiveError`](crate::OwnedInteractiveError) is returned instead.
+    /// Not available in no_std contexts.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rusteval::{Function, InteractiveRoot};
+    /// #
+    /// #[Function]
+    /// fn add_one(a: u32) -> u32 {
+    ///     a + 1
+    /// }
+    ///
+    /// #[derive(InteractiveRoot)]
+    /// struct Root;
+    ///
+    /// assert_eq!(Root.call_function("add_one", (41u32,)), Ok(42u32));
+    /// ```
+    fn call_function<R>(
+        &mut self,
+        function_name: &str,
+        args: impl Any,
+    ) -> core::result::Result<R, crate::OwnedInteractiveError>
+    where
+        R: Any + Clone,
+    {
+        self.call_method("", function_name, args)
+    }
+
+    #[cfg(feature = "std")]
+    /// Describes all interactive types reachable from the root and all free functions,
+    /// see [`Schema`](crate::Schema).
+    /// With the `serde` feature the schema can be serialized, e.g. as JSON.
+    /// Not available in no_std contexts.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rusteval::{Interactive, InteractiveRoot, Methods, Schema};
+    /// #
+    /// #[derive(Interactive, Debug, Default)]
+    /// struct Child {
+    ///     /// The last measured value.
+    ///     value: u8,
+    /// }
+    ///
+    /// #[Methods]
+    /// impl Child {
+    ///     fn reset(&mut self) {
+    ///         self.value = 0;
+    ///     }
+    /// }
+    ///
+    /// #[derive(InteractiveRoot, Debug, Default)]
+    /// struct Root {
+    ///     first: Child,
+    ///     second: Child,
+    /// }
+    ///
+    /// let schema = Root::default().schema();
+    /// assert_eq!(schema.version, Schema::VERSION);
+    /// assert_eq!(schema.root, schema.types[0].path);
+    /// // `Child` is only described once
+    /// assert_eq!(schema.types.len(), 2);
+    ///
+    /// let child = schema.get_type("Child").unwrap();
+    /// assert_eq!(child.fields[0].name, "value");
+    /// assert_eq!(child.fields[0].docs, "The last measured value.");
+    /// assert_eq!(child.methods[0].to_string(), "reset()");
+    /// ```
+    fn schema(&self) -> crate::Schema {
+        crate::Schema::new(self)
+    }
+
+    #[cfg(feature = "std")]
+    /// Returns a tree of all fields of the root and their fields, up to `max_depth` levels deep.
+    /// Not available in no_std contexts, use the `tree` query instead.
+    ///
+    /// Interactive fields are shown with their type name, all others with their `Debug` value.
+    /// Objects that are deeper than `max_depth` end with `...`,
+    /// objects that contain themselves, e.g. through an `Rc`, with `(cycle)`.
+    /// Skipped and hidden fields are left out.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rusteval::{Interactive, InteractiveRoot};
+    /// #
+    /// #[derive(Interactive, Debug, Default)]
+    /// struct Inner {
+    ///     flag: bool,
+    /// }
+    ///
+    /// #[derive(Interactive, Debug, Default)]
+    /// struct Child {
+    ///     value: u8,
+    ///     inner: Inner,
+    /// }
+    ///
+    /// #[derive(InteractiveRoot, Debug, Default)]
+    /// struct Root {
+    ///     child: Child,
+    /// }
+    ///
+    /// let mut root = Root::default();
+    /// assert_eq!(
+    ///     root.dump(usize::MAX),
+    ///     "Root\n  child: Child\n    child.value = 0\n    child.inner: Inner\n      child.inner.flag = false"
+    /// );
+    /// assert_eq!(root.dump(1), "Root\n  child: Child ...");
+    /// assert_eq!(
+    ///     root.eval_to_string("tree(child.inner)"),
+    ///     "child.inner: Inner\n  child.inner.flag = false"
+    /// );
+    /// ```
+    fn dump(&self, max_depth: usize) -> String {
+        let mut s = String::new();
+        // writing to a String never fails
+        let _ = crate::tree::write_tree(&mut s, self, "", max_depth);
         s
     }
 
+    #[cfg(feature = "std")]
+    /// Renders a Markdown reference of every query the root supports.
+    /// Not available in no_std contexts.
+    ///
+    /// There is a section for each object path with a table of its fields and a list of its methods,
+    /// followed by all free functions grouped by module.
+    /// Unlike the [`schema`](InteractiveRoot::schema), objects of the same type
+    /// are listed once per path they can be reached under.
+    ///
+    /// Fields are read-only if they or one of the fields on their path are.
+    ///
+    /// The reference can be written to a file from a build script or a test.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rusteval::{Interactive, InteractiveRoot, Methods};
+    /// #
+    /// #[derive(Interactive, Debug, Default)]
+    /// struct Led {
+    ///     /// Brightness in percent.
+    ///     brightness: u8,
+    /// }
+    ///
+    /// #[Methods]
+    /// impl Led {
+    ///     /// Turns the led off.
+    ///     fn off(&mut self) {
+    ///         self.brightness = 0;
+    ///     }
+    /// }
+    ///
+    /// #[derive(InteractiveRoot, Debug, Default)]
+    /// struct Board {
+    ///     #[interactive(readonly)]
+    ///     status: Led,
+    /// }
+    ///
+    /// let reference = Board::default().command_reference();
+    /// assert!(reference.contains("| `status.brightness` | `u8` | read-only | Brightness in percent. |"));
+    /// assert!(reference.contains("- `status.off()`: Turns the led off."));
+    /// std::fs::write(std::env::temp_dir().join("commands.md"), reference).unwrap();
+    /// ```
+    fn command_reference(&self) -> String {
+        crate::reference::command_reference(self)
+    }
+
+    #[cfg(feature = "serde")]
+    /// Evaluates the query and returns the result as JSON.
+    /// Only available with the `serde` feature.
+    ///
+    /// Results that implement `Serialize` are converted directly,
+    /// all others are shown as their `Debug` representation: `{"debug": "..."}`.
+    /// Errors are returned as `{"error": "...", "code": "E0005", "kind": "field_not_found"}`,
+    /// see [`ErrorKind::code`](crate::ErrorKind::code).
+    /// Errors while parsing an argument also have a `"cause"` with the code and kind of the
+    /// [`ArgParseError`](crate::ArgParseError).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rusteval::{Interactive, InteractiveRoot};
+    /// # use serde::Serialize;
+    /// # use serde_json::json;
+    /// #
+    /// #[derive(Interactive, Serialize, Debug, Default)]
+    /// struct Child {
+    ///     values: Vec<u8>,
+    /// }
+    ///
+    /// #[derive(InteractiveRoot, Debug, Default)]
+    /// struct Root {
+    ///     child: Child,
+    /// }
+    ///
+    /// let mut root = Root::default();
+    /// root.child.values = vec![1, 2];
+    /// assert_eq!(root.eval_to_json("child"), json!({ "values": [1, 2] }));
+    /// assert_eq!(root.eval_to_json("child.values"), json!([1, 2]));
+    /// assert_eq!(
+    ///     root.eval_to_json("nothing"),
+    ///     json!({
+    ///         "error": "No field `nothing` found for type `Root`",
+    ///         "code": "E0005",
+    ///         "kind": "field_not_found",
+    ///     })
+    /// );
+    /// ```
+    fn eval_to_json(&mut self, query: &str) -> serde_json::Value {
+        let mut json = serde_json::Value::Null;
+        self.try_eval_mut_output(query, |result| {
+            json = match result {
+                Ok(r) => r.to_json(),
+                Err(e) => error_to_json(&e),
+            }
+        });
+        json
+    }
+
+    #[cfg(feature = "serde")]
+    /// Same as [`eval_to_json`](#method.eval_to_json) but the JSON is written into the provided writer.
+    /// Only available with the `serde` feature.
+    fn eval_and_write_json<W>(&mut self, query: &str, writer: W) -> serde_json::Result<()>
+    where
+        W: std::io::Write,
+    {
+        serde_json::to_writer(writer, &self.eval_to_json(query))
+    }
+
     /// Evaluates the query and writes the result into the provided buffer.
     /// Useful in no_std contexts.
     fn eval_and_write<T>(&mut self, query: &str, buf: &mut T) -> core::fmt::Result
+    where
+        T: core::fmt::Write,
+    {
+        self.eval_and_write_with(query, buf, &OutputOptions::default())
+    }
+
+    /// Same as [`eval_and_write`](#method.eval_and_write) but the result is written according to the given [`OutputOptions`].
+    fn eval_and_write_with<T>(
+        &mut self,
+        query: &str,
+        buf: &mut T,
+        options: &OutputOptions,
+    ) -> core::fmt::Result
     where
         T: core::fmt::Write,
     {
         let mut r = Ok(());
-        self.try_eval_mut(query, |result| {
+        eval_mut_output(self, query, options.keep_results, &mut |result| {
             r = match result {
-                Ok(r) => write!(buf, "{:?}", r),
+                Ok(r) => write_value(buf, r, options),
                 Err(e) => write!(buf, "{}", e),
             }
         });
@@ -114,24 +642,30 @@ pub trait InteractiveRoot: Interactive + Sized {
     where
         F: FnMut(Result<'_, &dyn Debug>),
     {
-        match self.get_queried_object(query) {
-            Ok((object, rest_expression)) => {
-                let access_type = parse_access_type(rest_expression);
-                match access_type {
-                    Ok(AccessType::FieldAccess(field_name)) => {
-                        object.eval_field(field_name, &mut f)
-                    }
-                    Ok(AccessType::MethodAccess(method_name, args)) => {
-                        match object.try_as_methods() {
-                            Ok(obj) => obj.eval_method(method_name, args, &mut f),
-                            Err(e) => f(Err(e)),
-                        }
-                    }
-                    Err(e) => f(Err(e)),
-                }
-            }
+        self.try_eval_output(query, |result| match result {
+            Ok(value) => f(Ok(value)),
             Err(e) => f(Err(e)),
-        }
+        })
+    }
+
+    /// Same as [`try_eval`](#method.try_eval) but calls the closure with a [`Result`]`<&dyn `[`Output`]`>`,
+    /// which can also be shown with `Display`, converted to JSON or downcast.
+    fn try_eval_output<F>(&self, query: &str, f: F)
+    where
+        F: FnMut(Result<'_, &dyn Output>),
+    {
+        let f = RefCell::new(f);
+        catch_panics(
+            self.panic_handler(),
+            &mut |callback| {
+                eval_ref(self, query, |result| {
+                    unwrap_method_result(result, &mut |result| {
+                        callback.call(|| (f.borrow_mut())(result))
+                    })
+                })
+            },
+            &mut |message| (f.borrow_mut())(Err(InteractiveError::Panicked { query, message })),
+        );
     }
 
     /// Evaluates the given query and calls the given closure with a [`Result`]`<&dyn `[`Debug`]`>`.
@@ -170,23 +704,189 @@ pub trait InteractiveRoot: Interactive + Sized {
     where
         F: FnMut(Result<'_, &dyn Debug>),
     {
-        match self.get_queried_object_mut(query) {
-            Ok((object, rest_expression)) => {
-                let access_type = parse_access_type(rest_expression);
-                match access_type {
-                    Ok(AccessType::FieldAccess(field_name)) => {
-                        object.eval_field(field_name, &mut f)
-                    }
-                    Ok(AccessType::MethodAccess(method_name, args)) => {
-                        match object.try_as_methods_mut() {
-                            Ok(obj) => obj.eval_method_mut(method_name, args, &mut f),
-                            Err(e) => f(Err(e)),
-                        }
-                    }
+        self.try_eval_mut_output(query, |result| match result {
+            Ok(value) => f(Ok(value)),
+            Err(e) => f(Err(e)),
+        })
+    }
+
+    /// Same as [`try_eval_mut`](#method.try_eval_mut) but calls the closure with a [`Result`]`<&dyn `[`Output`]`>`,
+    /// which can also be shown with `Display`, converted to JSON or downcast.
+    fn try_eval_mut_output<F>(&mut self, query: &str, mut f: F)
+    where
+        F: FnMut(Result<'_, &dyn Output>),
+    {
+        eval_mut_output(self, query, false, &mut f);
+    }
+
+    /// Returns the [`PanicHandler`] queries are evaluated with.
+    ///
+    /// Set it with `#[interactive(catch_panics)]` or `#[interactive(panic_handler = "...")]` on the root,
+    /// see [`InteractiveRoot`](macro@crate::InteractiveRoot).
+    /// By default panics are not caught.
+    fn panic_handler(&self) -> Option<PanicHandler> {
+        None
+    }
+
+    /// Same as [`try_eval_mut`](#method.try_eval_mut) but the result is downcast to a `&T`.
+    ///
+    /// If the result is not of type `T` an [`InteractiveError::TypeMismatch`] is passed to the closure instead.
+    /// Only results that implement [`StaticType`](crate::StaticType) can be downcast.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rusteval::{Interactive, InteractiveError, InteractiveRoot};
+    /// #
+    /// #[derive(Interactive, Debug, Default)]
+    /// struct Child {
+    ///     values: [u8; 2],
+    /// }
+    ///
+    /// #[derive(InteractiveRoot, Debug, Default)]
+    /// struct Root {
+    ///     child: Child,
+    /// }
+    ///
+    /// let mut root = Root::default();
+    /// root.try_eval_as::<[u8; 2], _>("child.values", |result| assert_eq!(result, Ok(&[0, 0])));
+    /// root.try_eval_as::<u8, _>("child.values", |result| {
+    ///     assert_eq!(result, Err(InteractiveError::TypeMismatch { expected: "u8", found: "[u8; 2]" }))
+    /// });
+    /// ```
+    fn try_eval_as<T, F>(&mut self, query: &str, mut f: F)
+    where
+        T: Any,
+        F: FnMut(Result<'_, &T>),
+    {
+        self.try_eval_mut_output(query, |result| f(downcast_result(result)));
+    }
+
+    /// Evaluates a query with wildcards and calls the closure with the path and result of every match.
+    ///
+    /// A `*` segment matches every interactive field of an object,
+    /// a `**` segment matches an object and all interactive objects below it.
+    /// As the last segment they match all fields instead, e.g. `child.*` or `child.**`.
+    /// Queries without wildcards have at most one match.
+    ///
+    /// Objects that don't have the queried field or method are no match.
+    /// Errors for objects before the first wildcard, or for the query as a whole,
+    /// are passed with the query as the path.
+    /// Matches are only evaluated immutably, a method that takes `&mut self`
+    /// is reported as [`InteractiveError::MutableAccessRequired`] for every match.
+    ///
+    /// Queries with wildcards can also be evaluated with [`try_eval`](#method.try_eval) and the like,
+    /// which produce one line per match: `path = value`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rusteval::{Interactive, InteractiveRoot};
+    /// #
+    /// #[derive(Interactive, Debug, Default)]
+    /// struct Sensor {
+    ///     temperature: f32,
+    /// }
+    ///
+    /// #[derive(Interactive, Debug, Default)]
+    /// struct Sensors {
+    ///     inside: Sensor,
+    ///     outside: Sensor,
+    /// }
+    ///
+    /// #[derive(InteractiveRoot, Debug, Default)]
+    /// struct Root {
+    ///     sensors: Sensors,
+    /// }
+    ///
+    /// let mut root = Root::default();
+    /// root.sensors.outside.temperature = 12.5;
+    ///
+    /// let mut matches = Vec::new();
+    /// root.try_eval_matches("sensors.*.temperature", |path, result| {
+    ///     matches.push(format!("{} = {:?}", path, result.unwrap()))
+    /// });
+    /// assert_eq!(matches, ["sensors.inside.temperature = 0.0", "sensors.outside.temperature = 12.5"]);
+    ///
+    /// assert_eq!(
+    ///     root.eval_to_string("**.temperature"),
+    ///     "sensors.inside.temperature = 0.0\nsensors.outside.temperature = 12.5"
+    /// );
+    /// ```
+    fn try_eval_matches<F>(&self, query: &str, f: F)
+    where
+        F: FnMut(&dyn Display, Result<'_, &dyn Output>),
+    {
+        let f = RefCell::new(f);
+        catch_panics(
+            self.panic_handler(),
+            &mut |callback| {
+                crate::glob::eval_matches(self, query, &mut |path, result| {
+                    callback.call(|| (f.borrow_mut())(path, result))
+                })
+            },
+            &mut |message| {
+                (f.borrow_mut())(&query, Err(InteractiveError::Panicked { query, message }))
+            },
+        );
+    }
+
+    /// Calls the method of the object at the given path and passes the result to the given closure.
+    ///
+    /// Unlike [`try_eval_mut`](#method.try_eval_mut) the arguments are not parsed from a string,
+    /// they are passed as they are, as a tuple with one element per parameter of the method.
+    /// See [`TypedArgs`] for which types the parameters expect.
+    /// If they don't match an [`InteractiveError::WrongArgumentTypes`] is passed to the closure.
+    ///
+    /// An empty object path calls a method of the root itself or a free [`Function`](macro@crate::Function).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rusteval::{Interactive, InteractiveRoot, Methods};
+    /// #
+    /// #[derive(Interactive, Debug, Default)]
+    /// struct Child {
+    ///     name: String,
+    /// }
+    ///
+    /// #[Methods]
+    /// impl Child {
+    ///     fn rename(&mut self, name: &str, suffix: &mut u8) {
+    ///         self.name = format!("{}{}", name, suffix);
+    ///     }
+    /// }
+    ///
+    /// #[derive(InteractiveRoot, Debug, Default)]
+    /// struct Root {
+    ///     child: Child,
+    /// }
+    ///
+    /// let mut root = Root::default();
+    /// root.try_call_method("child", "rename", ("a".to_string(), 1u8), |result| assert!(result.is_ok()));
+    /// assert_eq!(root.child.name, "a1");
+    /// ```
+    fn try_call_method<F>(&mut self, object_path: &str, method_name: &str, args: impl Any, mut f: F)
+    where
+        F: FnMut(Result<'_, &dyn Output>),
+    {
+        let mut f = |result: Result<'_, &dyn Output>| unwrap_method_result(result, &mut f);
+        let mut args = Some(args);
+        let mut args = TypedArgs::new(&mut args);
+
+        match find_object_mut(self, object_path) {
+            Ok(object) => match object.try_as_methods_mut() {
+                Ok(obj) => obj.call_typed_method_mut(method_name, &mut args, &mut f),
+                Err(e) => f(Err(e)),
+            },
+            // object might be behind shared reference
+            Err(InteractiveError::FieldNotFound { .. }) => match find_object(self, object_path) {
+                Ok(object) => match object.try_as_methods() {
+                    Ok(obj) => obj.call_typed_method(method_name, &mut args, &mut f),
                     Err(e) => f(Err(e)),
-                }
-            }
-            Err(InteractiveError::FieldNotFound { .. }) => self.try_eval(query, f), // field might be behind shared reference
+                },
+                Err(e) => f(Err(e)),
+            },
             Err(e) => f(Err(e)),
         }
     }
@@ -200,7 +900,6 @@ pub trait InteractiveRoot: Interactive + Sized {
     ///
     /// E.g. `"path.to.obj.foo"` will split into the object path `"path.to.obj"` and the rest expression `"foo"`.
     ///
-
     /// # Example
     ///
     /// ```
@@ -222,20 +921,12 @@ pub trait InteractiveRoot: Interactive + Sized {
     /// assert_eq!(child.get_all_field_names(), &["field1"]);
     /// assert_eq!(rest_expression, "rest");
     /// ```
-    fn get_queried_object<'a>(&'a self, query: &'a str) -> Result<'_, (&dyn Interactive, &str)> {
-        let (mut object_path, rest_expression) = parse_object_path(query);
-
-        let mut current: &dyn Interactive = self;
-
-        while !object_path.is_empty() {
-            let (field_name, object_path_remainder) = object_path
-                .split_once('.')
-                .unwrap_or((object_path.trim(), ""));
-            object_path = object_path_remainder;
-
-            current = current.get_field(field_name.trim())?
-        }
-        Ok((current, rest_expression))
+    fn get_queried_object<'a>(
+        &'a self,
+        query: &'a str,
+    ) -> Result<'a, (&'a dyn Interactive, &'a str)> {
+        let (object_path, rest_expression) = parse_object_path(query);
+        Ok((find_object(self, object_path)?, rest_expression))
     }
 
     /// Same as [`get_queried_object`] but returning a mutable reference.
@@ -244,25 +935,295 @@ pub trait InteractiveRoot: Interactive + Sized {
     fn get_queried_object_mut<'a>(
         &'a mut self,
         query: &'a str,
-    ) -> Result<'_, (&mut dyn Interactive, &str)> {
-        let (mut object_path, rest_expression) = parse_object_path(query);
+    ) -> Result<'a, (&'a mut dyn Interactive, &'a str)> {
+        let (object_path, rest_expression) = parse_object_path(query);
+        Ok((find_object_mut(self, object_path)?, rest_expression))
+    }
+}
 
-        let mut current: &mut dyn Interactive = self;
+/// Evaluates the query immutably, see [`InteractiveRoot::try_eval`].
+fn eval_ref<R, F>(root: &R, query: &str, mut f: F)
+where
+    R: InteractiveRoot,
+    F: FnMut(Result<'_, &dyn Output>),
+{
+    if crate::help::try_root_first(query, &mut f, |f| eval_query(root, query, f)) {
+        crate::help::eval_builtin(root, query, &mut f);
+    }
+}
 
-        while !object_path.is_empty() {
-            let (field_name, object_path_remainder) = object_path
-                .split_once('.')
-                .unwrap_or((object_path.trim(), ""));
-            object_path = object_path_remainder;
+/// Evaluates a query that isn't a built-in one immutably.
+fn eval_query<R: InteractiveRoot>(
+    root: &R,
+    query: &str,
+    f: &mut dyn FnMut(Result<'_, &dyn Output>),
+) {
+    if crate::glob::eval_glob(root, query, f) {
+        return;
+    }
 
-            current = current.get_field_mut(field_name.trim())?
+    match root.get_queried_object(query) {
+        Ok((object, rest_expression)) => {
+            let access_type = parse_access_type(rest_expression);
+            match access_type {
+                Ok(AccessType::FieldAccess(field_name)) => object.eval_field_output(field_name, f),
+                Ok(AccessType::MethodAccess(method_name, args)) => match object.try_as_methods() {
+                    Ok(obj) => obj.eval_method_output(method_name, args, f),
+                    Err(e) => f(Err(e)),
+                },
+                Ok(AccessType::PropertyAssignment(property_name, _)) => {
+                    // setters need mutable access, but unknown properties should still be reported as such
+                    if is_writable_property(object, property_name) {
+                        return f(Err(InteractiveError::MutableAccessRequired {
+                            name: property_name,
+                        }));
+                    }
+                    match object.try_as_methods() {
+                        Ok(obj) => obj.eval_property(property_name, &mut |result| match result {
+                            Ok(_) => {
+                                f(Err(InteractiveError::PropertyNotWritable { property_name }))
+                            }
+                            Err(e) => f(Err(e)),
+                        }),
+                        Err(e) => f(Err(e)),
+                    }
+                }
+                Err(e) => f(Err(e)),
+            }
         }
-        Ok((current, rest_expression))
+        Err(e) => f(Err(e)),
     }
 }
 
-fn parse_access_type(expression: &str) -> Result<'_, AccessType<'_>> {
+/// Evaluates the query mutably and catches panics, see [`InteractiveRoot::try_eval_mut_output`].
+///
+/// With `keep_results` the `Result` returned by a method is passed on as a [`MethodResult`](crate::MethodResult)
+/// instead of being unwrapped.
+fn eval_mut_output<R: InteractiveRoot>(
+    root: &mut R,
+    query: &str,
+    keep_results: bool,
+    f: &mut dyn FnMut(Result<'_, &dyn Output>),
+) {
+    let f = RefCell::new(f);
+    catch_panics(
+        root.panic_handler(),
+        &mut |callback| {
+            eval_mut(root, query, |result| {
+                let mut call =
+                    |result: Result<'_, &dyn Output>| callback.call(|| (f.borrow_mut())(result));
+                if keep_results {
+                    call(result)
+                } else {
+                    unwrap_method_result(result, &mut call)
+                }
+            })
+        },
+        &mut |message| (f.borrow_mut())(Err(InteractiveError::Panicked { query, message })),
+    );
+}
+
+/// Evaluates the query mutably, see [`InteractiveRoot::try_eval_mut`].
+fn eval_mut<R, F>(root: &mut R, query: &str, mut f: F)
+where
+    R: InteractiveRoot,
+    F: FnMut(Result<'_, &dyn Output>),
+{
+    if crate::help::try_root_first(query, &mut f, |f| eval_query_mut(root, query, f)) {
+        crate::help::eval_builtin(root, query, &mut f);
+    }
+}
+
+/// Evaluates a query that isn't a built-in one mutably.
+fn eval_query_mut<R: InteractiveRoot>(
+    root: &mut R,
+    query: &str,
+    f: &mut dyn FnMut(Result<'_, &dyn Output>),
+) {
+    // matches are only evaluated immutably
+    if crate::glob::eval_glob(root, query, f) {
+        return;
+    }
+
+    match root.get_queried_object_mut(query) {
+        Ok((object, rest_expression)) => {
+            let access_type = parse_access_type(rest_expression);
+            match access_type {
+                Ok(AccessType::FieldAccess(field_name)) => object.eval_field_output(field_name, f),
+                Ok(AccessType::MethodAccess(method_name, args)) => {
+                    match object.try_as_methods_mut() {
+                        Ok(obj) => obj.eval_method_mut_output(method_name, args, f),
+                        Err(e) => f(Err(e)),
+                    }
+                }
+                Ok(AccessType::PropertyAssignment(property_name, value)) => {
+                    match object.try_as_methods_mut() {
+                        Ok(obj) => obj.set_property(property_name, value, f),
+                        Err(e) => f(Err(e)),
+                    }
+                }
+                Err(e) => f(Err(e)),
+            }
+        }
+        Err(InteractiveError::FieldNotFound { .. }) => eval_query(root, query, f), // field might be behind shared reference
+        Err(e) => f(Err(e)),
+    }
+}
+
+/// Runs `eval` with the given [`PanicHandler`] and passes the message of a panic to `on_panic`.
+///
+/// `eval` has to call the closure of the caller through [`Callback::call`]:
+/// panics of the closure itself are not caused by the query and are raised again.
+fn catch_panics(
+    panic_handler: Option<PanicHandler>,
+    eval: &mut dyn FnMut(&Callback),
+    on_panic: &mut dyn FnMut(&str),
+) {
+    let panic_handler = match panic_handler {
+        Some(panic_handler) => panic_handler,
+        None => return eval(&Callback::default()),
+    };
+
+    let callback = Callback {
+        catching: true,
+        ..Callback::default()
+    };
+    panic_handler(&mut || eval(&callback), &mut |message| {
+        if callback.in_callback.get() {
+            // with `std` the original payload is resumed below, otherwise only the message is left
+            #[cfg(not(feature = "std"))]
+            panic!("{}", message);
+        } else {
+            on_panic(message)
+        }
+    });
+    #[cfg(feature = "std")]
+    if let Some(payload) = callback.payload.take() {
+        std::panic::resume_unwind(payload);
+    }
+}
+
+/// Keeps track of whether a panic happened in the closure passed to an `eval` method.
+#[derive(Default)]
+struct Callback {
+    catching: bool,
+    in_callback: Cell<bool>,
+    #[cfg(feature = "std")]
+    payload: Cell<Option<Box<dyn Any + Send>>>,
+}
+
+impl Callback {
+    fn call(&self, f: impl FnOnce()) {
+        if !self.catching {
+            return f();
+        }
+        self.in_callback.set(true);
+        #[cfg(feature = "std")]
+        if let Err(payload) = std::panic::catch_unwind(std::panic::AssertUnwindSafe(f)) {
+            // keep the payload to resume it once the panic handler returned,
+            // `resume_unwind` doesn't run the panic hook a second time
+            self.payload.set(Some(payload));
+            std::panic::resume_unwind(Box::new("panic in callback"));
+        }
+        #[cfg(not(feature = "std"))]
+        f();
+        self.in_callback.set(false);
+    }
+}
+
+/// Recursively looks for the object at the given object path, e.g. `"path.to.obj"`.
+pub(crate) fn find_object<'a>(
+    mut current: &'a dyn Interactive,
+    mut object_path: &'a str,
+) -> Result<'a, &'a dyn Interactive> {
+    while !object_path.is_empty() {
+        let (field_name, object_path_remainder) = object_path
+            .split_once('.')
+            .unwrap_or((object_path.trim(), ""));
+        object_path = object_path_remainder;
+
+        current = current.get_field(field_name.trim())?
+    }
+    Ok(current)
+}
+
+/// Same as [`find_object`] but returning a mutable reference.
+fn find_object_mut<'a>(
+    mut current: &'a mut dyn Interactive,
+    mut object_path: &'a str,
+) -> Result<'a, &'a mut dyn Interactive> {
+    while !object_path.is_empty() {
+        let (field_name, object_path_remainder) = object_path
+            .split_once('.')
+            .unwrap_or((object_path.trim(), ""));
+        object_path = object_path_remainder;
+
+        current = current.get_field_mut(field_name.trim())?
+    }
+    Ok(current)
+}
+
+/// Adds the query and the types walked along the object path to an error,
+/// see [`OwnedInteractiveError::type_path`](crate::OwnedInteractiveError::type_path).
+#[cfg(feature = "std")]
+fn with_context<T>(
+    root: &dyn Interactive,
+    query: &str,
+    object_path: &str,
+    value: Option<core::result::Result<T, crate::OwnedInteractiveError>>,
+) -> core::result::Result<T, crate::OwnedInteractiveError> {
+    // the closure is always called exactly once
+    let value = value.expect("query was not evaluated");
+    value.map_err(|e| {
+        let mut type_path = vec![root.get_type_name()];
+        let mut current = root;
+        for field_name in object_path
+            .split('.')
+            .filter(|name| !name.trim().is_empty())
+        {
+            match current.get_field(field_name.trim()) {
+                Ok(field) => current = field,
+                Err(_) => break,
+            }
+            type_path.push(current.get_type_name());
+        }
+        e.with_context(query, type_path)
+    })
+}
+
+/// `{"error": "...", "code": "E0005", "kind": "field_not_found"}`, see [`InteractiveRoot::eval_to_json`].
+#[cfg(feature = "serde")]
+fn error_to_json(error: &InteractiveError<'_>) -> serde_json::Value {
+    let mut json = serde_json::json!({
+        "error": error.to_string(),
+        "code": error.code(),
+        "kind": error.kind().name(),
+    });
+    if let InteractiveError::ArgParseError { error, .. } = error {
+        json["cause"] = serde_json::json!({ "code": error.code(), "kind": error.name() });
+    }
+    json
+}
+
+/// Downcasts a successful result to a `&T`.
+fn downcast_result<'a, T: Any>(result: Result<'a, &'a dyn Output>) -> Result<'a, &'a T> {
+    let r = result?;
+    r.as_any()
+        .and_then(|any| any.downcast_ref::<T>())
+        .ok_or(InteractiveError::TypeMismatch {
+            expected: type_name::<T>(),
+            found: r.type_name(),
+        })
+}
+
+pub(crate) fn parse_access_type(expression: &str) -> Result<'_, AccessType<'_>> {
     let expression = expression.trim();
+    if let Some((property_name, value)) = split_assignment(expression) {
+        return Ok(AccessType::PropertyAssignment(
+            property_name.trim(),
+            value.trim(),
+        ));
+    }
     match expression.strip_suffix(')').map(|s| s.split_once('(')) {
         Some(Some((method_name, args))) => Ok(AccessType::MethodAccess(method_name.trim(), args)),
         Some(None) => Err(InteractiveError::SyntaxError), // closing parenthesis but no opening parenthesis
@@ -270,9 +1231,19 @@ fn parse_access_type(expression: &str) -> Result<'_, AccessType<'_>> {
     }
 }
 
+/// splits `name = value` at the `=`, unless it's part of a method's arguments
+fn split_assignment(expression: &str) -> Option<(&str, &str)> {
+    let equals_index = expression.find('=')?;
+    match expression.find('(') {
+        Some(args_start_index) if args_start_index < equals_index => None,
+        _ => Some((&expression[..equals_index], &expression[equals_index + 1..])),
+    }
+}
+
 /// splits query into object_path and rest_expression
-fn parse_object_path(query: &str) -> (&str, &str) {
-    let args_start_index = query.find('(').unwrap_or(query.len());
+pub(crate) fn parse_object_path(query: &str) -> (&str, &str) {
+    // the args or the assigned value may contain dots
+    let args_start_index = query.find(['(', '=']).unwrap_or(query.len());
     match query[..args_start_index].rfind('.') {
         Some(last_dot_index) => {
             let (object_path, rest_expression) = query.split_at(last_dot_index);
@@ -310,4 +1281,16 @@ mod tests {
     fn test_parse_object_path4() {
         assert_eq!(parse_object_path("foo.frob(1.5)"), ("foo", "frob(1.5)"));
     }
+
+    #[test]
+    fn test_parse_object_path5() {
+        assert_eq!(parse_object_path("foo.bar = 1.5"), ("foo", "bar = 1.5"));
+    }
+
+    #[test]
+    fn test_split_assignment() {
+        assert_eq!(split_assignment("bar = 1.5"), Some(("bar ", " 1.5")));
+        assert_eq!(split_assignment("frob(\"a=b\")"), None);
+        assert_eq!(split_assignment("bar"), None);
+    }
 }
@@ -0,0 +1,230 @@
+use crate::property::is_writable_property;
+use crate::{Function, Interactive, Signature};
+
+/// A description of every interactive type reachable from a root, its fields and methods,
+/// and of all free functions.
+///
+/// Use [`InteractiveRoot::schema`](crate::InteractiveRoot::schema) to get one.
+/// Can be used for host-side completion, documentation or generating clients.
+///
+/// Every type is described only once, even if several fields or a field of itself refer to it.
+/// Types are told apart by their full path, see [`Interactive::get_type_path`],
+/// so types of the same name from different modules are described separately.
+/// Hidden fields and methods are left out.
+///
+/// The types are found by walking the values of the fields,
+/// so a type that is only reachable through an `Option` that is `None`, an empty collection
+/// or a field that can't be accessed at the moment is not described.
+///
+/// With the `serde` feature `Schema` implements `Serialize`:
+/// ```json
+/// {
+///     "version": 1,
+///     "root": "my_crate::Root",
+///     "types": {
+///         "my_crate::Root": { "name": "Root", "fields": [...], "methods": [...] },
+///     },
+///     "functions": [{ "module": "my_crate", "name": "add_one", ... }],
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Schema {
+    /// The version of the schema format, [`Schema::VERSION`].
+    pub version: u32,
+    /// The path of the root's type.
+    pub root: &'static str,
+    /// All types reachable from the root, starting with the root itself.
+    pub types: Vec<TypeSchema>,
+    /// All free functions.
+    pub functions: Vec<FunctionSchema>,
+}
+
+impl Schema {
+    /// The current version of the schema format.
+    /// Increases whenever information is removed or changes its meaning.
+    pub const VERSION: u32 = 1;
+
+    /// Walks all fields of the root and describes every interactive type it finds.
+    pub(crate) fn new(root: &dyn Interactive) -> Self {
+        let mut types = Vec::new();
+        describe_type(root, &mut types);
+
+        let functions = inventory::iter::<&dyn Function>
+            .into_iter()
+            .map(|function| FunctionSchema {
+                module_path: function.module_path(),
+                signature: function.signature(),
+            })
+            .collect();
+
+        Schema {
+            version: Self::VERSION,
+            root: root.get_type_path(),
+            types,
+            functions,
+        }
+    }
+
+    /// Returns the description of the type with the given path,
+    /// or of the first type with the given name if no path matches.
+    pub fn get_type(&self, name: &str) -> Option<&TypeSchema> {
+        self.types
+            .iter()
+            .find(|schema| schema.path == name)
+            .or_else(|| self.types.iter().find(|schema| schema.name == name))
+    }
+}
+
+/// The fields and methods of an interactive type, see [`Schema`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeSchema {
+    /// The name of the type, see [`Interactive::get_type_name`].
+    pub name: &'static str,
+    /// The full path of the type, see [`Interactive::get_type_path`].
+    pub path: &'static str,
+    /// All fields listed by [`Interactive::get_all_field_names`].
+    pub fields: Vec<FieldSchema>,
+    /// All methods, without the free functions of a root.
+    pub methods: Vec<Signature>,
+}
+
+/// A field of an interactive type, see [`Schema`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FieldSchema {
+    /// The name the field is accessed with in a query.
+    pub name: &'static str,
+    /// The type of the field as written in the source code, `None` for properties.
+    pub type_name: Option<&'static str>,
+    /// Whether the field can be accessed mutably, or for properties whether they have a setter.
+    pub mutable: bool,
+    /// The path of the [`TypeSchema`] describing the field, if it is interactive itself.
+    pub schema: Option<&'static str>,
+    /// The doc comment, without the leading `///`.
+    pub docs: &'static str,
+}
+
+/// A free function, see [`Schema`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FunctionSchema {
+    /// The path of the module the function is defined in.
+    pub module_path: &'static str,
+    /// The signature of the function.
+    pub signature: Signature,
+}
+
+/// Adds a description of the object's type and all types reachable from it,
+/// unless the type was already described.
+fn describe_type(object: &dyn Interactive, types: &mut Vec<TypeSchema>) {
+    let path = object.get_type_path();
+    // types that refer to themselves or are used more than once are only described once
+    if types.iter().any(|schema| schema.path == path) {
+        return;
+    }
+
+    let methods = match object.try_as_methods() {
+        Ok(methods) => methods
+            .get_all_method_signatures()
+            .iter()
+            .filter(|signature| signature.receiver.is_some())
+            .copied()
+            .collect(),
+        Err(_) => Vec::new(),
+    };
+    let index = types.len();
+    types.push(TypeSchema {
+        name: object.get_type_name(),
+        path,
+        fields: Vec::new(),
+        methods,
+    });
+
+    let mut fields = Vec::new();
+    for field_name in object.get_all_field_names() {
+        let child = object.get_field(field_name).ok();
+        let info = object.get_field_info(field_name);
+        fields.push(FieldSchema {
+            name: field_name,
+            type_name: info.map(|info| info.type_name),
+            mutable: match info {
+                Some(info) => info.mutable,
+                None => is_writable_property(object, field_name),
+            },
+            schema: child.map(|child| child.get_type_path()),
+            docs: info.map_or("", |info| info.docs),
+        });
+
+        if let Some(child) = child {
+            describe_type(child, types);
+        }
+    }
+    types[index].fields = fields;
+}
+
+#[cfg(feature = "serde")]
+mod serialize {
+    use serde::ser::{Serialize, SerializeMap, Serializer};
+
+    use super::*;
+
+    impl Serialize for Schema {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            /// `{"my_crate::Root": {...}, "my_crate::Child": {...}}`
+            struct Types<'a>(&'a [TypeSchema]);
+
+            impl Serialize for Types<'_> {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    let mut map = serializer.serialize_map(Some(self.0.len()))?;
+                    for schema in self.0 {
+                        map.serialize_entry(schema.path, schema)?;
+                    }
+                    map.end()
+                }
+            }
+
+            let mut map = serializer.serialize_map(Some(4))?;
+            map.serialize_entry("version", &self.version)?;
+            map.serialize_entry("root", self.root)?;
+            map.serialize_entry("types", &Types(&self.types))?;
+            map.serialize_entry("functions", &self.functions)?;
+            map.end()
+        }
+    }
+
+    /// `{"name": "Root", "fields": [...], "methods": [...]}`, the path is the key in the `types` map
+    impl Serialize for TypeSchema {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut map = serializer.serialize_map(Some(3))?;
+            map.serialize_entry("name", self.name)?;
+            map.serialize_entry("fields", &self.fields)?;
+            map.serialize_entry("methods", &self.methods)?;
+            map.end()
+        }
+    }
+
+    /// `{"name": "child", "type": "Child", "mutable": true, "schema": "my_crate::Child", "docs": "..."}`
+    impl Serialize for FieldSchema {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut map = serializer.serialize_map(Some(5))?;
+            map.serialize_entry("name", self.name)?;
+            map.serialize_entry("type", &self.type_name)?;
+            map.serialize_entry("mutable", &self.mutable)?;
+            map.serialize_entry("schema", &self.schema)?;
+            map.serialize_entry("docs", self.docs)?;
+            map.end()
+        }
+    }
+
+    /// The signature with the module path: `{"module": "my_crate", "name": "add_one", ...}`
+    impl Serialize for FunctionSchema {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let signature = &self.signature;
+            let mut map = serializer.serialize_map(Some(5))?;
+            map.serialize_entry("module", self.module_path)?;
+            map.serialize_entry("name", signature.name)?;
+            map.serialize_entry("params", signature.params)?;
+            map.serialize_entry("return_type", &signature.return_type)?;
+            map.serialize_entry("docs", signature.docs)?;
+            map.end()
+        }
+    }
+}
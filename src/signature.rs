@@ -0,0 +1,131 @@
+use core::fmt::{Display, Formatter};
+
+/// The signature of an interactive method or function, as written in the source code.
+///
+/// Can be used to show hints like `add(a: f32, b: f32) -> f32` in a CLI.
+///
+/// # Example
+///
+/// ```
+/// use rusteval::{Interactive, Methods, Receiver};
+///
+/// #[derive(Interactive, Debug, Default)]
+/// struct Calculator;
+///
+/// #[Methods]
+/// impl Calculator {
+///     /// Adds two numbers.
+///     fn add(&self, a: f32, b: f32) -> f32 {
+///         a + b
+///     }
+/// }
+///
+/// let signature = Calculator.get_method_signature("add").unwrap();
+/// assert_eq!(signature.to_string(), "add(a: f32, b: f32) -> f32");
+/// assert_eq!(signature.receiver, Some(Receiver::Ref));
+/// assert_eq!(signature.params[1].type_name, "f32");
+/// assert_eq!(signature.docs, "Adds two numbers.");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Signature {
+    /// The name the method or function is called with in a query.
+    pub name: &'static str,
+    /// How the method takes `self`, `None` for free functions.
+    pub receiver: Option<Receiver>,
+    /// The parameters, without the receiver.
+    pub params: &'static [Parameter],
+    /// The return type, `None` if nothing is returned.
+    pub return_type: Option<&'static str>,
+    /// The doc comment, without the leading `///`.
+    pub docs: &'static str,
+}
+
+/// A parameter of a [`Signature`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Parameter {
+    /// The name of the parameter, e.g. `a`.
+    pub name: &'static str,
+    /// The type of the parameter, e.g. `&str`.
+    pub type_name: &'static str,
+}
+
+/// How a method takes `self`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Receiver {
+    /// `&self`
+    Ref,
+    /// `&mut self`
+    RefMut,
+    /// `self`
+    Value,
+}
+
+impl Display for Signature {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}(", self.name)?;
+        for (index, param) in self.params.iter().enumerate() {
+            if index > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{}", param)?;
+        }
+        f.write_str(")")?;
+        match self.return_type {
+            Some(return_type) => write!(f, " -> {}", return_type),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Display for Parameter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}: {}", self.name, self.type_name)
+    }
+}
+
+impl Display for Receiver {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Receiver::Ref => "&self",
+            Receiver::RefMut => "&mut self",
+            Receiver::Value => "self",
+        })
+    }
+}
+
+/// `{"name": "add", "receiver": "&self", "params": [...], "return_type": "f32", "docs": "..."}`
+#[cfg(feature = "serde")]
+impl serde::Serialize for Signature {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+
+        let mut map = serializer.serialize_map(Some(5))?;
+        map.serialize_entry("name", self.name)?;
+        map.serialize_entry("receiver", &self.receiver)?;
+        map.serialize_entry("params", self.params)?;
+        map.serialize_entry("return_type", &self.return_type)?;
+        map.serialize_entry("docs", self.docs)?;
+        map.end()
+    }
+}
+
+/// `{"name": "a", "type": "f32"}`
+#[cfg(feature = "serde")]
+impl serde::Serialize for Parameter {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+
+        let mut map = serializer.serialize_map(Some(2))?;
+        map.serialize_entry("name", self.name)?;
+        map.serialize_entry("type", self.type_name)?;
+        map.end()
+    }
+}
+
+/// `"&self"`, `"&mut self"` or `"self"`
+#[cfg(feature = "serde")]
+impl serde::Serialize for Receiver {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
@@ -1,10 +1,11 @@
 //! Implementation details.
 #![allow(missing_docs)]
 
-use core::any::type_name;
-use core::fmt::Debug;
+use core::any::{type_name, Any};
+use core::fmt::{Debug, Display};
 
-use crate::{Interactive, InteractiveError, Methods, Result};
+use crate::output::DisplayOnly;
+use crate::{Interactive, InteractiveError, Methods, Output, Result, StaticType};
 
 /// Use specialization to retrieve a trait object reference
 /// from types that implement the trait or an error if it doesn't.
@@ -67,6 +68,80 @@ duck_type!(pub AsMethods(try_as_methods): Methods | MethodsNotImplemented);
 
 duck_type_mut!(pub AsMethodsMut(try_as_methods_mut): Methods | MethodsNotImplemented);
 duck_type!(pub AsDebug(try_as_debug): Debug | DebugNotImplemented);
+duck_type!(pub AsDisplay(try_as_display): Display | DisplayNotImplemented);
+
+/// Use specialization to retrieve a `&dyn Output` from types that implement `Debug`.
+///
+/// Types that only implement `Display` are shown with it instead.
+pub trait AsOutput {
+    fn try_as_output(&self) -> Result<'_, &dyn Output>;
+}
+
+impl<T> AsOutput for T {
+    default fn try_as_output(&self) -> Result<'_, &dyn Output> {
+        match self.try_as_display() {
+            Ok(_) => Ok(DisplayOnly::new(self)),
+            Err(_) => Err(InteractiveError::DebugNotImplemented {
+                type_name: type_name::<T>(),
+            }),
+        }
+    }
+}
+
+impl<T> AsOutput for T
+where
+    T: Output,
+{
+    fn try_as_output(&self) -> Result<'_, &dyn Output> {
+        Ok(self)
+    }
+}
+
+/// Use specialization to retrieve a `&dyn Any` from types that implement [`StaticType`].
+///
+/// `Any` itself can't be used, because specializing on `'static` is not possible.
+pub trait AsAny {
+    fn try_as_any(&self) -> Option<&dyn Any>;
+}
+
+impl<T> AsAny for T {
+    default fn try_as_any(&self) -> Option<&dyn Any> {
+        None
+    }
+}
+
+impl<T> AsAny for T
+where
+    T: StaticType,
+{
+    fn try_as_any(&self) -> Option<&dyn Any> {
+        Some(self)
+    }
+}
+
+/// Use specialization to convert types that implement `Serialize` into JSON.
+#[cfg(feature = "serde")]
+pub trait AsSerialize {
+    /// Returns `None` if the type doesn't implement `Serialize` or the value can't be represented as JSON.
+    fn try_to_json(&self) -> Option<serde_json::Value>;
+}
+
+#[cfg(feature = "serde")]
+impl<T> AsSerialize for T {
+    default fn try_to_json(&self) -> Option<serde_json::Value> {
+        None
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T> AsSerialize for T
+where
+    T: serde::Serialize,
+{
+    fn try_to_json(&self) -> Option<serde_json::Value> {
+        serde_json::to_value(self).ok()
+    }
+}
 
 /// Add the appropriate $AsTrait impl for &dyn Interactive
 /// Prevents $AsTrait from using &(&dyn Interactive) or &(&mut dyn Interactive) as self
@@ -101,6 +176,7 @@ macro_rules! deref_for_interactive_mut {
 deref_for_interactive!(AsMethods(try_as_methods): Methods);
 deref_for_interactive_mut!(AsMethodsMut(try_as_methods_mut): Methods);
 deref_for_interactive!(AsDebug(try_as_debug): Debug);
+deref_for_interactive!(AsOutput(try_as_output): Output);
 
 /// Used as a dummy value for types that don't implement Debug inside #[derive(PartialDebug)].
 #[allow(missing_copy_implementations)]
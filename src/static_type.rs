@@ -0,0 +1,90 @@
+use core::any::Any;
+
+/// Types whose results can be downcast with [`eval_as`](crate::InteractiveRoot::eval_as)
+/// and [`try_eval_as`](crate::InteractiveRoot::try_eval_as).
+///
+/// Implemented for primitives, `String` and common containers of `StaticType`s.
+/// Structs that derive [`Interactive`](macro@crate::Interactive) opt in with `#[interactive(any)]`,
+/// which checks the requirements below, so prefer it over implementing this trait by hand.
+///
+/// # Safety
+///
+/// Whether a result implements this trait is looked up with specialization, which ignores lifetimes.
+/// Implementing it for a type with lifetime parameters (e.g. `Foo<'static>`) would therefore
+/// allow downcasting borrowed data of a `Foo<'a>` to `Foo<'static>`, so the implementing type must not have any.
+/// Type parameters must be bounded by `StaticType` themselves.
+///
+/// ```
+/// use rusteval::StaticType;
+///
+/// #[derive(Clone)]
+/// struct Celsius(f32);
+///
+/// unsafe impl StaticType for Celsius {}
+/// ```
+pub unsafe trait StaticType: Any {}
+
+macro_rules! static_type {
+    ($($ty:ty),* $(,)?) => {
+        $(unsafe impl StaticType for $ty {})*
+    };
+}
+
+static_type!(
+    (),
+    bool,
+    char,
+    f32,
+    f64,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+);
+
+unsafe impl<T: StaticType> StaticType for Option<T> {}
+unsafe impl<T: StaticType, E: StaticType> StaticType for core::result::Result<T, E> {}
+unsafe impl<T: StaticType, const N: usize> StaticType for [T; N] {}
+
+macro_rules! tuple_static_type {
+    ($($T:ident),+) => {
+        unsafe impl<$($T: StaticType),+> StaticType for ($($T,)+) {}
+    };
+}
+
+tuple_static_type!(A);
+tuple_static_type!(A, B);
+tuple_static_type!(A, B, C);
+tuple_static_type!(A, B, C, D);
+tuple_static_type!(A, B, C, D, E);
+tuple_static_type!(A, B, C, D, E, F);
+tuple_static_type!(A, B, C, D, E, F, G);
+tuple_static_type!(A, B, C, D, E, F, G, H);
+
+#[cfg(feature = "std")]
+mod std_impls {
+    use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+    use std::rc::Rc;
+    use std::sync::Arc;
+
+    use super::StaticType;
+
+    unsafe impl StaticType for String {}
+    unsafe impl<T: StaticType> StaticType for Vec<T> {}
+    unsafe impl<T: StaticType> StaticType for VecDeque<T> {}
+    unsafe impl<T: StaticType> StaticType for Box<T> {}
+    unsafe impl<T: StaticType> StaticType for Rc<T> {}
+    unsafe impl<T: StaticType> StaticType for Arc<T> {}
+    unsafe impl<T: StaticType> StaticType for BTreeSet<T> {}
+    unsafe impl<T: StaticType> StaticType for HashSet<T> {}
+    unsafe impl<K: StaticType, V: StaticType> StaticType for BTreeMap<K, V> {}
+    unsafe impl<K: StaticType, V: StaticType> StaticType for HashMap<K, V> {}
+}
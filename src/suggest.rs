@@ -0,0 +1,92 @@
+/// Returns the candidate that was most likely meant instead of `name`.
+///
+/// A candidate that only differs in case is preferred,
+/// otherwise the one with the smallest edit distance is returned,
+/// as long as it takes at most one edit for every three chars of `name`.
+///
+/// Used for the suggestions of [`InteractiveError::FieldNotFound`](crate::InteractiveError::FieldNotFound) and co.
+///
+/// # Example
+///
+/// ```
+/// use rusteval::did_you_mean;
+///
+/// let names = &["temperature", "humidity", "Pressure"];
+/// assert_eq!(did_you_mean("temprature", names), Some("temperature"));
+/// assert_eq!(did_you_mean("pressure", names), Some("Pressure"));
+/// assert_eq!(did_you_mean("voltage", names), None);
+/// ```
+pub fn did_you_mean(name: &str, candidates: &[&'static str]) -> Option<&'static str> {
+    let candidates = candidates
+        .iter()
+        .copied()
+        .filter(|candidate| *candidate != name);
+
+    if let Some(candidate) = candidates
+        .clone()
+        .find(|candidate| candidate.eq_ignore_ascii_case(name))
+    {
+        return Some(candidate);
+    }
+
+    let max_distance = (name.chars().count() / 3).max(1);
+    candidates
+        .filter_map(|candidate| {
+            edit_distance(name, candidate, max_distance).map(|distance| (distance, candidate))
+        })
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// The longest candidate that is compared, the distance is computed without allocating.
+const MAX_LEN: usize = 64;
+
+/// Levenshtein distance between `a` and `b` or `None` if it is larger than `max_distance`.
+fn edit_distance(a: &str, b: &str, max_distance: usize) -> Option<usize> {
+    let b_len = b.chars().count();
+    if b_len > MAX_LEN || a.chars().count().abs_diff(b_len) > max_distance {
+        return None;
+    }
+
+    // distances between the first i chars of `a` and the first j chars of `b`, one row per i
+    let mut row = [0; MAX_LEN + 1];
+    for (j, distance) in row.iter_mut().enumerate().take(b_len + 1) {
+        *distance = j;
+    }
+
+    for (i, a_char) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, b_char) in b.chars().enumerate() {
+            let substitution = diagonal + usize::from(a_char != b_char);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+
+    Some(row[b_len]).filter(|distance| *distance <= max_distance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("kitten", "sitting", 5), Some(3));
+        assert_eq!(edit_distance("kitten", "sitting", 2), None);
+        assert_eq!(edit_distance("", "abc", 3), Some(3));
+        assert_eq!(edit_distance("ab", "ba", 2), Some(2));
+        assert_eq!(edit_distance("frob", "frob", 0), Some(0));
+    }
+
+    #[test]
+    fn test_did_you_mean() {
+        let names = &["field1", "field2", "ping", "Pong"];
+        assert_eq!(did_you_mean("feild1", names), Some("field1"));
+        assert_eq!(did_you_mean("pong", names), Some("Pong"));
+        assert_eq!(did_you_mean("pin", names), Some("ping"));
+        assert_eq!(did_you_mean("ping", names), None);
+        assert_eq!(did_you_mean("xyz", names), None);
+    }
+}
@@ -0,0 +1,120 @@
+//! The recursive dump of interactive objects, see [`InteractiveRoot::dump`](crate::InteractiveRoot::dump).
+use core::fmt::{Display, Formatter, Result, Write};
+
+use crate::Interactive;
+
+/// An object on the way from the first object of a walk to the current one.
+///
+/// Lives on the stack so that no allocation is needed.
+/// Displays the path of the object.
+pub(crate) struct Node<'a> {
+    pub(crate) object: &'a dyn Interactive,
+    /// The field name, or the whole path for the first object.
+    pub(crate) name: &'a str,
+    pub(crate) parent: Option<&'a Node<'a>>,
+}
+
+impl Node<'_> {
+    /// Whether the object is also one of its ancestors, e.g. through an `Rc`.
+    ///
+    /// The type names are compared as well because a struct and its first field may share an address.
+    pub(crate) fn is_cycle(&self) -> bool {
+        let mut ancestor = self.parent;
+        while let Some(parent) = ancestor {
+            if core::ptr::addr_eq(parent.object, self.object)
+                && parent.object.get_type_name() == self.object.get_type_name()
+            {
+                return true;
+            }
+            ancestor = parent.parent;
+        }
+        false
+    }
+}
+
+impl Display for Node<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        if let Some(parent) = self.parent {
+            write!(f, "{}", parent)?;
+            if !parent.name.is_empty() {
+                f.write_char('.')?;
+            }
+        }
+        f.write_str(self.name)
+    }
+}
+
+/// Writes the object and its fields up to `max_depth` levels deep, one line per field:
+///
+/// ```text
+/// child: Child
+///   child.value = 3
+///   child.inner: Inner
+///     child.inner.flag = true
+/// ```
+///
+/// Interactive fields are shown with their type name, all others with their `Debug` value.
+/// `path` is the query of the object, empty for the root.
+pub(crate) fn write_tree(
+    f: &mut dyn Write,
+    object: &dyn Interactive,
+    path: &str,
+    max_depth: usize,
+) -> Result {
+    match path {
+        "" => f.write_str(object.get_type_name())?,
+        _ => write!(f, "{}: {}", path, object.get_type_name())?,
+    }
+    let root = Node {
+        object,
+        name: path,
+        parent: None,
+    };
+    write_fields(f, &root, 1, max_depth)
+}
+
+fn write_fields(f: &mut dyn Write, parent: &Node<'_>, depth: usize, max_depth: usize) -> Result {
+    let object = parent.object;
+    let field_names = object.get_all_field_names();
+    if field_names.is_empty() {
+        return Ok(());
+    }
+    if depth > max_depth {
+        return f.write_str(" ...");
+    }
+
+    for &name in field_names {
+        write!(f, "\n{:indent$}{}", "", parent, indent = 2 * depth)?;
+        if !parent.name.is_empty() {
+            f.write_char('.')?;
+        }
+        f.write_str(name)?;
+
+        match object.get_field(name) {
+            Ok(field) => {
+                write!(f, ": {}", field.get_type_name())?;
+                let node = Node {
+                    object: field,
+                    name,
+                    parent: Some(parent),
+                };
+                if node.is_cycle() {
+                    f.write_str(" (cycle)")?;
+                } else {
+                    write_fields(f, &node, depth + 1, max_depth)?;
+                }
+            }
+            Err(_) => {
+                let mut result = Ok(());
+                object.eval_field_output(name, &mut |value| {
+                    result = match value {
+                        Ok(value) => write!(f, " = {:?}", value),
+                        Err(e) => write!(f, " = <{}>", e),
+                    }
+                });
+                result?;
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use core::any::{type_name, Any};
+use core::fmt::{Debug, Formatter};
+
+use crate::{InteractiveError, Result};
+
+/// Arguments that are passed to a method as they are, instead of being parsed from a string.
+///
+/// The arguments are a tuple with one element per parameter of the method.
+/// References are passed as the type they point to, e.g. a `u8` for `&mut u8`,
+/// and `&str` as well as `&mut str` are passed as a `String`.
+///
+/// Used by [`InteractiveRoot::call_method`](crate::InteractiveRoot::call_method) and
+/// [`InteractiveRoot::call_function`](crate::InteractiveRoot::call_function).
+pub struct TypedArgs<'a> {
+    args: &'a mut dyn Any,
+    type_name: &'static str,
+}
+
+impl<'a> TypedArgs<'a> {
+    /// Wraps the given arguments, which can then be taken by the method that gets called with them.
+    pub fn new<A: Any>(args: &'a mut Option<A>) -> Self {
+        TypedArgs {
+            args,
+            type_name: type_name::<A>(),
+        }
+    }
+
+    /// Takes the arguments out if they are of type `A`.
+    ///
+    /// Otherwise or if they were already taken an [`InteractiveError::WrongArgumentTypes`] is returned.
+    pub fn take<'b, A: Any>(&mut self, method_name: &'b str) -> Result<'b, A> {
+        self.args
+            .downcast_mut::<Option<A>>()
+            .and_then(Option::take)
+            .ok_or(InteractiveError::WrongArgumentTypes {
+                method_name,
+                expected: type_name::<A>(),
+                found: self.type_name,
+            })
+    }
+
+    /// Returns the type name of the arguments, e.g. `(f32, f32)`.
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+}
+
+impl Debug for TypedArgs<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("TypedArgs")
+            .field("type_name", &self.type_name)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,353 @@
+use core::any::type_name;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::rc::Rc;
+use std::sync::Arc;
+
+use crate::specialization::{AsDebug, AsInteractive};
+use crate::Interactive;
+
+/// An owned result of an evaluation.
+///
+/// Unlike the `&dyn Output` passed to the closure of [`try_eval_output`](crate::InteractiveRoot::try_eval_output)
+/// a `Value` can be stored, compared and sent elsewhere.
+/// Use [`try_eval_value`](crate::InteractiveRoot::try_eval_value) to get one.
+///
+/// Types that implement [`Interactive`] are converted into a [`Record`](Value::Record) of their fields,
+/// types that are not known to `Value` are kept as their `Debug` representation.
+///
+/// With the `serde` feature `Value` implements `Serialize`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    /// `()`
+    Unit,
+    /// A `bool`
+    Bool(bool),
+    /// A signed integer
+    Int(i128),
+    /// An unsigned integer
+    UInt(u128),
+    /// A `f32` or `f64`
+    Float(f64),
+    /// A `char`
+    Char(char),
+    /// A `String` or `&str`
+    String(String),
+    /// A tuple, array, slice, `Vec` or set
+    List(Vec<Value>),
+    /// A map as a list of key value pairs
+    Map(Vec<(Value, Value)>),
+    /// A type that implements [`Interactive`] with the values of its fields
+    Record {
+        /// The name of the type without its module path, e.g. `Vec<Sensor>`.
+        name: String,
+        /// Field names and their values.
+        fields: Vec<(String, Value)>,
+    },
+    /// The `Debug` representation of any other type
+    Debug(String),
+}
+
+impl Value {
+    /// Returns the value of the field with the given name if this is a [`Record`](Value::Record).
+    pub fn field(&self, field_name: &str) -> Option<&Value> {
+        match self {
+            Value::Record { fields, .. } => fields
+                .iter()
+                .find(|(name, _)| name == field_name)
+                .map(|(_, value)| value),
+            _ => None,
+        }
+    }
+}
+
+/// Conversion into a [`Value`], specialized for the types `Value` knows about.
+pub(crate) trait ToValue {
+    fn to_value(&self) -> Value;
+}
+
+impl<T> ToValue for T {
+    default fn to_value(&self) -> Value {
+        if let Ok(interactive) = self.try_as_interactive() {
+            return record(type_name::<T>(), interactive);
+        }
+        match self.try_as_debug() {
+            Ok(debug) => Value::Debug(format!("{:?}", debug)),
+            Err(_) => Value::Debug(short_type_name(type_name::<T>())),
+        }
+    }
+}
+
+fn record(type_name: &str, interactive: &dyn Interactive) -> Value {
+    let mut fields = Vec::new();
+    for &field_name in interactive.get_all_field_names() {
+        interactive.eval_field_output(field_name, &mut |result| {
+            if let Ok(value) = result {
+                fields.push((field_name.to_string(), value.to_value()));
+            }
+        });
+    }
+    Value::Record {
+        name: short_type_name(type_name),
+        fields,
+    }
+}
+
+/// Removes the module paths from a type name, e.g. `alloc::vec::Vec<app::Sensor>` becomes `Vec<Sensor>`.
+fn short_type_name(type_name: &str) -> String {
+    let mut short = String::with_capacity(type_name.len());
+    for c in type_name.chars() {
+        short.push(c);
+        if short.ends_with("::") {
+            short.truncate(short.len() - 2);
+            let segment_start = short
+                .rfind(|c: char| !(c.is_alphanumeric() || c == '_'))
+                .map_or(0, |index| index + 1);
+            short.truncate(segment_start);
+        }
+    }
+    short
+}
+
+impl ToValue for () {
+    fn to_value(&self) -> Value {
+        Value::Unit
+    }
+}
+
+impl ToValue for bool {
+    fn to_value(&self) -> Value {
+        Value::Bool(*self)
+    }
+}
+
+impl ToValue for char {
+    fn to_value(&self) -> Value {
+        Value::Char(*self)
+    }
+}
+
+macro_rules! to_value {
+    ($variant:ident($as:ty): $($ty:ty),*) => {
+        $(
+            impl ToValue for $ty {
+                fn to_value(&self) -> Value {
+                    Value::$variant(*self as $as)
+                }
+            }
+        )*
+    };
+}
+
+to_value!(Int(i128): i8, i16, i32, i64, i128, isize);
+to_value!(UInt(u128): u8, u16, u32, u64, u128, usize);
+to_value!(Float(f64): f32, f64);
+
+impl ToValue for String {
+    fn to_value(&self) -> Value {
+        Value::String(self.clone())
+    }
+}
+
+impl ToValue for &str {
+    fn to_value(&self) -> Value {
+        Value::String(self.to_string())
+    }
+}
+
+impl ToValue for Cow<'_, str> {
+    fn to_value(&self) -> Value {
+        Value::String(self.to_string())
+    }
+}
+
+impl<T> ToValue for &T {
+    fn to_value(&self) -> Value {
+        (**self).to_value()
+    }
+}
+
+impl<T> ToValue for &mut T {
+    fn to_value(&self) -> Value {
+        (**self).to_value()
+    }
+}
+
+impl<T> ToValue for Box<T> {
+    fn to_value(&self) -> Value {
+        (**self).to_value()
+    }
+}
+
+impl<T> ToValue for Rc<T> {
+    fn to_value(&self) -> Value {
+        (**self).to_value()
+    }
+}
+
+impl<T> ToValue for Arc<T> {
+    fn to_value(&self) -> Value {
+        (**self).to_value()
+    }
+}
+
+fn list<'a, T: 'a>(items: impl IntoIterator<Item = &'a T>) -> Value {
+    Value::List(items.into_iter().map(ToValue::to_value).collect())
+}
+
+impl<T> ToValue for &[T] {
+    fn to_value(&self) -> Value {
+        list(self.iter())
+    }
+}
+
+impl<T, const N: usize> ToValue for [T; N] {
+    fn to_value(&self) -> Value {
+        list(self)
+    }
+}
+
+impl<T> ToValue for Vec<T> {
+    fn to_value(&self) -> Value {
+        list(self)
+    }
+}
+
+impl<T> ToValue for VecDeque<T> {
+    fn to_value(&self) -> Value {
+        list(self)
+    }
+}
+
+impl<T> ToValue for BTreeSet<T> {
+    fn to_value(&self) -> Value {
+        list(self)
+    }
+}
+
+impl<T, S> ToValue for HashSet<T, S> {
+    fn to_value(&self) -> Value {
+        list(self)
+    }
+}
+
+impl<K, V> ToValue for BTreeMap<K, V> {
+    fn to_value(&self) -> Value {
+        Value::Map(
+            self.iter()
+                .map(|(k, v)| (k.to_value(), v.to_value()))
+                .collect(),
+        )
+    }
+}
+
+impl<K, V, S> ToValue for HashMap<K, V, S> {
+    fn to_value(&self) -> Value {
+        Value::Map(
+            self.iter()
+                .map(|(k, v)| (k.to_value(), v.to_value()))
+                .collect(),
+        )
+    }
+}
+
+macro_rules! tuple_to_value {
+    ($($T:ident $index:tt),+) => {
+        impl<$($T),+> ToValue for ($($T,)+) {
+            fn to_value(&self) -> Value {
+                Value::List(vec![$(self.$index.to_value()),+])
+            }
+        }
+    };
+}
+
+tuple_to_value!(A 0);
+tuple_to_value!(A 0, B 1);
+tuple_to_value!(A 0, B 1, C 2);
+tuple_to_value!(A 0, B 1, C 2, D 3);
+tuple_to_value!(A 0, B 1, C 2, D 3, E 4);
+tuple_to_value!(A 0, B 1, C 2, D 3, E 4, F 5);
+tuple_to_value!(A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+tuple_to_value!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Value {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::{SerializeMap, SerializeSeq};
+
+        match self {
+            Value::Unit => serializer.serialize_unit(),
+            Value::Bool(value) => serializer.serialize_bool(*value),
+            Value::Int(value) => serializer.serialize_i128(*value),
+            Value::UInt(value) => serializer.serialize_u128(*value),
+            Value::Float(value) => serializer.serialize_f64(*value),
+            Value::Char(value) => serializer.serialize_char(*value),
+            Value::String(value) => serializer.serialize_str(value),
+            Value::List(values) => {
+                let mut seq = serializer.serialize_seq(Some(values.len()))?;
+                for value in values {
+                    seq.serialize_element(value)?;
+                }
+                seq.end()
+            }
+            Value::Map(entries) => {
+                let mut map = serializer.serialize_map(Some(entries.len()))?;
+                for (key, value) in entries {
+                    map.serialize_entry(key, value)?;
+                }
+                map.end()
+            }
+            Value::Record { fields, .. } => {
+                let mut map = serializer.serialize_map(Some(fields.len()))?;
+                for (name, value) in fields {
+                    map.serialize_entry(name, value)?;
+                }
+                map.end()
+            }
+            Value::Debug(debug) => {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry("debug", debug)?;
+                map.end()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_short_type_name() {
+        assert_eq!(short_type_name("u8"), "u8");
+        assert_eq!(short_type_name("app::Sensor"), "Sensor");
+        assert_eq!(
+            short_type_name("alloc::vec::Vec<app::sensors::Sensor>"),
+            "Vec<Sensor>"
+        );
+        assert_eq!(
+            short_type_name("(app::A, &mut app::B<[core::C; 2]>)"),
+            "(A, &mut B<[C; 2]>)"
+        );
+    }
+
+    #[test]
+    fn test_to_value() {
+        assert_eq!(3u8.to_value(), Value::UInt(3));
+        assert_eq!((-3i64).to_value(), Value::Int(-3));
+        assert_eq!(0.5f32.to_value(), Value::Float(0.5));
+        assert_eq!("a".to_value(), Value::String("a".into()));
+        assert_eq!(
+            (true, 'c').to_value(),
+            Value::List(vec![Value::Bool(true), Value::Char('c')])
+        );
+        assert_eq!(
+            vec![[1u8], [2u8]].to_value(),
+            Value::List(vec![
+                Value::List(vec![Value::UInt(1)]),
+                Value::List(vec![Value::UInt(2)])
+            ])
+        );
+        assert_eq!(Some(1).to_value(), Value::Debug("Some(1)".into()));
+    }
+}
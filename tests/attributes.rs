@@ -0,0 +1,141 @@
+use rusteval::{Interactive, InteractiveError, InteractiveRoot, Methods, PartialDebug};
+
+#[derive(Interactive, PartialDebug, Default)]
+struct Counter {
+    count: u32,
+}
+
+#[Methods]
+impl Counter {
+    fn get(&self) -> u32 {
+        self.count
+    }
+
+    fn increment(&mut self) {
+        self.count += 1;
+    }
+}
+
+#[derive(Interactive, PartialDebug, Default)]
+struct Account {
+    #[interactive(rename = "user")]
+    user_name: String,
+    #[interactive(skip)]
+    password: String,
+    #[interactive(readonly)]
+    logins: Counter,
+    #[interactive(hidden)]
+    debug_flags: u8,
+}
+
+#[Methods]
+impl Account {
+    #[interactive(rename = "name_len")]
+    fn user_name_len(&self) -> usize {
+        self.user_name.len()
+    }
+
+    #[interactive(hidden)]
+    fn password_len(&self) -> usize {
+        self.password.len()
+    }
+
+    #[interactive(skip)]
+    fn set_password(&mut self, password: String) {
+        self.password = password;
+    }
+}
+
+#[derive(InteractiveRoot, Default)]
+struct Root {
+    account: Account,
+}
+
+fn root() -> Root {
+    let mut root = Root::default();
+    root.account.user_name = "admin".into();
+    root.account.set_password("hunter2".into());
+    root
+}
+
+#[test]
+fn test_skip_field() {
+    let mut root = root();
+    assert_eq!(
+        root.eval_to_string("account.password"),
+        "No field `password` found for type `Account`"
+    );
+    assert!(!root.account.get_all_field_names().contains(&"password"));
+}
+
+#[test]
+fn test_rename_field() {
+    let mut root = root();
+    assert_eq!(root.eval_to_string("account.user"), "\"admin\"");
+    assert_eq!(
+        root.eval_to_string("account.user_name"),
+        "No field `user_name` found for type `Account`"
+    );
+}
+
+#[test]
+fn test_readonly_field() {
+    let mut root = root();
+    assert_eq!(root.eval_to_string("account.logins.get()"), "0");
+    assert_eq!(root.eval_to_string("account.logins.count"), "0");
+    assert!(matches!(
+        root.account.get_field_mut("logins"),
+        Err(InteractiveError::FieldNotFound {
+            type_name: "Account",
+            field_name: "logins",
+            ..
+        })
+    ));
+    assert_eq!(
+        root.eval_to_string("account.logins.increment()"),
+        "No method named `increment` found for type `Counter`"
+    );
+}
+
+#[test]
+fn test_hidden_field() {
+    let mut root = root();
+    assert_eq!(root.eval_to_string("account.debug_flags"), "0");
+    assert_eq!(root.account.get_all_field_names(), ["user", "logins"]);
+}
+
+#[test]
+fn test_rename_method() {
+    let mut root = root();
+    assert_eq!(root.eval_to_string("account.name_len()"), "5");
+    assert_eq!(
+        root.eval_to_string("account.user_name_len()"),
+        "No method named `user_name_len` found for type `Account`"
+    );
+}
+
+#[test]
+fn test_hidden_method() {
+    let mut root = root();
+    assert_eq!(root.eval_to_string("account.password_len()"), "7");
+    assert_eq!(root.account.get_all_method_names(), ["name_len"]);
+}
+
+#[test]
+fn test_skip_method() {
+    let mut root = root();
+    assert_eq!(
+        root.eval_to_string("account.set_password(\"1234\")"),
+        "No method named `set_password` found for type `Account`"
+    );
+    assert!(root.account.get_all_skipped_methods().is_empty());
+}
+
+#[test]
+fn test_partial_debug_skips_field() {
+    let mut root = root();
+    assert_eq!(
+        root.eval_to_string("account"),
+        "Account { user_name: \"admin\", logins: Counter { count: 0 }, debug_flags: 0, .. }"
+    );
+}
@@ -0,0 +1,164 @@
+use rusteval::{Function, Interactive, InteractiveError, InteractiveRoot, Methods};
+
+#[derive(Interactive, Debug, Default)]
+struct Child {
+    name: String,
+    count: u32,
+}
+
+#[Methods]
+impl Child {
+    fn add(&self, a: f32, b: f32) -> f32 {
+        a + b
+    }
+
+    fn greet(&self, greeting: &str) -> String {
+        format!("{}, {}!", greeting, self.name)
+    }
+
+    fn increment(&mut self, by: &mut u32) -> u32 {
+        self.count += *by;
+        self.count
+    }
+
+    #[interactive(format = "hex")]
+    fn status(&self) -> u8 {
+        0xA4
+    }
+}
+
+#[derive(Interactive, Debug, Default)]
+struct Generic<T> {
+    value: T,
+}
+
+#[Methods]
+impl<T: Clone + 'static> Generic<T> {
+    fn get(&self) -> T {
+        self.value.clone()
+    }
+}
+
+#[derive(Interactive, Debug, Default)]
+struct Parent {
+    child: Child,
+    #[interactive(flatten)]
+    flat: Generic<u8>,
+}
+
+#[Methods]
+impl Parent {}
+
+#[derive(InteractiveRoot, Debug)]
+struct Root<'a> {
+    parent: Parent,
+    shared: &'a Child,
+}
+
+fn root(shared: &Child) -> Root<'_> {
+    Root {
+        parent: Parent::default(),
+        shared,
+    }
+}
+
+#[Function]
+fn add_one(a: u32) -> u32 {
+    a + 1
+}
+
+#[test]
+fn test_call_method() {
+    let child = Child::default();
+    let mut root = root(&child);
+
+    assert_eq!(
+        root.call_method::<f32>("parent.child", "add", (1.0f32, 2.0f32)),
+        Ok(3.0)
+    );
+    assert_eq!(root.call_method::<u8>("parent", "get", ()), Ok(0));
+    assert_eq!(
+        root.call_method::<f32>("shared", "add", (1.0f32, 2.0f32)),
+        Ok(3.0)
+    );
+}
+
+#[test]
+fn test_call_method_reference_args() {
+    let child = Child {
+        name: "you".into(),
+        count: 0,
+    };
+    let mut root = root(&child);
+
+    assert_eq!(
+        root.call_method::<String>("shared", "greet", ("Hello".to_string(),)),
+        Ok("Hello, you!".into())
+    );
+    assert_eq!(
+        root.call_method::<u32>("parent.child", "increment", (2u32,)),
+        Ok(2)
+    );
+    assert_eq!(root.parent.child.count, 2);
+    assert_eq!(
+        root.call_method::<u32>("shared", "increment", (2u32,))
+            .unwrap_err()
+            .to_string(),
+        "No method named `increment` found for type `Child`"
+    );
+}
+
+#[test]
+fn test_call_function() {
+    let child = Child::default();
+    let mut root = root(&child);
+
+    assert_eq!(root.call_function("add_one", (41u32,)), Ok(42u32));
+    assert_eq!(
+        root.call_function::<u32>("add_two", (41u32,))
+            .unwrap_err()
+            .to_string(),
+        "No function named `add_two` found"
+    );
+}
+
+#[test]
+fn test_wrong_argument_types() {
+    let child = Child::default();
+    let mut root = root(&child);
+
+    root.try_call_method("parent.child", "add", (1.0f64, 2.0f64), |result| {
+        assert_eq!(
+            result.unwrap_err(),
+            InteractiveError::WrongArgumentTypes {
+                method_name: "add",
+                expected: "(f32, f32)",
+                found: "(f64, f64)",
+            }
+        )
+    });
+    root.try_call_method("", "add_one", 41u32, |result| {
+        assert_eq!(
+            result.unwrap_err(),
+            InteractiveError::WrongArgumentTypes {
+                method_name: "add_one",
+                expected: "(u32,)",
+                found: "u32",
+            }
+        )
+    });
+}
+
+#[test]
+fn test_call_method_with_format() {
+    let child = Child::default();
+    let mut root = root(&child);
+
+    root.try_call_method("parent.child", "status", (), |result| {
+        assert_eq!(format!("{:?}", result.unwrap()), "0xA4")
+    });
+    assert_eq!(
+        root.call_method::<u8>("parent.child", "status", ()),
+        Ok(0xA4)
+    );
+}
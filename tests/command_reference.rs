@@ -0,0 +1,155 @@
+use rusteval::{Function, Interactive, InteractiveRoot, Methods};
+
+#[derive(Interactive, Debug, Default)]
+struct Sensor {
+    /// The last measured value.
+    ///
+    /// In °C.
+    value: f32,
+    /// Either `a | b`.
+    mode: u8,
+}
+
+#[Methods]
+impl Sensor {
+    /// Measures again.
+    ///
+    /// Takes a while.
+    fn measure(&mut self, samples: u8) -> f32 {
+        self.value * samples as f32
+    }
+
+    #[interactive(property)]
+    fn doubled(&self) -> f32 {
+        self.value * 2.0
+    }
+
+    #[interactive(property)]
+    fn fahrenheit(&self) -> f32 {
+        self.value * 1.8 + 32.0
+    }
+
+    #[interactive(setter = "fahrenheit")]
+    fn set_fahrenheit(&mut self, fahrenheit: f32) {
+        self.value = (fahrenheit - 32.0) / 1.8;
+    }
+}
+
+#[derive(InteractiveRoot, Debug, Default)]
+struct Root {
+    first: Sensor,
+    #[interactive(readonly)]
+    second: Sensor,
+}
+
+/// Adds one.
+#[Function]
+fn add_one(a: u32) -> u32 {
+    a + 1
+}
+
+#[test]
+fn test_command_reference() {
+    let reference = Root::default().command_reference();
+
+    assert_eq!(
+        reference,
+        "\
+# `Root` command reference
+
+## `Root`
+
+### Fields
+
+| Query | Type | Access | Description |
+| --- | --- | --- | --- |
+| `first` | `Sensor` | read-write |  |
+| `second` | `Sensor` | read-only |  |
+
+## `first`: `Sensor`
+
+### Fields
+
+| Query | Type | Access | Description |
+| --- | --- | --- | --- |
+| `first.value` | `f32` | read-write | The last measured value.  In °C. |
+| `first.mode` | `u8` | read-write | Either `a \\| b`. |
+| `first.doubled` |  | read-only |  |
+| `first.fahrenheit` |  | read-write |  |
+
+### Methods
+
+- `first.measure(samples: u8) -> f32`: Measures again.
+
+  Takes a while.
+- `first.set_fahrenheit(fahrenheit: f32)`
+
+## `second`: `Sensor`
+
+### Fields
+
+| Query | Type | Access | Description |
+| --- | --- | --- | --- |
+| `second.value` | `f32` | read-only | The last measured value.  In °C. |
+| `second.mode` | `u8` | read-only | Either `a \\| b`. |
+| `second.doubled` |  | read-only |  |
+| `second.fahrenheit` |  | read-only |  |
+
+### Methods
+
+- `second.measure(samples: u8) -> f32`: Measures again.
+
+  Takes a while.
+- `second.set_fahrenheit(fahrenheit: f32)`
+
+## Functions
+
+### `command_reference`
+
+- `add_one(a: u32) -> u32`: Adds one.
+"
+    );
+}
+
+mod cycle {
+    use rusteval::{did_you_mean, Interactive, InteractiveError, InteractiveRoot, Result};
+
+    /// Contains itself as the field `me`.
+    #[derive(Debug, Default)]
+    pub struct Mirror;
+
+    impl Interactive for Mirror {
+        fn get_field<'a>(&'a self, field_name: &'a str) -> Result<'a, &'a dyn Interactive> {
+            match field_name {
+                "me" => Ok(self),
+                _ => Err(InteractiveError::FieldNotFound {
+                    type_name: "Mirror",
+                    field_name,
+                    suggestion: did_you_mean(field_name, self.get_all_field_names()),
+                }),
+            }
+        }
+
+        fn get_all_field_names(&self) -> &'static [&'static str] {
+            &["me"]
+        }
+
+        fn get_type_name(&self) -> &'static str {
+            "Mirror"
+        }
+    }
+
+    #[derive(InteractiveRoot, Debug, Default)]
+    pub struct Root {
+        mirror: Mirror,
+    }
+
+    #[test]
+    fn test_cycle() {
+        let reference = Root::default().command_reference();
+
+        assert!(reference.contains("\n## `mirror`: `Mirror`\n"));
+        assert!(reference.contains("| `mirror.me` |  | read-only |  |\n"));
+        assert!(!reference.contains("## `mirror.me`"));
+    }
+}
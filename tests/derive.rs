@@ -1,6 +1,7 @@
 use core::fmt::Debug;
 use rusteval::Interactive;
 
+#[allow(dead_code)]
 #[derive(Default, Debug)]
 struct Inner(bool, Option<String>);
 
@@ -48,6 +49,17 @@ fn test_complex_field() {
     });
 }
 
+#[test]
+fn test_field_output() {
+    let test_struct = TestStruct::default();
+
+    test_struct.eval_field_output("field1", &mut |field| {
+        let field = field.unwrap();
+        assert_eq!(field.type_name(), "u32");
+        assert_eq!(field.as_display().unwrap().to_string(), "0");
+    });
+}
+
 #[test]
 fn test_references() {
     let test_struct = TestStruct::default();
@@ -151,7 +163,8 @@ fn test_shared_references_as_mut_references() {
             .unwrap_err(),
         InteractiveError::FieldNotFound {
             type_name: "RefStruct",
-            field_name: "test_struct_ref"
+            field_name: "test_struct_ref",
+            suggestion: None,
         }
     );
 }
@@ -259,7 +272,8 @@ fn test_dyn_shared_references_as_mut_references() {
             .unwrap_err(),
         InteractiveError::FieldNotFound {
             type_name: "DynRefStruct",
-            field_name: "test_struct_ref"
+            field_name: "test_struct_ref",
+            suggestion: None,
         }
     );
 }
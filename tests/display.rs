@@ -0,0 +1,112 @@
+use core::fmt::{self, Display, Formatter};
+
+use rusteval::{Interactive, InteractiveRoot, Methods, OutputOptions};
+
+#[derive(Debug, Default, Clone, Copy)]
+struct Celsius(f32);
+
+impl Display for Celsius {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} °C", self.0)
+    }
+}
+
+/// Only implements `Display`
+#[derive(Default)]
+struct Version;
+
+impl Display for Version {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("v1.2.3")
+    }
+}
+
+#[derive(Interactive, Debug, Default)]
+struct Thermometer {
+    temperature: Celsius,
+    #[interactive(display, unit = "(ambient)")]
+    ambient: Celsius,
+    samples: u32,
+}
+
+#[Methods]
+impl Thermometer {
+    fn current(&self) -> Celsius {
+        self.temperature
+    }
+
+    #[interactive(display)]
+    fn current_display(&self) -> Celsius {
+        self.temperature
+    }
+
+    fn version(&self) -> Version {
+        Version
+    }
+}
+
+#[derive(InteractiveRoot, Default)]
+struct Root {
+    thermometer: Thermometer,
+}
+
+fn prefer_display() -> OutputOptions {
+    OutputOptions {
+        prefer_display: true,
+        ..OutputOptions::default()
+    }
+}
+
+#[test]
+fn test_debug_by_default() {
+    let mut root = Root::default();
+    assert_eq!(
+        root.eval_to_string("thermometer.temperature"),
+        "Celsius(0.0)"
+    );
+    assert_eq!(root.eval_to_string("thermometer.current()"), "Celsius(0.0)");
+}
+
+#[test]
+fn test_prefer_display() {
+    let mut root = Root::default();
+    let options = prefer_display();
+    assert_eq!(
+        root.eval_to_string_with("thermometer.temperature", &options),
+        "0 °C"
+    );
+    assert_eq!(
+        root.eval_to_string_with("thermometer.current()", &options),
+        "0 °C"
+    );
+    assert_eq!(
+        root.eval_to_string_with("thermometer.samples", &options),
+        "0"
+    );
+}
+
+#[test]
+fn test_fall_back_to_debug() {
+    let mut root = Root::default();
+    assert_eq!(
+        root.eval_to_string_with("thermometer", &prefer_display()),
+        "Thermometer { temperature: Celsius(0.0), ambient: Celsius(0.0), samples: 0 }"
+    );
+}
+
+#[test]
+fn test_display_attribute() {
+    let mut root = Root::default();
+    assert_eq!(root.eval_to_string("thermometer.current_display()"), "0 °C");
+    assert_eq!(root.eval_to_string("thermometer.ambient"), "0 °C (ambient)");
+}
+
+#[test]
+fn test_display_only() {
+    let mut root = Root::default();
+    assert_eq!(root.eval_to_string("thermometer.version()"), "v1.2.3");
+    assert_eq!(
+        root.eval_to_string_with("thermometer.version()", &prefer_display()),
+        "v1.2.3"
+    );
+}
@@ -0,0 +1,126 @@
+use rusteval::{Interactive, InteractiveError, InteractiveRoot, Methods, StaticType};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Celsius(f32);
+
+unsafe impl StaticType for Celsius {}
+
+#[derive(Interactive, Debug, Clone, Default, PartialEq)]
+#[interactive(any)]
+struct Child {
+    last_sum: f32,
+    #[interactive(format = "hex")]
+    status: u32,
+    name: String,
+}
+
+#[Methods]
+impl Child {
+    fn add(&mut self, a: f32, b: f32) -> f32 {
+        self.last_sum = a + b;
+        self.last_sum
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn temperature(&self) -> Celsius {
+        Celsius(21.5)
+    }
+}
+
+#[derive(Interactive, Debug, Clone, Default, PartialEq)]
+#[interactive(any)]
+struct Generic<T> {
+    value: T,
+}
+
+#[derive(Interactive, Debug, Clone, Default, PartialEq)]
+struct NotOptedIn {
+    value: u8,
+}
+
+#[derive(InteractiveRoot, Default)]
+struct Root {
+    child: Child,
+    generic: Generic<Vec<u8>>,
+    not_opted_in: NotOptedIn,
+}
+
+#[test]
+fn test_primitive() {
+    let mut root = Root::default();
+    assert_eq!(root.eval_as::<f32>("child.add(1, 2)"), Ok(3.0));
+    assert_eq!(root.eval_as::<f32>("child.last_sum"), Ok(3.0));
+    assert_eq!(root.eval_as::<String>("child.name"), Ok(String::new()));
+}
+
+#[test]
+fn test_format_hint() {
+    let mut root = Root::default();
+    root.child.status = 0xA4;
+    assert_eq!(root.eval_as::<u32>("child.status"), Ok(0xA4));
+}
+
+#[test]
+fn test_structs() {
+    let mut root = Root::default();
+    root.generic.value = vec![1, 2];
+    assert_eq!(root.eval_as::<Child>("child"), Ok(Child::default()));
+    assert_eq!(
+        root.eval_as::<Generic<Vec<u8>>>("generic"),
+        Ok(Generic { value: vec![1, 2] })
+    );
+    assert_eq!(
+        root.eval_as::<Celsius>("child.temperature()"),
+        Ok(Celsius(21.5))
+    );
+}
+
+#[test]
+fn test_type_mismatch() {
+    let mut root = Root::default();
+    assert_eq!(
+        root.eval_as::<u8>("child.add(1, 2)")
+            .unwrap_err()
+            .to_string(),
+        "Expected type `u8`, found `f32`"
+    );
+    root.try_eval_as::<u8, _>("child.last_sum", |result| {
+        assert_eq!(
+            result,
+            Err(InteractiveError::TypeMismatch {
+                expected: "u8",
+                found: "f32"
+            })
+        )
+    });
+}
+
+#[test]
+fn test_not_static() {
+    let mut root = Root::default();
+    assert_eq!(
+        root.eval_as::<&str>("child.name()")
+            .unwrap_err()
+            .to_string(),
+        "Expected type `&str`, but `&str` doesn't implement `StaticType`"
+    );
+    // derived structs only implement `StaticType` with `#[interactive(any)]`
+    assert_eq!(
+        root.eval_as::<NotOptedIn>("not_opted_in")
+            .unwrap_err()
+            .to_string(),
+        "Expected type `eval_as::NotOptedIn`, but `eval_as::NotOptedIn` doesn't implement `StaticType`"
+    );
+}
+
+#[test]
+fn test_error() {
+    let mut root = Root::default();
+    assert_eq!(
+        root.eval_as::<u8>("child.nothing").unwrap_err().to_string(),
+        "No field `nothing` found for type `Child`"
+    );
+}
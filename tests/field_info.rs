@@ -0,0 +1,116 @@
+use rusteval::{FieldInfo, Interactive, InteractiveRoot};
+
+#[derive(Interactive, Debug, Default)]
+struct Child {
+    /// Degrees Celsius.
+    ///
+    /// Measured once per second.
+    temperature: f32,
+    #[interactive(readonly)]
+    id: u32,
+    #[interactive(rename = "label", hidden)]
+    name: String,
+    #[interactive(skip)]
+    #[allow(dead_code)]
+    secret: u8,
+}
+
+#[derive(Interactive, Debug, Default)]
+struct Generic<T> {
+    value: T,
+}
+
+#[derive(Interactive, Debug, Default)]
+struct Wrapper {
+    inner: Child,
+}
+
+#[derive(Interactive, Debug)]
+struct Parent<'a> {
+    owned: Child,
+    shared: &'a Child,
+    #[interactive(flatten)]
+    flat: Generic<Vec<u8>>,
+    #[interactive(flatten)]
+    shared_flat: &'a Wrapper,
+}
+
+#[derive(InteractiveRoot, Debug)]
+struct Root<'a> {
+    parent: Parent<'a>,
+}
+
+#[test]
+fn test_field_info() {
+    let child = Child::default();
+    assert_eq!(
+        child.get_field_info("temperature"),
+        Some(FieldInfo {
+            name: "temperature",
+            type_name: "f32",
+            mutable: true,
+            interactive: false,
+            docs: "Degrees Celsius.\n\nMeasured once per second.",
+        })
+    );
+    assert!(!child.get_field_info("id").unwrap().mutable);
+    assert_eq!(child.get_field_info("label").unwrap().type_name, "String");
+    assert_eq!(child.get_field_info("name"), None);
+    assert_eq!(child.get_field_info("secret"), None);
+}
+
+#[test]
+fn test_references_and_flatten() {
+    let child = Child::default();
+    let wrapper = Wrapper::default();
+    let parent = Parent {
+        owned: Child::default(),
+        shared: &child,
+        flat: Generic::default(),
+        shared_flat: &wrapper,
+    };
+
+    let owned = parent.get_field_info("owned").unwrap();
+    assert!(owned.mutable);
+    assert!(owned.interactive);
+
+    let shared = parent.get_field_info("shared").unwrap();
+    assert_eq!(shared.type_name, "&'a Child");
+    assert!(!shared.mutable);
+    assert!(shared.interactive);
+
+    assert_eq!(
+        parent.get_field_info("value"),
+        Some(FieldInfo {
+            name: "value",
+            type_name: "T",
+            mutable: true,
+            interactive: false,
+            docs: "",
+        })
+    );
+
+    let inner = parent.get_field_info("inner").unwrap();
+    assert_eq!(inner.type_name, "Child");
+    assert!(!inner.mutable);
+    assert!(inner.interactive);
+}
+
+#[test]
+fn test_field_info_through_root() {
+    let child = Child::default();
+    let wrapper = Wrapper::default();
+    let root = Root {
+        parent: Parent {
+            owned: Child::default(),
+            shared: &child,
+            flat: Generic::default(),
+            shared_flat: &wrapper,
+        },
+    };
+
+    let (parent, field_name) = root.get_queried_object("parent.shared").unwrap();
+    let info = parent.get_field_info(field_name).unwrap();
+    assert_eq!(info.name, "shared");
+    assert!(info.interactive);
+}
@@ -0,0 +1,157 @@
+use rusteval::{Interactive, InteractiveError, InteractiveRoot, Methods};
+
+#[derive(Interactive, Debug, Default)]
+struct Board {
+    led: bool,
+}
+
+#[Methods]
+impl Board {
+    fn led_on(&mut self) {
+        self.led = true;
+    }
+
+    fn is_led_on(&self) -> bool {
+        self.led
+    }
+}
+
+#[derive(Interactive, Debug, Default)]
+struct Sensors {
+    temperature: f32,
+    humidity: f32,
+}
+
+#[Methods]
+impl Sensors {
+    fn average(&self) -> f32 {
+        (self.temperature + self.humidity) / 2.0
+    }
+}
+
+#[derive(Interactive, Debug, Default)]
+struct Nested {
+    #[interactive(flatten)]
+    sensors: Sensors,
+}
+
+// flattened methods are reached through the struct's own `Methods` implementation
+#[Methods]
+impl Nested {}
+
+#[derive(InteractiveRoot, Default)]
+struct Root {
+    name: &'static str,
+    #[interactive(flatten)]
+    board: Board,
+    #[interactive(flatten)]
+    sensors: Sensors,
+}
+
+#[derive(InteractiveRoot)]
+struct RefRoot<'a> {
+    #[interactive(flatten)]
+    board: &'a mut Board,
+    #[interactive(flatten, readonly)]
+    sensors: &'a Sensors,
+}
+
+#[test]
+fn test_flattened_field() {
+    let mut root = Root::default();
+    root.sensors.temperature = 21.5;
+    assert_eq!(root.eval_to_string("temperature"), "21.5");
+    assert_eq!(root.eval_to_string("led"), "false");
+    assert_eq!(root.eval_to_string("name"), "\"\"");
+}
+
+#[test]
+fn test_flattened_field_is_not_accessible_by_name() {
+    let mut root = Root::default();
+    assert_eq!(
+        root.eval_to_string("board"),
+        "No field `board` found for type `Root`"
+    );
+}
+
+#[test]
+fn test_flattened_method() {
+    let mut root = Root::default();
+    assert_eq!(root.eval_to_string("led_on()"), "()");
+    assert_eq!(root.eval_to_string("is_led_on()"), "true");
+    assert!(root.board.led);
+    assert_eq!(root.eval_to_string("average()"), "0.0");
+}
+
+#[test]
+fn test_get_field_mut() {
+    let mut root = Root::default();
+    assert!(matches!(
+        root.get_field_mut("humidity"),
+        Err(InteractiveError::InteractiveNotImplemented { type_name: "f32" })
+    ));
+    assert!(matches!(
+        root.get_field_mut("nothing"),
+        Err(InteractiveError::FieldNotFound {
+            type_name: "Root",
+            field_name: "nothing",
+            ..
+        })
+    ));
+}
+
+#[test]
+fn test_not_found() {
+    let mut root = Root::default();
+    assert_eq!(
+        root.eval_to_string("nothing"),
+        "No field `nothing` found for type `Root`"
+    );
+    assert_eq!(
+        root.eval_to_string("nothing()"),
+        "No function named `nothing` found"
+    );
+}
+
+#[test]
+fn test_names() {
+    let root = Root::default();
+    assert_eq!(
+        root.get_all_field_names(),
+        ["name", "led", "temperature", "humidity"]
+    );
+    let method_names = root.get_all_method_names();
+    assert!(method_names.contains(&"led_on"));
+    assert!(method_names.contains(&"is_led_on"));
+    assert!(method_names.contains(&"average"));
+}
+
+#[test]
+fn test_nested_flatten() {
+    let nested = Nested::default();
+    assert_eq!(nested.get_all_field_names(), ["temperature", "humidity"]);
+    nested.eval_field("humidity", &mut |result| {
+        assert_eq!(format!("{:?}", result.unwrap()), "0.0")
+    });
+    nested.eval_method("average", "", &mut |result| {
+        assert_eq!(format!("{:?}", result.unwrap()), "0.0")
+    });
+}
+
+#[test]
+fn test_flattened_references() {
+    let mut board = Board::default();
+    let sensors = Sensors::default();
+    let mut root = RefRoot {
+        board: &mut board,
+        sensors: &sensors,
+    };
+    assert_eq!(root.eval_to_string("led_on()"), "()");
+    assert_eq!(root.eval_to_string("average()"), "0.0");
+    assert_eq!(root.eval_to_string("temperature"), "0.0");
+    assert!(matches!(
+        root.get_field_mut("temperature"),
+        Err(InteractiveError::FieldNotFound { .. })
+    ));
+    assert!(board.led);
+}
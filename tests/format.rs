@@ -0,0 +1,87 @@
+use rusteval::{Interactive, InteractiveRoot, Methods, PartialDebug};
+
+#[derive(Interactive, PartialDebug, Default)]
+struct Board {
+    #[interactive(format = "hex")]
+    status: u32,
+    #[interactive(format = "bin")]
+    flags: u8,
+    #[interactive(format = "{:.3}", unit = "V")]
+    voltage: f32,
+    #[interactive(unit = "mA")]
+    current: u16,
+    #[interactive(format = "{:#x}")]
+    address: usize,
+}
+
+#[Methods]
+impl Board {
+    #[interactive(unit = "mW")]
+    fn power(&self) -> f32 {
+        self.current as f32 * 2.5
+    }
+
+    #[interactive(format = "hex")]
+    fn read_register(&self, offset: u32) -> u16 {
+        (self.status >> offset) as u16
+    }
+
+    #[interactive(property, format = "{:.1}", unit = "%")]
+    fn load(&self) -> f32 {
+        12.345
+    }
+}
+
+#[derive(InteractiveRoot)]
+struct Root {
+    board: Board,
+}
+
+fn root() -> Root {
+    Root {
+        board: Board {
+            status: 0xA4,
+            flags: 0b101,
+            voltage: 3.29871,
+            current: 20,
+            address: 0x2000,
+        },
+    }
+}
+
+#[test]
+fn test_hex() {
+    let mut root = root();
+    assert_eq!(root.eval_to_string("board.status"), "0x0000_00A4");
+}
+
+#[test]
+fn test_bin() {
+    let mut root = root();
+    assert_eq!(root.eval_to_string("board.flags"), "0b0000_0101");
+}
+
+#[test]
+fn test_format_string_and_unit() {
+    let mut root = root();
+    assert_eq!(root.eval_to_string("board.voltage"), "3.299 V");
+    assert_eq!(root.eval_to_string("board.current"), "20 mA");
+    assert_eq!(root.eval_to_string("board.address"), "0x2000");
+}
+
+#[test]
+fn test_method_return_value() {
+    let mut root = root();
+    assert_eq!(root.eval_to_string("board.power()"), "50.0 mW");
+    assert_eq!(root.eval_to_string("board.read_register(4)"), "0x000A");
+    assert_eq!(root.eval_to_string("board.load"), "12.3 %");
+}
+
+#[test]
+fn test_partial_debug() {
+    let mut root = root();
+    assert_eq!(
+        root.eval_to_string("board"),
+        "Board { status: 0x0000_00A4, flags: 0b0000_0101, voltage: 3.299 V, current: 20 mA, address: 0x2000 }"
+    );
+}
@@ -31,7 +31,8 @@ fn test_free_function_not_there() {
         format!(
             "{}",
             InteractiveError::FunctionNotFound {
-                function_name: "add_two"
+                function_name: "add_two",
+                suggestion: None,
             }
         )
     );
@@ -0,0 +1,255 @@
+use rusteval::{Function, Interactive, InteractiveError, InteractiveRoot, Methods};
+
+#[derive(Interactive, Debug, Default)]
+struct Child {
+    /// The current temperature in °C.
+    ///
+    /// Updated once per second.
+    temperature: f32,
+    #[interactive(hidden)]
+    secret: u8,
+}
+
+#[Methods]
+impl Child {
+    /// Adds two numbers.
+    ///
+    /// Overflows are not checked.
+    fn add(&self, a: u8, b: u8) -> u8 {
+        a + b
+    }
+
+    fn reset(&mut self) {
+        self.temperature = 0.0;
+    }
+
+    #[interactive(property)]
+    fn fahrenheit(&self) -> f32 {
+        self.temperature * 1.8 + 32.0
+    }
+}
+
+#[derive(Interactive, Debug, Default)]
+struct Parent {
+    /// The only child.
+    child: Child,
+}
+
+#[derive(InteractiveRoot, Debug, Default)]
+struct Root {
+    parent: Parent,
+}
+
+/// Increments `a`.
+#[Function]
+fn add_one(a: u32) -> u32 {
+    a + 1
+}
+
+#[test]
+fn test_help_object() {
+    let mut root = Root::default();
+    assert_eq!(
+        root.eval_to_string("help(parent.child)"),
+        "child: Child\n\
+         \n\
+         The only child.\n\
+         \n\
+         Fields:\n  \
+           temperature: f32 - The current temperature in °C.\n  \
+           fahrenheit\n\
+         \n\
+         Methods:\n  \
+           add(a: u8, b: u8) -> u8 - Adds two numbers.\n  \
+           reset()"
+    );
+    assert_eq!(
+        root.eval_to_string("help(parent.child.)"),
+        root.eval_to_string("help(parent.child)").replacen(
+            "child: Child\n\nThe only child.",
+            "Child",
+            1
+        )
+    );
+}
+
+#[test]
+fn test_help_root() {
+    let mut root = Root::default();
+    let help = root.eval_to_string("help");
+    assert!(help.starts_with("Root\n\nFields:\n  parent: Parent\n"));
+    assert!(help.contains("\n\nFunctions:\n  add_one(a: u32) -> u32 - Increments `a`."));
+    assert!(help.contains("\n\nBuilt-in queries:\n  help(path)"));
+    assert_eq!(root.eval_to_string("help()"), help);
+}
+
+#[test]
+fn test_help_members() {
+    let mut root = Root::default();
+    assert_eq!(
+        root.eval_to_string("help(parent.child.add)"),
+        "add(a: u8, b: u8) -> u8\n\nAdds two numbers.\n\nOverflows are not checked."
+    );
+    assert_eq!(
+        root.eval_to_string("help(parent.child.temperature)"),
+        "temperature: f32\n\nThe current temperature in °C.\n\nUpdated once per second."
+    );
+    assert_eq!(
+        root.eval_to_string("help(parent.child.secret)"),
+        "secret: u8"
+    );
+    assert_eq!(
+        root.eval_to_string("help(parent.child.fahrenheit)"),
+        "fahrenheit: f32"
+    );
+    assert_eq!(
+        root.eval_to_string("help(add_one)"),
+        "add_one(a: u32) -> u32\n\nIncrements `a`."
+    );
+}
+
+#[test]
+fn test_type_of() {
+    let mut root = Root::default();
+    assert_eq!(root.eval_to_string("type_of(parent.child)"), "Child");
+    assert_eq!(
+        root.eval_to_string("type_of(parent.child.temperature)"),
+        "f32"
+    );
+    assert_eq!(
+        root.eval_to_string("type_of(parent.child.fahrenheit)"),
+        "f32"
+    );
+    assert_eq!(
+        root.eval_to_string("type_of(parent.child.reset)"),
+        "reset()"
+    );
+    assert_eq!(root.eval_to_string("type_of()"), "Root");
+}
+
+#[test]
+fn test_errors() {
+    let root = Root::default();
+    root.try_eval("help(parent.chld)", |result| {
+        assert_eq!(
+            result.unwrap_err(),
+            InteractiveError::FieldNotFound {
+                type_name: "Parent",
+                field_name: "chld",
+                suggestion: Some("child"),
+            }
+        )
+    });
+    root.try_eval("type_of(parent.child.ad)", |result| {
+        assert_eq!(
+            result.unwrap_err(),
+            InteractiveError::FieldNotFound {
+                type_name: "Child",
+                field_name: "ad",
+                suggestion: Some("add"),
+            }
+        )
+    });
+}
+
+mod shadowed {
+    use rusteval::{InteractiveRoot, Methods};
+
+    #[derive(InteractiveRoot, Debug, Default)]
+    pub struct Root;
+
+    #[Methods]
+    impl Root {
+        fn help(&self) -> &'static str {
+            "custom help"
+        }
+    }
+
+    #[test]
+    fn test_own_help_takes_precedence() {
+        assert_eq!(Root.eval_to_string("help()"), "\"custom help\"");
+        assert_eq!(Root.eval_to_string("type_of()"), "Root");
+    }
+}
+
+mod shadowed_by_field {
+    use rusteval::{InteractiveRoot, Methods};
+
+    #[derive(InteractiveRoot, Debug, Default)]
+    pub struct Root {
+        help: u8,
+    }
+
+    #[Methods]
+    impl Root {
+        #[interactive(property)]
+        fn type_of(&self) -> &'static str {
+            "custom type_of"
+        }
+    }
+
+    #[test]
+    fn test_own_field_takes_precedence() {
+        let mut root = Root { help: 42 };
+        assert_eq!(root.eval_to_string("help"), "42");
+        assert_eq!(root.eval_to_string("type_of"), "\"custom type_of\"");
+        // calls are still built-in, the root has no method `help`
+        assert!(root
+            .eval_to_string("help()")
+            .starts_with("Root\n\nFields:\n  help: u8"));
+    }
+}
+
+mod shadowed_by_hidden_field {
+    use rusteval::InteractiveRoot;
+
+    #[derive(InteractiveRoot, Debug, Default)]
+    pub struct Root {
+        #[interactive(hidden)]
+        tree: u8,
+    }
+
+    #[test]
+    fn test_hidden_field_takes_precedence() {
+        let mut root = Root { tree: 7 };
+        assert_eq!(root.eval_to_string("tree"), "7");
+        assert_eq!(root.eval_to_string("tree(0)"), "Root");
+    }
+}
+
+mod property_type {
+    use std::cell::Cell;
+
+    use rusteval::{Interactive, InteractiveRoot, Methods};
+
+    #[derive(Interactive, Debug, Default)]
+    pub struct Counter {
+        reads: Cell<u32>,
+    }
+
+    #[Methods]
+    impl Counter {
+        /// Counts how often it was read.
+        #[interactive(property)]
+        fn next(&self) -> u32 {
+            self.reads.set(self.reads.get() + 1);
+            self.reads.get()
+        }
+    }
+
+    #[derive(InteractiveRoot, Debug, Default)]
+    pub struct Root {
+        counter: Counter,
+    }
+
+    #[test]
+    fn test_getter_is_not_called() {
+        let mut root = Root::default();
+        assert_eq!(
+            root.eval_to_string("help(counter.next)"),
+            "next: u32\n\nCounts how often it was read."
+        );
+        assert_eq!(root.eval_to_string("type_of(counter.next)"), "u32");
+        assert_eq!(root.counter.reads.get(), 0);
+    }
+}
@@ -1,7 +1,7 @@
 #![no_implicit_prelude]
+#![allow(dead_code)]
 use ::rusteval::{Function, Interactive, InteractiveRoot, Methods, PartialDebug};
 
-
 struct NoDebug;
 
 #[derive(Interactive, PartialDebug)]
@@ -15,11 +15,29 @@ impl ChildStruct {
     fn yes(&mut self, _a: f32, _b: &str, _c: &mut str) -> bool {
         true
     }
+
+    #[interactive(property, display, unit = "V")]
+    fn sum(&self) -> f32 {
+        self.last_sum
+    }
+
+    #[interactive(setter = "sum")]
+    fn set_sum(&mut self, sum: f32) -> ::core::result::Result<(), ::std::string::String> {
+        self.last_sum = sum;
+        ::core::result::Result::Ok(())
+    }
+}
+
+#[derive(Interactive)]
+struct FlattenedStruct {
+    flag: bool,
 }
 
 #[derive(InteractiveRoot)]
 struct Root {
     child: ChildStruct,
+    #[interactive(flatten)]
+    flattened: FlattenedStruct,
 }
 
 #[Function]
@@ -5,7 +5,7 @@ struct TestStruct {
     field: u32,
 }
 
-#[Methods]
+#[Methods(primary)]
 impl TestStruct {
     fn _new() -> Self {
         Self::default()
@@ -57,7 +57,8 @@ fn test_method_not_found() {
             result.unwrap_err(),
             InteractiveError::MethodNotFound {
                 type_name: "TestStruct",
-                method_name: "yeet"
+                method_name: "yeet",
+                suggestion: None,
             }
         )
     });
@@ -81,7 +82,8 @@ fn test_associated_function() {
             result.unwrap_err(),
             InteractiveError::MethodNotFound {
                 type_name: "TestStruct",
-                method_name: "_new"
+                method_name: "_new",
+                suggestion: None,
             }
         )
     });
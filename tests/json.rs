@@ -0,0 +1,167 @@
+#![cfg(feature = "serde")]
+
+use rusteval::{Interactive, InteractiveRoot, Methods};
+use serde::Serialize;
+use serde_json::json;
+
+#[derive(Interactive, Serialize, Debug, Default)]
+struct Sensor {
+    id: u32,
+    name: String,
+    samples: Vec<f32>,
+    #[interactive(unit = "mA")]
+    current: u16,
+}
+
+/// Doesn't implement `Serialize`
+#[derive(Interactive, Debug, Default)]
+struct Opaque {
+    value: u8,
+}
+
+#[Methods]
+impl Sensor {
+    fn sum(&self) -> f32 {
+        self.samples.iter().sum()
+    }
+
+    fn range(&self) -> (f32, f32) {
+        (0.0, 1.5)
+    }
+
+    fn opaque(&self) -> Opaque {
+        Opaque { value: 7 }
+    }
+
+    fn set_current(&mut self, current: u16) {
+        self.current = current;
+    }
+
+    fn read(&self, index: usize) -> Result<f32, String> {
+        self.samples
+            .get(index)
+            .copied()
+            .ok_or_else(|| format!("no sample {}", index))
+    }
+}
+
+#[derive(InteractiveRoot, Default)]
+struct Root {
+    sensor: Sensor,
+    opaque: Opaque,
+}
+
+fn root() -> Root {
+    Root {
+        sensor: Sensor {
+            id: 1,
+            name: "temp".into(),
+            samples: vec![0.5, 1.0],
+            current: 20,
+        },
+        opaque: Opaque::default(),
+    }
+}
+
+#[test]
+fn test_serialize() {
+    let mut root = root();
+    assert_eq!(
+        root.eval_to_json("sensor"),
+        json!({ "id": 1, "name": "temp", "samples": [0.5, 1.0], "current": 20 })
+    );
+    assert_eq!(root.eval_to_json("sensor.name"), json!("temp"));
+    assert_eq!(root.eval_to_json("sensor.sum()"), json!(1.5));
+    assert_eq!(root.eval_to_json("sensor.range()"), json!([0.0, 1.5]));
+}
+
+#[test]
+fn test_format_hints_are_ignored() {
+    let mut root = root();
+    assert_eq!(root.eval_to_string("sensor.current"), "20 mA");
+    assert_eq!(root.eval_to_json("sensor.current"), json!(20));
+}
+
+#[test]
+fn test_debug_fallback() {
+    let mut root = root();
+    assert_eq!(
+        root.eval_to_json("opaque"),
+        json!({ "debug": "Opaque { value: 0 }" })
+    );
+    assert_eq!(
+        root.eval_to_json("sensor.opaque()"),
+        json!({ "debug": "Opaque { value: 7 }" })
+    );
+}
+
+#[test]
+fn test_error() {
+    let mut root = root();
+    assert_eq!(
+        root.eval_to_json("sensor.nothing"),
+        json!({
+            "error": "No field `nothing` found for type `Sensor`",
+            "code": "E0005",
+            "kind": "field_not_found",
+        })
+    );
+    assert_eq!(
+        root.eval_to_json("sensor.sum(1)"),
+        json!({
+            "error": "´sum´ takes 0 arguments but 1 argument was supplied",
+            "code": "E0010",
+            "kind": "wrong_number_of_arguments",
+        })
+    );
+}
+
+#[test]
+fn test_method_result() {
+    let mut root = root();
+    assert_eq!(root.eval_to_json("sensor.read(1)"), json!(1.0));
+    assert_eq!(
+        root.eval_to_json("sensor.read(2)"),
+        json!({
+            "error": "`read` returned an error: no sample 2",
+            "code": "E0013",
+            "kind": "method_returned_error",
+        })
+    );
+}
+
+#[test]
+fn test_arg_parse_error() {
+    let mut root = root();
+    assert_eq!(
+        root.eval_to_json("sensor.set_current(fast)"),
+        json!({
+            "error": "argument 1 `current` of `set_current`: expected u16, found `fast`",
+            "code": "E0012",
+            "kind": "arg_parse_error",
+            "cause": { "code": "E0101", "kind": "parse_int_error" },
+        })
+    );
+}
+
+#[test]
+fn test_write_json() {
+    let mut root = root();
+    let mut buf = Vec::new();
+    root.eval_and_write_json("sensor.samples", &mut buf)
+        .unwrap();
+    assert_eq!(buf, b"[0.5,1.0]");
+}
+
+#[test]
+fn test_serialize_value() {
+    let mut root = root();
+    let value = root.try_eval_value("sensor").unwrap();
+    assert_eq!(
+        serde_json::to_value(&value).unwrap(),
+        root.eval_to_json("sensor")
+    );
+    // `Opaque` doesn't implement `Serialize`, but its interactive fields are known
+    let value = root.try_eval_value("opaque").unwrap();
+    assert_eq!(serde_json::to_value(&value).unwrap(), json!({ "value": 0 }));
+}
@@ -0,0 +1,163 @@
+use rusteval::{Function, Interactive, InteractiveRoot, Methods};
+
+trait Sensor {
+    fn read(&self) -> f32;
+}
+
+#[derive(Interactive, Debug, Default)]
+struct Board {
+    led: bool,
+}
+
+#[Methods]
+impl Board {
+    fn led_on(&mut self) {
+        self.led = true;
+    }
+}
+
+#[Methods]
+impl Board {
+    fn is_led_on(&self) -> bool {
+        self.led
+    }
+}
+
+#[Methods]
+impl Sensor for Board {
+    fn read(&self) -> f32 {
+        4.2
+    }
+}
+
+trait Idle {
+    fn is_idle(&self) -> bool;
+}
+
+#[derive(Debug)]
+enum Mode {
+    Idle,
+}
+
+// enums can't derive `Interactive`, so one of their impl blocks implements `Methods`
+#[Methods(primary)]
+impl Idle for Mode {
+    fn is_idle(&self) -> bool {
+        matches!(self, Mode::Idle)
+    }
+}
+
+#[Methods]
+impl Mode {
+    fn is_busy(&self) -> bool {
+        !self.is_idle()
+    }
+}
+
+#[derive(InteractiveRoot, Debug, Default)]
+struct Root {
+    board: Board,
+}
+
+#[Methods]
+impl Root {
+    fn reset(&mut self) {
+        self.board = Board::default();
+    }
+}
+
+#[Function]
+fn add_one(a: u32) -> u32 {
+    a + 1
+}
+
+#[test]
+fn test_methods_of_all_impl_blocks() {
+    let mut root = Root::default();
+
+    assert_eq!(root.eval_to_string("board.is_led_on()"), "false");
+    assert_eq!(root.eval_to_string("board.led_on()"), "()");
+    assert_eq!(root.eval_to_string("board.is_led_on()"), "true");
+}
+
+#[test]
+fn test_trait_impl_method() {
+    let mut root = Root::default();
+
+    assert_eq!(root.eval_to_string("board.read()"), "4.2");
+}
+
+#[test]
+fn test_all_method_names() {
+    let board = Board::default();
+
+    let mut names = board.get_all_method_names().to_vec();
+    names.sort_unstable();
+    assert_eq!(names, ["is_led_on", "led_on", "read"]);
+}
+
+#[test]
+fn test_mut_method_through_shared_reference() {
+    let board = Board::default();
+
+    board.eval_method("led_on", "", &mut |result| {
+        assert_eq!(
+            result.unwrap_err(),
+            rusteval::InteractiveError::MethodNotFound {
+                type_name: "Board",
+                method_name: "led_on",
+                suggestion: None,
+            }
+        )
+    });
+}
+
+#[test]
+fn test_trait_impl_extended_by_inherent_impl() {
+    let mode = Mode::Idle;
+
+    mode.eval_method("is_idle", "", &mut |result| {
+        assert_eq!(format!("{:?}", result.unwrap()), "true")
+    });
+    mode.eval_method("is_busy", "", &mut |result| {
+        assert_eq!(format!("{:?}", result.unwrap()), "false")
+    });
+    let mut names = mode.get_all_method_names().to_vec();
+    names.sort_unstable();
+    assert_eq!(names, ["is_busy", "is_idle"]);
+}
+
+#[test]
+fn test_root_methods_and_functions() {
+    let mut root = Root::default();
+
+    assert_eq!(root.eval_to_string("board.led_on()"), "()");
+    assert_eq!(root.eval_to_string("reset()"), "()");
+    assert_eq!(root.eval_to_string("board.is_led_on()"), "false");
+    assert_eq!(root.eval_to_string("add_one(41)"), "42");
+
+    let mut names = root.get_all_method_names().to_vec();
+    names.sort_unstable();
+    assert_eq!(names, ["add_one", "reset"]);
+}
+
+/// Implements `Methods` by hand instead of the derive.
+#[derive(Interactive, Debug, Default)]
+#[interactive(manual_methods)]
+struct Manual {
+    value: u8,
+}
+
+impl rusteval::Methods for Manual {
+    fn get_all_method_names(&self) -> &'static [&'static str] {
+        &["manual"]
+    }
+}
+
+#[test]
+fn test_hand_written_methods_impl() {
+    let manual = Manual::default();
+
+    assert_eq!(manual.get_all_method_names(), ["manual"]);
+    assert_eq!(manual.get_all_field_names(), ["value"]);
+}
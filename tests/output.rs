@@ -0,0 +1,142 @@
+use rusteval::{Interactive, InteractiveRoot, Methods, OutputOptions, Radix};
+
+#[derive(Interactive, Debug, Default)]
+struct Sensor {
+    id: u32,
+    name: String,
+    samples: Vec<f32>,
+}
+
+#[Methods]
+impl Sensor {
+    fn scaled(&self, factor: f32) -> f32 {
+        self.samples.iter().sum::<f32>() * factor
+    }
+
+    fn label(&self) -> String {
+        format!("sensor{} v1.5", self.id % 10)
+    }
+}
+
+#[derive(InteractiveRoot, Debug, Default)]
+struct Root {
+    sensor: Sensor,
+}
+
+fn root() -> Root {
+    Root {
+        sensor: Sensor {
+            id: 255,
+            name: "temp 1".into(),
+            samples: vec![0.5, 1.25],
+        },
+    }
+}
+
+#[test]
+fn test_default_options() {
+    let mut root = root();
+    assert_eq!(
+        root.eval_to_string_with("sensor", &OutputOptions::default()),
+        root.eval_to_string("sensor")
+    );
+}
+
+#[test]
+fn test_pretty() {
+    let mut root = root();
+    let options = OutputOptions {
+        pretty: true,
+        ..OutputOptions::default()
+    };
+    assert_eq!(
+        root.eval_to_string_with("sensor", &options),
+        format!("{:#?}", root.sensor)
+    );
+}
+
+#[test]
+fn test_radix_and_precision() {
+    let mut root = root();
+    let options = OutputOptions {
+        radix: Some(Radix::Hex),
+        float_precision: Some(1),
+        ..OutputOptions::default()
+    };
+    assert_eq!(
+        root.eval_to_string_with("sensor", &options),
+        "Sensor { id: 0xFF, name: \"temp 1\", samples: [0.5, 1.2] }"
+    );
+    assert_eq!(
+        root.eval_to_string_with("sensor.scaled(2)", &options),
+        "3.5"
+    );
+}
+
+#[test]
+fn test_radix_and_precision_with_display() {
+    let mut root = root();
+    let options = OutputOptions {
+        radix: Some(Radix::Hex),
+        float_precision: Some(0),
+        prefer_display: true,
+        ..OutputOptions::default()
+    };
+    // digits in text are not numbers
+    assert_eq!(
+        root.eval_to_string_with("sensor.label()", &options),
+        "sensor5 v1.5"
+    );
+    assert_eq!(root.eval_to_string_with("sensor.name", &options), "temp 1");
+    assert_eq!(root.eval_to_string_with("sensor.id", &options), "0xFF");
+    assert_eq!(root.eval_to_string_with("sensor.scaled(2)", &options), "4");
+}
+
+#[test]
+fn test_depth_and_length() {
+    let mut root = root();
+    let options = OutputOptions {
+        max_depth: Some(1),
+        ..OutputOptions::default()
+    };
+    assert_eq!(
+        root.eval_to_string_with("sensor", &options),
+        "Sensor { id: 255, name: \"temp 1\", samples: [..] }"
+    );
+
+    let options = OutputOptions {
+        max_len: Some(8),
+        truncation_marker: " [...]",
+        ..OutputOptions::default()
+    };
+    assert_eq!(
+        root.eval_to_string_with("sensor", &options),
+        "Sensor { [...]"
+    );
+}
+
+#[test]
+fn test_errors_are_unchanged() {
+    let mut root = root();
+    let options = OutputOptions {
+        max_len: Some(3),
+        ..OutputOptions::default()
+    };
+    assert_eq!(
+        root.eval_to_string_with("sensor.nothing", &options),
+        "No field `nothing` found for type `Sensor`"
+    );
+}
+
+#[test]
+fn test_eval_and_write_with() {
+    let mut root = root();
+    let mut buf = String::new();
+    let options = OutputOptions {
+        radix: Some(Radix::Binary),
+        ..OutputOptions::default()
+    };
+    root.eval_and_write_with("sensor.id", &mut buf, &options)
+        .unwrap();
+    assert_eq!(buf, "0b11111111");
+}
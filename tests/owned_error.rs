@@ -0,0 +1,121 @@
+use rusteval::{
+    ErrorKind, Interactive, InteractiveError, InteractiveRoot, Methods, OwnedInteractiveError,
+};
+
+#[derive(Interactive, Debug, Default)]
+struct Child {
+    value: u8,
+}
+
+#[Methods]
+impl Child {
+    fn add(&self, a: u8, b: u8) -> u8 {
+        a + b
+    }
+}
+
+#[derive(Interactive, Debug, Default)]
+struct Parent {
+    child: Child,
+}
+
+#[derive(InteractiveRoot, Debug, Default)]
+struct Root {
+    parent: Parent,
+}
+
+#[test]
+fn test_from() {
+    let error = OwnedInteractiveError::from(InteractiveError::FunctionNotFound {
+        function_name: "foo",
+        suggestion: None,
+    });
+    assert_eq!(error.kind(), ErrorKind::FunctionNotFound);
+    assert_eq!(error.message(), "No function named `foo` found");
+    assert_eq!(error.to_string(), "No function named `foo` found");
+    assert_eq!(error.query(), None);
+    assert!(error.type_path().is_empty());
+}
+
+#[test]
+fn test_type_path() {
+    let mut root = Root::default();
+
+    let error = root.eval_as::<u8>("parent.child.nothing").unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::FieldNotFound);
+    assert_eq!(error.query(), Some("parent.child.nothing"));
+    assert_eq!(error.type_path(), &["Root", "Parent", "Child"]);
+
+    let error = root.eval_as::<u8>("parent.nothing.value").unwrap_err();
+    assert_eq!(error.query(), Some("parent.nothing.value"));
+    assert_eq!(error.type_path(), &["Root", "Parent"]);
+
+    let error = root.try_eval_value("nothing").unwrap_err();
+    assert_eq!(error.type_path(), &["Root"]);
+}
+
+#[test]
+fn test_call_method_context() {
+    let mut root = Root::default();
+
+    let error = root
+        .call_method::<u8>("parent.child", "add", (1u16, 2u16))
+        .unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::WrongArgumentTypes);
+    assert_eq!(error.query(), Some("parent.child.add"));
+    assert_eq!(error.type_path(), &["Root", "Parent", "Child"]);
+
+    let error = root.call_function::<u8>("nothing", ()).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::FunctionNotFound);
+    assert_eq!(error.query(), Some("nothing"));
+    assert_eq!(error.type_path(), &["Root"]);
+}
+
+#[test]
+fn test_std_error() {
+    fn eval(root: &mut Root, query: &str) -> Result<u8, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(root.eval_as(query)?)
+    }
+
+    let mut root = Root::default();
+    assert_eq!(eval(&mut root, "parent.child.value").unwrap(), 0);
+
+    let handle = std::thread::spawn(move || eval(&mut root, "parent.child.vlue"));
+    let error = handle.join().unwrap().unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "No field `vlue` found for type `Child`, did you mean `value`?"
+    );
+}
+
+#[test]
+fn test_kind() {
+    let error = InteractiveError::TypeMismatch {
+        expected: "u8",
+        found: "f32",
+    };
+    assert_eq!(error.kind(), ErrorKind::TypeMismatch);
+    let _: &dyn core::error::Error = &error;
+}
+
+#[test]
+fn test_codes() {
+    let mut root = Root::default();
+
+    let error = root.eval_as::<u8>("parent.nothing").unwrap_err();
+    assert_eq!(error.kind().code(), "E0005");
+    assert_eq!(error.kind().name(), "field_not_found");
+
+    root.try_eval_mut("parent.child.add(1, x)", |result| {
+        let error = result.unwrap_err();
+        assert_eq!(error.code(), "E0012");
+        assert_eq!(error.kind().name(), "arg_parse_error");
+        match error {
+            InteractiveError::ArgParseError { error, .. } => {
+                assert_eq!(error.code(), "E0101");
+                assert_eq!(error.name(), "parse_int_error");
+            }
+            _ => unreachable!(),
+        }
+    });
+}
@@ -0,0 +1,145 @@
+use rusteval::{Interactive, InteractiveError, InteractiveRoot, Methods};
+
+#[derive(Interactive, Debug, Default)]
+struct Motor {
+    rpm: u32,
+}
+
+#[Methods]
+impl Motor {
+    fn spin(&mut self, rpm: u32) -> u32 {
+        if rpm >= 1000 {
+            panic!("{} rpm is too fast", rpm);
+        }
+        self.rpm = rpm;
+        rpm
+    }
+
+    fn stall(&self) {
+        std::panic::panic_any(42);
+    }
+
+    fn check(&self) -> u32 {
+        if self.rpm > 0 {
+            panic!("motor is spinning");
+        }
+        self.rpm
+    }
+}
+
+#[derive(InteractiveRoot, Debug, Default)]
+#[interactive(catch_panics)]
+struct Root {
+    motor: Motor,
+}
+
+#[derive(InteractiveRoot, Debug, Default)]
+struct UncaughtRoot {
+    motor: Motor,
+}
+
+fn ignore_panics(call: &mut dyn FnMut(), on_panic: &mut dyn FnMut(&str)) {
+    if std::panic::catch_unwind(std::panic::AssertUnwindSafe(call)).is_err() {
+        on_panic("ignored")
+    }
+}
+
+#[derive(InteractiveRoot, Debug, Default)]
+#[interactive(panic_handler = "ignore_panics")]
+struct CustomRoot {
+    motor: Motor,
+}
+
+#[test]
+fn test_catch_panic() {
+    let mut root = Root::default();
+
+    root.try_eval_mut("motor.spin(2000)", |result| {
+        assert_eq!(
+            result.unwrap_err(),
+            InteractiveError::Panicked {
+                query: "motor.spin(2000)",
+                message: "2000 rpm is too fast",
+            }
+        )
+    });
+    assert_eq!(root.eval_to_string("motor.spin(500)"), "500");
+    assert_eq!(root.eval_to_string("motor.rpm"), "500");
+}
+
+#[test]
+fn test_non_string_payload() {
+    let mut root = Root::default();
+
+    assert_eq!(
+        root.eval_to_string("motor.stall()"),
+        "`motor.stall()` panicked: Box<dyn Any>"
+    );
+}
+
+#[test]
+fn test_custom_panic_handler() {
+    let mut root = CustomRoot::default();
+
+    assert_eq!(
+        root.eval_to_string("motor.spin(2000)"),
+        "`motor.spin(2000)` panicked: ignored"
+    );
+}
+
+#[test]
+#[should_panic(expected = "2000 rpm is too fast")]
+fn test_uncaught_panic() {
+    let mut root = UncaughtRoot::default();
+    root.eval_to_string("motor.spin(2000)");
+}
+
+#[test]
+#[should_panic(expected = "closure panicked")]
+fn test_panic_in_closure() {
+    let mut root = Root::default();
+    root.try_eval_mut("motor.spin(5)", |_| panic!("closure panicked"));
+}
+
+#[test]
+fn test_catch_panic_immutably() {
+    let root = Root {
+        motor: Motor { rpm: 5 },
+    };
+
+    root.try_eval("motor.check()", |result| {
+        assert_eq!(
+            result.unwrap_err(),
+            InteractiveError::Panicked {
+                query: "motor.check()",
+                message: "motor is spinning",
+            }
+        )
+    });
+
+    let mut matches = Vec::new();
+    root.try_eval_matches("*.check()", |path, result| {
+        matches.push(format!("{}: {}", path, result.unwrap_err()))
+    });
+    assert_eq!(
+        matches,
+        ["*.check(): `*.check()` panicked: motor is spinning"]
+    );
+}
+
+#[test]
+fn test_panic_in_closure_keeps_payload() {
+    let root = Root::default();
+    let payload = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        root.try_eval("motor.check()", |_| std::panic::panic_any(7_u8))
+    }))
+    .unwrap_err();
+    assert_eq!(payload.downcast_ref::<u8>(), Some(&7));
+}
+
+#[test]
+#[should_panic(expected = "closure panicked")]
+fn test_panic_in_immutable_closure() {
+    let root = CustomRoot::default();
+    root.try_eval("motor.rpm", |_| panic!("closure panicked"));
+}
@@ -0,0 +1,128 @@
+use rusteval::{Interactive, InteractiveRoot, Methods};
+
+#[derive(Interactive, Default)]
+struct Cpu {
+    busy_cycles: u32,
+    total_cycles: u32,
+    limit: f32,
+}
+
+#[Methods]
+impl Cpu {
+    #[interactive(property)]
+    fn utilization(&self) -> f32 {
+        self.busy_cycles as f32 / self.total_cycles as f32
+    }
+
+    #[interactive(property, rename = "max")]
+    fn max_utilization(&self) -> f32 {
+        self.limit
+    }
+
+    #[interactive(setter = "max")]
+    fn set_max_utilization(&mut self, limit: f32) {
+        self.limit = limit;
+    }
+
+    #[interactive(property, hidden)]
+    fn cycles(&self) -> u32 {
+        self.total_cycles
+    }
+}
+
+#[derive(Interactive, Default)]
+struct Generic<T> {
+    value: T,
+}
+
+#[Methods]
+impl<T: Clone> Generic<T> {
+    #[interactive(property)]
+    fn copy(&self) -> T {
+        self.value.clone()
+    }
+}
+
+#[derive(InteractiveRoot, Default)]
+struct Root {
+    cpu: Cpu,
+    #[interactive(flatten)]
+    flattened: Cpu,
+    generic: Generic<u8>,
+}
+
+fn root() -> Root {
+    let mut root = Root::default();
+    root.cpu.busy_cycles = 1;
+    root.cpu.total_cycles = 4;
+    root
+}
+
+#[test]
+fn test_property() {
+    let mut root = root();
+    assert_eq!(root.eval_to_string("cpu.utilization"), "0.25");
+    assert_eq!(root.eval_to_string("cpu.max"), "0.0");
+    assert_eq!(root.eval_to_string("cpu.cycles"), "4");
+    assert_eq!(root.eval_to_string("generic.copy"), "0");
+}
+
+#[test]
+fn test_property_names() {
+    let root = root();
+    assert_eq!(
+        root.cpu.get_all_field_names(),
+        ["busy_cycles", "total_cycles", "limit", "utilization", "max"]
+    );
+    assert_eq!(root.cpu.get_all_method_names(), ["set_max_utilization"]);
+}
+
+#[test]
+fn test_setter() {
+    let mut root = root();
+    assert_eq!(root.eval_to_string("cpu.max = 0.5"), "()");
+    assert_eq!(root.eval_to_string("cpu.max"), "0.5");
+    assert_eq!(root.cpu.limit, 0.5);
+}
+
+#[test]
+fn test_setter_errors() {
+    let mut root = root();
+    assert_eq!(
+        root.eval_to_string("cpu.utilization = 1.0"),
+        "Property `utilization` is not writable"
+    );
+    assert_eq!(
+        root.eval_to_string("cpu.nothing = 1.0"),
+        "No field `nothing` found for type `Cpu`"
+    );
+    assert_eq!(
+        root.eval_to_string("cpu.max = fast"),
+        "argument 1 `limit` of `set_max_utilization`: expected f32, found `fast`"
+    );
+}
+
+#[test]
+fn test_setter_needs_mutable_access() {
+    let root = root();
+    root.try_eval("cpu.max = 0.5", |result| {
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "`max` requires mutable access"
+        )
+    });
+    root.try_eval("cpu.utilization = 0.5", |result| {
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Property `utilization` is not writable"
+        )
+    });
+}
+
+#[test]
+fn test_flattened_property() {
+    let mut root = root();
+    assert_eq!(root.eval_to_string("max = 0.75"), "()");
+    assert_eq!(root.eval_to_string("max"), "0.75");
+    assert_eq!(root.flattened.limit, 0.75);
+}
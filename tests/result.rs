@@ -0,0 +1,206 @@
+use std::fmt::{self, Display, Formatter};
+
+use rusteval::{
+    Function, Interactive, InteractiveError, InteractiveRoot, MethodResult, Methods, OutputOptions,
+    Value,
+};
+
+#[allow(dead_code)]
+#[derive(Debug)]
+struct DebugError {
+    code: u8,
+}
+
+#[derive(Debug)]
+struct DisplayError;
+
+impl Display for DisplayError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "something went wrong")
+    }
+}
+
+#[derive(Interactive, Debug, Default)]
+struct Device {
+    limit: u8,
+}
+
+#[Methods]
+impl Device {
+    fn check(&self, value: u8) -> Result<u8, DebugError> {
+        if value <= self.limit {
+            Ok(value)
+        } else {
+            Err(DebugError { code: value })
+        }
+    }
+
+    fn fail(&self) -> Result<(), DisplayError> {
+        Err(DisplayError)
+    }
+
+    #[interactive(format = "hex")]
+    fn status(&self) -> std::io::Result<u8> {
+        Ok(0xA4)
+    }
+
+    #[interactive(property)]
+    fn limit_checked(&self) -> Result<u8, DisplayError> {
+        Err(DisplayError)
+    }
+
+    #[interactive(setter = "limit_checked")]
+    fn set_limit(&mut self, limit: u8) -> Result<(), String> {
+        if limit > 10 {
+            return Err(format!("{} is too high", limit));
+        }
+        self.limit = limit;
+        Ok(())
+    }
+}
+
+#[derive(InteractiveRoot, Debug, Default)]
+struct Root {
+    device: Device,
+}
+
+#[Function]
+fn parse_number(s: &str) -> Result<u32, std::num::ParseIntError> {
+    s.parse()
+}
+
+fn keep_results() -> OutputOptions {
+    OutputOptions {
+        keep_results: true,
+        ..OutputOptions::default()
+    }
+}
+
+fn error_of(root: &Root, method_name: &str, args: &str) -> String {
+    let mut error = String::new();
+    root.device
+        .eval_method_output(method_name, args, &mut |result| {
+            let method_result = result.map(|value| value.as_method_result());
+            match method_result {
+                Ok(Some(MethodResult {
+                    method_name,
+                    result: Err(e),
+                    ..
+                })) => error = format!("{}: {:?}", method_name, e),
+                _ => panic!("expected an Err returned by a method"),
+            }
+        });
+    error
+}
+
+#[test]
+fn test_ok_value() {
+    let mut root = Root::default();
+    root.device.limit = 5;
+    assert_eq!(root.eval_to_string("device.check(3)"), "3");
+    assert_eq!(root.eval_to_string("device.status()"), "0xA4");
+    assert_eq!(root.eval_as::<u8>("device.check(3)"), Ok(3));
+    assert_eq!(root.try_eval_value("device.check(3)"), Ok(Value::UInt(3)));
+    assert_eq!(root.call_method::<u8>("device", "check", (3u8,)), Ok(3));
+    root.try_eval("device.check(3)", |result| {
+        assert_eq!(format!("{:?}", result.unwrap()), "3")
+    });
+}
+
+#[test]
+fn test_keep_results() {
+    let mut root = Root::default();
+    root.device.limit = 5;
+    let options = keep_results();
+    assert_eq!(
+        root.eval_to_string_with("device.check(3)", &options),
+        "Ok(3)"
+    );
+    assert_eq!(
+        root.eval_to_string_with("device.check(7)", &options),
+        "Err(DebugError { code: 7 })"
+    );
+    assert_eq!(
+        root.eval_to_string_with("device.status()", &options),
+        "Ok(0xA4)"
+    );
+}
+
+#[test]
+fn test_debug_error() {
+    let mut root = Root::default();
+    assert_eq!(
+        error_of(&root, "check", "7"),
+        "check: DebugError { code: 7 }"
+    );
+    assert_eq!(
+        root.eval_to_string("device.check(7)"),
+        "`check` returned an error: DebugError { code: 7 }"
+    );
+    root.try_eval("device.check(7)", |result| {
+        assert_eq!(
+            result.unwrap_err(),
+            InteractiveError::MethodReturnedError {
+                method_name: "check",
+                error: "DebugError { code: 7 }"
+            }
+        )
+    });
+}
+
+#[test]
+fn test_display_error() {
+    let mut root = Root::default();
+    assert_eq!(error_of(&root, "fail", ""), "fail: DisplayError");
+    assert_eq!(
+        root.eval_to_string("device.fail()"),
+        "`fail` returned an error: something went wrong"
+    );
+    assert_eq!(
+        root.eval_as::<()>("device.fail()").unwrap_err().to_string(),
+        "`fail` returned an error: something went wrong"
+    );
+}
+
+#[test]
+fn test_property_and_setter() {
+    let mut root = Root::default();
+    assert_eq!(
+        root.eval_to_string("device.limit_checked"),
+        "`limit_checked` returned an error: something went wrong"
+    );
+    assert_eq!(
+        root.eval_to_string("device.limit_checked = 11"),
+        "`set_limit` returned an error: 11 is too high"
+    );
+    assert_eq!(root.eval_to_string("device.limit_checked = 4"), "()");
+    assert_eq!(root.device.limit, 4);
+}
+
+#[test]
+fn test_function() {
+    let mut root = Root::default();
+    assert_eq!(root.eval_to_string("parse_number(\"12\")"), "12");
+    assert_eq!(
+        root.eval_to_string_with("parse_number(\"12\")", &keep_results()),
+        "Ok(12)"
+    );
+    assert_eq!(
+        root.eval_to_string("parse_number(\"x\")"),
+        "`parse_number` returned an error: invalid digit found in string"
+    );
+}
+
+#[test]
+fn test_matches() {
+    let mut root = Root::default();
+    root.device.limit = 5;
+    assert_eq!(
+        root.eval_matches("*.check(3)"),
+        Ok(vec![("device.check(3)".to_string(), Value::UInt(3))])
+    );
+    assert_eq!(
+        root.eval_to_string("*.check(7)"),
+        "device.check(7): `check` returned an error: DebugError { code: 7 }"
+    );
+}
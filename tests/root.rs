@@ -66,10 +66,7 @@ fn test_get_child_field() {
 #[test]
 fn test_call_child_method() {
     let mut root = Root::default();
-    assert_eq!(
-        root.eval_to_string("parent.child.try_ping()"),
-        "Ok(\"pong\")"
-    );
+    assert_eq!(root.eval_to_string("parent.child.try_ping()"), "\"pong\"");
 }
 
 #[test]
@@ -98,10 +95,18 @@ fn test_call_with_bad_args() {
             "{}",
             InteractiveError::ArgParseError {
                 method_name: "add",
+                arg_index: 0,
+                arg_name: "a",
+                expected: "f32",
+                found: "nope",
                 error: ArgParseError::ParseFloatError("nope".parse::<f32>().unwrap_err())
             }
         )
     );
+    assert_eq!(
+        root.eval_to_string("parent.child.add(1, nope)"),
+        "argument 2 `b` of `add`: expected f32, found `nope`"
+    );
 }
 
 #[test]
@@ -143,7 +148,8 @@ fn test_shared_reference_mut_method() {
             "{}",
             InteractiveError::MethodNotFound {
                 type_name: "TestStruct",
-                method_name: "toggle"
+                method_name: "toggle",
+                suggestion: None,
             }
         )
     );
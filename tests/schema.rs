@@ -0,0 +1,204 @@
+use rusteval::{Function, Interactive, InteractiveRoot, Methods, Schema};
+
+#[derive(Interactive, Debug, Default)]
+struct Sensor {
+    /// The last measured value.
+    value: f32,
+    #[interactive(skip)]
+    #[allow(dead_code)]
+    calibration: f32,
+}
+
+#[Methods]
+impl Sensor {
+    /// Measures again.
+    fn measure(&mut self) -> f32 {
+        self.value
+    }
+
+    #[interactive(hidden)]
+    fn secret(&self) {}
+
+    #[interactive(property)]
+    fn doubled(&self) -> f32 {
+        self.value * 2.0
+    }
+
+    #[interactive(setter = "doubled")]
+    fn set_doubled(&mut self, doubled: f32) {
+        self.value = doubled / 2.0;
+    }
+
+    #[interactive(property)]
+    fn halved(&self) -> f32 {
+        self.value / 2.0
+    }
+}
+
+#[derive(Interactive, Debug, Default)]
+struct Board {
+    #[interactive(readonly)]
+    sensor: Sensor,
+}
+
+#[derive(InteractiveRoot, Debug, Default)]
+struct Root {
+    first: Board,
+    second: Box<Board>,
+    spare: Sensor,
+    count: u8,
+}
+
+/// Adds one.
+#[Function]
+fn add_one(a: u32) -> u32 {
+    a + 1
+}
+
+#[test]
+fn test_types() {
+    let schema = Root::default().schema();
+
+    assert_eq!(schema.version, Schema::VERSION);
+    assert_eq!(schema.root, "schema::Root");
+    // every type is described once, in the order they are found
+    let names: Vec<_> = schema.types.iter().map(|schema| schema.name).collect();
+    assert_eq!(names, ["Root", "Board", "Sensor"]);
+    let paths: Vec<_> = schema.types.iter().map(|schema| schema.path).collect();
+    assert_eq!(paths, ["schema::Root", "schema::Board", "schema::Sensor"]);
+}
+
+mod other {
+    use rusteval::Interactive;
+
+    /// Has the same name as `super::Sensor`, but different fields.
+    #[derive(Interactive, Debug, Default)]
+    pub struct Sensor {
+        pub raw: u16,
+    }
+}
+
+#[derive(InteractiveRoot, Debug, Default)]
+struct TwoSensors {
+    sensor: Sensor,
+    other: other::Sensor,
+}
+
+#[test]
+fn test_same_name() {
+    let schema = TwoSensors::default().schema();
+
+    let paths: Vec<_> = schema.types.iter().map(|schema| schema.path).collect();
+    assert_eq!(
+        paths,
+        [
+            "schema::TwoSensors",
+            "schema::Sensor",
+            "schema::other::Sensor"
+        ]
+    );
+
+    let root = schema.get_type("TwoSensors").unwrap();
+    assert_eq!(root.fields[0].schema, Some("schema::Sensor"));
+    assert_eq!(root.fields[1].schema, Some("schema::other::Sensor"));
+
+    let other = schema.get_type("schema::other::Sensor").unwrap();
+    assert_eq!(other.name, "Sensor");
+    assert_eq!(other.fields[0].name, "raw");
+    // a name matches the first type found
+    assert_eq!(schema.get_type("Sensor").unwrap().path, "schema::Sensor");
+}
+
+#[test]
+fn test_fields() {
+    let schema = Root::default().schema();
+
+    let root = schema.get_type("Root").unwrap();
+    let fields: Vec<_> = root.fields.iter().map(|field| field.name).collect();
+    assert_eq!(fields, ["first", "second", "spare", "count"]);
+    assert_eq!(root.fields[1].type_name, Some("Box<Board>"));
+    assert_eq!(root.fields[1].schema, Some("schema::Board"));
+    assert_eq!(root.fields[3].schema, None);
+
+    let board = schema.get_type("Board").unwrap();
+    assert!(!board.fields[0].mutable);
+
+    let sensor = schema.get_type("Sensor").unwrap();
+    let value = &sensor.fields[0];
+    assert_eq!(value.name, "value");
+    assert_eq!(value.type_name, Some("f32"));
+    assert!(value.mutable);
+    assert_eq!(value.docs, "The last measured value.");
+
+    // properties have no type information
+    let doubled = &sensor.fields[1];
+    assert_eq!(doubled.name, "doubled");
+    assert_eq!(doubled.type_name, None);
+    // properties with a setter can be assigned to
+    assert!(doubled.mutable);
+    assert!(!sensor.fields[2].mutable);
+    assert_eq!(sensor.fields.len(), 3);
+}
+
+#[test]
+fn test_methods_and_functions() {
+    let schema = Root::default().schema();
+
+    let sensor = schema.get_type("Sensor").unwrap();
+    let methods: Vec<_> = sensor.methods.iter().map(|m| m.to_string()).collect();
+    assert_eq!(methods, ["measure() -> f32", "set_doubled(doubled: f32)"]);
+
+    // functions are not listed as methods of the root
+    assert!(schema.get_type("Root").unwrap().methods.is_empty());
+
+    let function = schema
+        .functions
+        .iter()
+        .find(|function| function.signature.name == "add_one")
+        .unwrap();
+    assert_eq!(function.module_path, "schema");
+    assert_eq!(function.signature.to_string(), "add_one(a: u32) -> u32");
+    assert_eq!(function.signature.docs, "Adds one.");
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_json() {
+    use serde_json::json;
+
+    let json = serde_json::to_value(Root::default().schema()).unwrap();
+
+    assert_eq!(json["version"], json!(Schema::VERSION));
+    assert_eq!(json["root"], json!("schema::Root"));
+    assert_eq!(json["types"]["schema::Sensor"]["name"], json!("Sensor"));
+    assert_eq!(
+        json["types"]["schema::Sensor"]["fields"][0],
+        json!({
+            "name": "value",
+            "type": "f32",
+            "mutable": true,
+            "schema": null,
+            "docs": "The last measured value.",
+        })
+    );
+    assert_eq!(
+        json["types"]["schema::Sensor"]["methods"][0],
+        json!({
+            "name": "measure",
+            "receiver": "&mut self",
+            "params": [],
+            "return_type": "f32",
+            "docs": "Measures again.",
+        })
+    );
+    assert_eq!(
+        json["functions"][0],
+        json!({
+            "module": "schema",
+            "name": "add_one",
+            "params": [{ "name": "a", "type": "u32" }],
+            "return_type": "u32",
+            "docs": "Adds one.",
+        })
+    );
+}
@@ -0,0 +1,125 @@
+use rusteval::{Function, Interactive, InteractiveRoot, Methods, Parameter, Receiver, Signature};
+
+#[derive(Interactive, Debug, Default, Clone, Copy)]
+struct Child {
+    id: u8,
+}
+
+#[Methods]
+impl Child {
+    /// Adds two numbers.
+    ///
+    /// Returns their sum.
+    fn add(&self, a: f32, b: f32) -> f32 {
+        a + b
+    }
+
+    fn rename(&mut self, name: &str) {
+        self.id = name.len() as u8;
+    }
+
+    #[interactive(rename = "consume")]
+    fn by_value(self) -> Vec<u8> {
+        Vec::new()
+    }
+
+    #[interactive(hidden)]
+    fn secret(&self) {}
+
+    #[interactive(property)]
+    fn length(&self) -> usize {
+        self.id.into()
+    }
+}
+
+#[derive(Interactive, Debug, Default)]
+struct Generic<T> {
+    value: T,
+}
+
+#[Methods]
+impl<T: Clone + 'static> Generic<T> {
+    /// Returns the value.
+    fn get(&self) -> T {
+        self.value.clone()
+    }
+}
+
+#[derive(Interactive, Debug, Default)]
+struct Parent {
+    #[interactive(flatten)]
+    flat: Generic<u8>,
+}
+
+#[Methods]
+impl Parent {}
+
+#[derive(InteractiveRoot, Debug, Default)]
+struct Root {
+    parent: Parent,
+}
+
+/// Increments `a`.
+#[Function]
+fn add_one(a: u32) -> u32 {
+    a + 1
+}
+
+#[test]
+fn test_method_signatures() {
+    let child = Child::default();
+    let signatures = child.get_all_method_signatures();
+    assert_eq!(
+        signatures.iter().map(|s| s.name).collect::<Vec<_>>(),
+        child.get_all_method_names()
+    );
+
+    assert_eq!(
+        child.get_method_signature("add"),
+        Some(&Signature {
+            name: "add",
+            receiver: Some(Receiver::Ref),
+            params: &[
+                Parameter {
+                    name: "a",
+                    type_name: "f32"
+                },
+                Parameter {
+                    name: "b",
+                    type_name: "f32"
+                },
+            ],
+            return_type: Some("f32"),
+            docs: "Adds two numbers.\n\nReturns their sum.",
+        })
+    );
+
+    let rename = child.get_method_signature("rename").unwrap();
+    assert_eq!(rename.receiver, Some(Receiver::RefMut));
+    assert_eq!(rename.to_string(), "rename(name: &str)");
+    assert_eq!(rename.docs, "");
+
+    let consume = child.get_method_signature("consume").unwrap();
+    assert_eq!(consume.receiver, Some(Receiver::Value));
+    assert_eq!(consume.to_string(), "consume() -> Vec<u8>");
+
+    assert_eq!(child.get_method_signature("secret"), None);
+    assert_eq!(child.get_method_signature("length"), None);
+}
+
+#[test]
+fn test_flattened_signatures() {
+    let parent = Parent::default();
+    let get = parent.get_method_signature("get").unwrap();
+    assert_eq!(get.to_string(), "get() -> T");
+    assert_eq!(get.docs, "Returns the value.");
+}
+
+#[test]
+fn test_function_signatures() {
+    let root = Root::default();
+    let add_one = root.get_method_signature("add_one").unwrap();
+    assert_eq!(add_one.receiver, None);
+    assert_eq!(add_one.to_string(), "add_one(a: u32) -> u32");
+    assert_eq!(add_one.docs, "Increments `a`.");
+}
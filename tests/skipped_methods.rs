@@ -0,0 +1,87 @@
+// the methods are skipped on purpose, which is reported as a `deprecated` warning
+#![allow(deprecated)]
+
+use rusteval::{Interactive, InteractiveError, Methods, SkippedMethod};
+
+#[derive(Interactive, Default)]
+struct Device {
+    buffer: Vec<u8>,
+}
+
+#[Methods]
+impl Device {
+    fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    fn write(&mut self, data: &[u8]) {
+        self.buffer.extend_from_slice(data);
+    }
+
+    fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[derive(Interactive, Default)]
+struct GenericDevice<T> {
+    inner: T,
+}
+
+#[Methods]
+impl<T> GenericDevice<T> {
+    fn replace(&mut self, inner: T) -> T {
+        core::mem::replace(&mut self.inner, inner)
+    }
+}
+
+#[test]
+fn test_skipped_methods() {
+    let device = Device::new();
+
+    assert_eq!(device.get_all_method_names(), ["len"]);
+    assert_eq!(
+        device.get_all_skipped_methods(),
+        [SkippedMethod {
+            method_name: "write",
+            reason: "parameter `data` has unsupported type `&[u8]`"
+        }]
+    );
+}
+
+#[test]
+fn test_skipped_method_not_found() {
+    let mut device = Device::new();
+
+    device.eval_method_mut("write", "", &mut |result| {
+        assert_eq!(
+            result.unwrap_err(),
+            InteractiveError::MethodNotFound {
+                type_name: "Device",
+                method_name: "write",
+                suggestion: None,
+            }
+        )
+    });
+}
+
+#[test]
+fn test_skipped_method_still_callable_from_rust() {
+    let mut device = Device::new();
+    device.write(&[1, 2, 3]);
+
+    device.eval_method("len", "", &mut |result| {
+        assert_eq!(format!("{:?}", result.unwrap()), "3")
+    });
+}
+
+#[test]
+fn test_skipped_generic_methods() {
+    let mut device = GenericDevice { inner: 0u8 };
+    assert_eq!(device.replace(1), 0);
+
+    assert_eq!(
+        device.get_all_skipped_methods()[0].to_string(),
+        "`replace` was skipped: parameter `inner` has unsupported type `T`"
+    );
+}
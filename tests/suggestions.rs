@@ -0,0 +1,115 @@
+use rusteval::{Function, Interactive, InteractiveError, InteractiveRoot, Methods};
+
+#[derive(Interactive, Debug, Default)]
+struct Location {
+    altitude: f32,
+}
+
+#[derive(Interactive, Debug, Default)]
+struct Sensor {
+    temperature: f32,
+    humidity: f32,
+    #[interactive(flatten)]
+    location: Location,
+}
+
+#[Methods]
+impl Sensor {
+    fn calibrate(&mut self) {}
+
+    #[allow(non_snake_case)]
+    fn readValue(&self) -> f32 {
+        self.temperature
+    }
+
+    #[interactive(property)]
+    fn dew_point(&self) -> f32 {
+        0.0
+    }
+}
+
+#[derive(InteractiveRoot, Debug, Default)]
+struct Root {
+    sensor: Sensor,
+}
+
+#[Function]
+fn reset_all() {}
+
+fn error_of(root: &mut Root, query: &str) -> String {
+    let mut error = String::new();
+    root.try_eval_mut(query, |result| error = result.unwrap_err().to_string());
+    error
+}
+
+#[test]
+fn test_field_suggestion() {
+    let mut root = Root::default();
+
+    assert_eq!(
+        error_of(&mut root, "sensor.temprature"),
+        "No field `temprature` found for type `Sensor`, did you mean `temperature`?"
+    );
+    assert_eq!(
+        error_of(&mut root, "sensr.humidity"),
+        "No field `sensr` found for type `Root`, did you mean `sensor`?"
+    );
+    assert_eq!(
+        error_of(&mut root, "sensor.altitud"),
+        "No field `altitud` found for type `Sensor`, did you mean `altitude`?"
+    );
+    assert_eq!(
+        error_of(&mut root, "sensor.dewpoint"),
+        "No field `dewpoint` found for type `Sensor`, did you mean `dew_point`?"
+    );
+    assert_eq!(
+        error_of(&mut root, "sensor.pressure"),
+        "No field `pressure` found for type `Sensor`"
+    );
+}
+
+#[test]
+fn test_method_suggestion() {
+    let mut root = Root::default();
+
+    assert_eq!(
+        error_of(&mut root, "sensor.calibrat()"),
+        "No method named `calibrat` found for type `Sensor`, did you mean `calibrate`?"
+    );
+    root.try_eval_mut("sensor.readvalue()", |result| {
+        assert_eq!(
+            result.unwrap_err(),
+            InteractiveError::MethodNotFound {
+                type_name: "Sensor",
+                method_name: "readvalue",
+                suggestion: Some("readValue"),
+            }
+        )
+    });
+}
+
+#[test]
+fn test_function_suggestion() {
+    let mut root = Root::default();
+
+    assert_eq!(
+        error_of(&mut root, "reset_al()"),
+        "No function named `reset_al` found, did you mean `reset_all`?"
+    );
+}
+
+#[test]
+fn test_no_suggestion_for_inaccessible_method() {
+    let root = Root::default();
+
+    root.try_eval("sensor.calibrate()", |result| {
+        assert_eq!(
+            result.unwrap_err(),
+            InteractiveError::MethodNotFound {
+                type_name: "Sensor",
+                method_name: "calibrate",
+                suggestion: None,
+            }
+        )
+    });
+}
@@ -0,0 +1,168 @@
+use std::fmt::Debug;
+use std::rc::Rc;
+
+use rusteval::{did_you_mean, Interactive, InteractiveError, InteractiveRoot, Result};
+
+#[derive(Interactive, Debug, Default)]
+struct Sensor {
+    value: f32,
+    #[interactive(hidden)]
+    secret: u8,
+    #[interactive(skip)]
+    #[allow(dead_code)]
+    calibration: f32,
+}
+
+#[derive(Interactive, Debug, Default)]
+struct Board {
+    sensor: Sensor,
+    shared: Rc<Sensor>,
+    name: &'static str,
+}
+
+/// Contains itself as the field `me`.
+#[derive(Debug, Default)]
+struct Mirror {
+    value: u8,
+}
+
+impl Interactive for Mirror {
+    fn get_field<'a>(&'a self, field_name: &'a str) -> Result<'a, &'a dyn Interactive> {
+        match field_name {
+            "me" => Ok(self),
+            _ => Err(InteractiveError::FieldNotFound {
+                type_name: "Mirror",
+                field_name,
+                suggestion: did_you_mean(field_name, self.get_all_field_names()),
+            }),
+        }
+    }
+
+    fn eval_field(&self, field_name: &str, f: &mut dyn FnMut(Result<'_, &dyn Debug>)) {
+        match field_name {
+            "value" => f(Ok(&self.value)),
+            _ => f(Err(InteractiveError::FieldNotFound {
+                type_name: "Mirror",
+                field_name,
+                suggestion: did_you_mean(field_name, self.get_all_field_names()),
+            })),
+        }
+    }
+
+    fn get_all_field_names(&self) -> &'static [&'static str] {
+        &["value", "me"]
+    }
+
+    fn get_type_name(&self) -> &'static str {
+        "Mirror"
+    }
+}
+
+#[derive(InteractiveRoot, Debug, Default)]
+struct Root {
+    board: Board,
+    mirror: Mirror,
+}
+
+#[test]
+fn test_dump() {
+    let root = Root::default();
+
+    assert_eq!(
+        root.dump(usize::MAX),
+        "\
+Root
+  board: Board
+    board.sensor: Sensor
+      board.sensor.value = 0.0
+    board.shared: Sensor
+      board.shared.value = 0.0
+    board.name = \"\"
+  mirror: Mirror
+    mirror.value = 0
+    mirror.me: Mirror (cycle)"
+    );
+}
+
+#[test]
+fn test_dump_depth() {
+    let root = Root::default();
+
+    assert_eq!(root.dump(0), "Root ...");
+    assert_eq!(
+        root.dump(2),
+        "\
+Root
+  board: Board
+    board.sensor: Sensor ...
+    board.shared: Sensor ...
+    board.name = \"\"
+  mirror: Mirror
+    mirror.value = 0
+    mirror.me: Mirror (cycle)"
+    );
+}
+
+#[test]
+fn test_tree_query() {
+    let mut root = Root::default();
+
+    assert_eq!(root.eval_to_string("tree"), root.dump(usize::MAX));
+    assert_eq!(
+        root.eval_to_string("tree(1)"),
+        "Root\n  board: Board ...\n  mirror: Mirror ..."
+    );
+    assert_eq!(
+        root.eval_to_string("tree(board.sensor)"),
+        "board.sensor: Sensor\n  board.sensor.value = 0.0"
+    );
+    assert_eq!(
+        root.eval_to_string("tree(board, 1)"),
+        "board: Board\n  board.sensor: Sensor ...\n  board.shared: Sensor ...\n  board.name = \"\""
+    );
+    assert_eq!(root.eval_to_string("tree(board.name)"), "board.name = \"\"");
+}
+
+#[test]
+fn test_tree_errors() {
+    let mut root = Root::default();
+
+    assert_eq!(
+        root.eval_to_string("tree(board.sensr)"),
+        "No field `sensr` found for type `Board`, did you mean `sensor`?"
+    );
+    assert_eq!(
+        root.eval_to_string("tree(board, x)"),
+        "argument 2 `depth` of `tree`: expected usize, found `x`"
+    );
+}
+
+mod shadowed {
+    use rusteval::{InteractiveRoot, Methods};
+
+    #[derive(InteractiveRoot, Debug, Default)]
+    pub struct FieldRoot {
+        tree: u8,
+    }
+
+    #[derive(InteractiveRoot, Debug, Default)]
+    pub struct PropertyRoot;
+
+    #[Methods]
+    impl PropertyRoot {
+        #[interactive(property)]
+        fn tree(&self) -> &'static str {
+            "custom tree"
+        }
+    }
+
+    #[test]
+    fn test_own_tree_takes_precedence() {
+        let mut root = FieldRoot { tree: 42 };
+        assert_eq!(root.eval_to_string("tree"), "42");
+        // the root has no method `tree`
+        assert_eq!(root.eval_to_string("tree(0)"), "FieldRoot ...");
+
+        assert_eq!(PropertyRoot.eval_to_string("tree"), "\"custom tree\"");
+    }
+}
@@ -0,0 +1,108 @@
+use std::collections::BTreeMap;
+
+use rusteval::{Interactive, InteractiveRoot, Methods, Value};
+
+#[derive(Interactive, Debug, Default)]
+struct Sensor {
+    id: u8,
+    offset: i32,
+    name: String,
+    #[interactive(unit = "V")]
+    voltage: f32,
+    #[interactive(hidden)]
+    secret: bool,
+}
+
+#[Methods]
+impl Sensor {
+    fn tags(&self) -> BTreeMap<&'static str, char> {
+        [("a", 'x'), ("b", 'y')].into_iter().collect()
+    }
+
+    fn pair(&self) -> (bool, Option<u8>) {
+        (true, None)
+    }
+
+    fn reset(&mut self) {}
+}
+
+#[derive(InteractiveRoot, Default)]
+struct Root {
+    sensors: [Sensor; 1],
+    sensor: Sensor,
+}
+
+fn sensor_value() -> Value {
+    Value::Record {
+        name: "Sensor".into(),
+        fields: vec![
+            ("id".into(), Value::UInt(1)),
+            ("offset".into(), Value::Int(-2)),
+            ("name".into(), Value::String("temp".into())),
+            ("voltage".into(), Value::Float(0.5)),
+        ],
+    }
+}
+
+fn root() -> Root {
+    let mut root = Root::default();
+    root.sensor.id = 1;
+    root.sensor.offset = -2;
+    root.sensor.name = "temp".into();
+    root.sensor.voltage = 0.5;
+    root
+}
+
+#[test]
+fn test_primitives() {
+    let mut root = root();
+    assert_eq!(root.try_eval_value("sensor.id"), Ok(Value::UInt(1)));
+    assert_eq!(root.try_eval_value("sensor.offset"), Ok(Value::Int(-2)));
+    assert_eq!(
+        root.try_eval_value("sensor.name"),
+        Ok(Value::String("temp".into()))
+    );
+    assert_eq!(root.try_eval_value("sensor.voltage"), Ok(Value::Float(0.5)));
+    assert_eq!(root.try_eval_value("sensor.reset()"), Ok(Value::Unit));
+}
+
+#[test]
+fn test_record() {
+    let mut root = root();
+    let value = root.try_eval_value("sensor").unwrap();
+    assert_eq!(value, sensor_value());
+    assert_eq!(value.field("name"), Some(&Value::String("temp".into())));
+    assert_eq!(value.field("secret"), None);
+}
+
+#[test]
+fn test_collections() {
+    let mut root = root();
+    let sensors = root.try_eval_value("sensors").unwrap();
+    assert!(matches!(sensors, Value::List(sensors) if sensors.len() == 1));
+    assert_eq!(
+        root.try_eval_value("sensor.tags()"),
+        Ok(Value::Map(vec![
+            (Value::String("a".into()), Value::Char('x')),
+            (Value::String("b".into()), Value::Char('y')),
+        ]))
+    );
+    assert_eq!(
+        root.try_eval_value("sensor.pair()"),
+        Ok(Value::List(vec![
+            Value::Bool(true),
+            Value::Debug("None".into())
+        ]))
+    );
+}
+
+#[test]
+fn test_error() {
+    let mut root = root();
+    assert_eq!(
+        root.try_eval_value("sensor.nothing")
+            .unwrap_err()
+            .to_string(),
+        "No field `nothing` found for type `Sensor`"
+    );
+}
@@ -0,0 +1,204 @@
+use std::rc::Rc;
+
+use rusteval::{Interactive, InteractiveRoot, Methods, Value};
+
+#[derive(Interactive, Debug, Default)]
+struct Sensor {
+    temperature: f32,
+    error_count: u32,
+}
+
+#[Methods]
+impl Sensor {
+    fn scaled(&self, factor: f32) -> f32 {
+        self.temperature * factor
+    }
+
+    fn reset(&mut self) {
+        self.error_count = 0;
+    }
+}
+
+#[derive(Interactive, Debug, Default)]
+struct Sensors {
+    inside: Sensor,
+    outside: Rc<Sensor>,
+    count: u8,
+}
+
+#[derive(Interactive, Debug, Default)]
+struct Board {
+    sensors: Sensors,
+    error_count: u32,
+}
+
+#[derive(InteractiveRoot, Debug, Default)]
+struct Root {
+    board: Board,
+}
+
+fn root() -> Root {
+    let mut root = Root::default();
+    root.board.sensors.inside.temperature = 21.0;
+    root.board.sensors.outside = Rc::new(Sensor {
+        temperature: 4.5,
+        error_count: 3,
+    });
+    root.board.error_count = 1;
+    root
+}
+
+fn paths(root: &Root, query: &str) -> Vec<String> {
+    let mut paths = Vec::new();
+    root.try_eval_matches(query, |path, result| {
+        assert!(result.is_ok());
+        paths.push(path.to_string())
+    });
+    paths
+}
+
+#[test]
+fn test_single_wildcard() {
+    let root = root();
+
+    assert_eq!(
+        root.eval_matches("board.sensors.*.temperature"),
+        Ok(vec![
+            (
+                "board.sensors.inside.temperature".to_string(),
+                Value::Float(21.0)
+            ),
+            (
+                "board.sensors.outside.temperature".to_string(),
+                Value::Float(4.5)
+            ),
+        ])
+    );
+    assert_eq!(paths(&root, "*.sensors"), ["board.sensors"],);
+}
+
+#[test]
+fn test_trailing_wildcard() {
+    let root = root();
+
+    assert_eq!(
+        paths(&root, "board.sensors.*"),
+        [
+            "board.sensors.inside",
+            "board.sensors.outside",
+            "board.sensors.count"
+        ]
+    );
+    assert_eq!(
+        paths(&root, "board.sensors.**"),
+        [
+            "board.sensors.inside",
+            "board.sensors.inside.temperature",
+            "board.sensors.inside.error_count",
+            "board.sensors.outside",
+            "board.sensors.outside.temperature",
+            "board.sensors.outside.error_count",
+            "board.sensors.count"
+        ]
+    );
+}
+
+#[test]
+fn test_recursive_wildcard() {
+    let root = root();
+
+    assert_eq!(
+        root.eval_matches("**.error_count"),
+        Ok(vec![
+            ("board.error_count".to_string(), Value::UInt(1)),
+            (
+                "board.sensors.inside.error_count".to_string(),
+                Value::UInt(0)
+            ),
+            (
+                "board.sensors.outside.error_count".to_string(),
+                Value::UInt(3)
+            ),
+        ])
+    );
+    assert_eq!(
+        paths(&root, "board.**.inside.temperature"),
+        ["board.sensors.inside.temperature"]
+    );
+}
+
+#[test]
+fn test_method() {
+    let root = root();
+
+    assert_eq!(
+        paths(&root, "board.sensors.*.scaled(2)"),
+        [
+            "board.sensors.inside.scaled(2)",
+            "board.sensors.outside.scaled(2)"
+        ]
+    );
+    assert_eq!(
+        root.eval_matches("**.scaled(2)").unwrap()[1].1,
+        Value::Float(9.0)
+    );
+}
+
+#[test]
+fn test_mutable_method() {
+    let mut root = root();
+
+    // matches are only evaluated immutably, but objects without the method are still no match
+    assert_eq!(
+        root.eval_to_string("board.sensors.*.reset()"),
+        "board.sensors.inside.reset(): `reset` requires mutable access\n\
+         board.sensors.outside.reset(): `reset` requires mutable access"
+    );
+    assert_eq!(root.eval_to_string("board.sensors.*.nothing()"), "");
+}
+
+#[test]
+fn test_no_wildcard() {
+    let root = root();
+
+    assert_eq!(
+        root.eval_matches("board.error_count"),
+        Ok(vec![("board.error_count".to_string(), Value::UInt(1))])
+    );
+}
+
+#[test]
+fn test_eval_to_string() {
+    let mut root = root();
+
+    assert_eq!(
+        root.eval_to_string("board.sensors.*.error_count"),
+        "board.sensors.inside.error_count = 0\nboard.sensors.outside.error_count = 3"
+    );
+    assert_eq!(root.eval_to_string("*.nothing"), "");
+}
+
+#[test]
+fn test_errors() {
+    let mut root = root();
+
+    let error = root.eval_matches("board.sensrs.*").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "No field `sensrs` found for type `Board`, did you mean `sensors`?"
+    );
+    assert_eq!(error.type_path(), &["Root", "Board"]);
+
+    let error = root.eval_matches("board.sensors.*.scaled(x)").unwrap_err();
+    assert_eq!(error.query(), Some("board.sensors.*.scaled(x)"));
+    assert_eq!(error.type_path(), &["Root", "Board", "Sensors", "Sensor"]);
+
+    assert_eq!(
+        root.eval_to_string("board.sensrs.*"),
+        "No field `sensrs` found for type `Board`, did you mean `sensors`?"
+    );
+    assert_eq!(
+        root.eval_to_string("*.error_count = 2"),
+        "Property `error_count` is not writable"
+    );
+}